@@ -0,0 +1,439 @@
+//! Abstract Control Model requests.
+//! Encoding and decoding of the PSTN class requests used by serial ports
+//! (`CDCControlSubClass::Abstract`, usually with `CDCControlProtocol::V250`),
+//! and of the line coding they exchange.
+//! Documentation: Section 6.3 of the CDC PSTN 1.2 specification.
+
+
+
+use crate::descriptor::{ ToBytes, read_u16, read_u32 };
+use crate::error::*;
+use super::ACMCapabilities;
+
+
+
+/// Number of stop bits of a line coding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StopBits {
+    /// 1 stop bit (0).
+    One,
+
+    /// 1.5 stop bits (1).
+    OnePointFive,
+
+    /// 2 stop bits (2).
+    Two,
+}
+
+impl StopBits {
+    /// Raw `bCharFormat` code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            StopBits::One          => 0,
+            StopBits::OnePointFive => 1,
+            StopBits::Two          => 2,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for StopBits {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<StopBits, USBParseError> {
+        match code {
+            0 => Ok( StopBits::One ),
+            1 => Ok( StopBits::OnePointFive ),
+            2 => Ok( StopBits::Two ),
+
+            _ => Err( USBParseError::UnknownStopBits(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for StopBits {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
+
+
+/// Parity of a line coding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Parity {
+    /// No parity bit (0).
+    None,
+
+    /// Odd parity (1).
+    Odd,
+
+    /// Even parity (2).
+    Even,
+
+    /// Parity bit always set (3).
+    Mark,
+
+    /// Parity bit always cleared (4).
+    Space,
+}
+
+impl Parity {
+    /// Raw `bParityType` code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            Parity::None  => 0,
+            Parity::Odd   => 1,
+            Parity::Even  => 2,
+            Parity::Mark  => 3,
+            Parity::Space => 4,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for Parity {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<Parity, USBParseError> {
+        match code {
+            0 => Ok( Parity::None ),
+            1 => Ok( Parity::Odd ),
+            2 => Ok( Parity::Even ),
+            3 => Ok( Parity::Mark ),
+            4 => Ok( Parity::Space ),
+
+            _ => Err( USBParseError::UnknownParity(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for Parity {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
+
+
+/// Number of data bits of a line coding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataBits {
+    /// 5 data bits.
+    Five,
+
+    /// 6 data bits.
+    Six,
+
+    /// 7 data bits.
+    Seven,
+
+    /// 8 data bits.
+    Eight,
+
+    /// 16 data bits.
+    Sixteen,
+}
+
+impl DataBits {
+    /// Raw `bDataBits` code, which is the number of bits.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            DataBits::Five    => 5,
+            DataBits::Six     => 6,
+            DataBits::Seven   => 7,
+            DataBits::Eight   => 8,
+            DataBits::Sixteen => 16,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for DataBits {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<DataBits, USBParseError> {
+        match code {
+            5  => Ok( DataBits::Five ),
+            6  => Ok( DataBits::Six ),
+            7  => Ok( DataBits::Seven ),
+            8  => Ok( DataBits::Eight ),
+            16 => Ok( DataBits::Sixteen ),
+
+            _ => Err( USBParseError::UnknownDataBits(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for DataBits {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
+
+
+/// Line Coding Structure, the data stage of `SET_LINE_CODING` and
+/// `GET_LINE_CODING`.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineCoding {
+    /// Data terminal rate, in bits per second.
+    dwDTERate: u32,
+
+    /// Number of stop bits.
+    bCharFormat: StopBits,
+
+    /// Parity.
+    bParityType: Parity,
+
+    /// Number of data bits.
+    bDataBits: DataBits,
+}
+
+impl LineCoding {
+    /// Size of this structure in bytes.
+    pub const LENGTH: u16 = 7;
+
+    /// Creates a Line Coding Structure.
+    pub const fn new(rate: u32, stop_bits: StopBits, parity: Parity, data_bits: DataBits) -> LineCoding {
+        LineCoding {
+            dwDTERate: rate,
+            bCharFormat: stop_bits,
+            bParityType: parity,
+            bDataBits: data_bits,
+        }
+    }
+
+    /// Parses a Line Coding Structure from the start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<LineCoding, USBParseError> {
+        use core::convert::TryFrom;
+
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        Ok(LineCoding {
+            dwDTERate: read_u32(bytes, 0),
+            bCharFormat: StopBits::try_from(bytes[4])?,
+            bParityType: Parity::try_from(bytes[5])?,
+            bDataBits: DataBits::try_from(bytes[6])?,
+        })
+    }
+
+    /// Encodes the structure.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let rate = self.dwDTERate.to_le_bytes();
+
+        [
+            rate[0], rate[1], rate[2], rate[3],
+            self.bCharFormat.code(),
+            self.bParityType.code(),
+            self.bDataBits.code(),
+        ]
+    }
+
+    /// Data terminal rate, in bits per second.
+    pub const fn rate(&self) -> u32 {
+        self.dwDTERate
+    }
+
+    /// Number of stop bits.
+    pub const fn stop_bits(&self) -> StopBits {
+        self.bCharFormat
+    }
+
+    /// Parity.
+    pub const fn parity(&self) -> Parity {
+        self.bParityType
+    }
+
+    /// Number of data bits.
+    pub const fn data_bits(&self) -> DataBits {
+        self.bDataBits
+    }
+}
+
+impl Default for LineCoding {
+    /// 9600 bauds, 8 data bits, no parity and 1 stop bit.
+    fn default() -> LineCoding {
+        LineCoding::new(9600, StopBits::One, Parity::None, DataBits::Eight)
+    }
+}
+
+impl ToBytes for LineCoding {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// Control signals set by `SET_CONTROL_LINE_STATE`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ControlLineState(pub u16);
+
+impl ControlLineState {
+    /// Data Terminal Ready: the host is present.
+    pub const DTR: ControlLineState = ControlLineState(1 << 0);
+
+    /// Request To Send: carrier control for half duplex modems.
+    pub const RTS: ControlLineState = ControlLineState(1 << 1);
+
+    /// Returns `true` if all the given signals are set.
+    pub const fn contains(&self, other: ControlLineState) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Sets the given signals.
+    pub const fn with(self, other: ControlLineState) -> ControlLineState {
+        ControlLineState(self.0 | other.0)
+    }
+}
+
+
+
+/// A PSTN class request sent to an Abstract Control Model interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACMRequest {
+    /// `SET_LINE_CODING`, followed by the line coding in its data stage.
+    SetLineCoding { interface: u16, coding: LineCoding },
+
+    /// `GET_LINE_CODING`, answered by the line coding in its data stage.
+    GetLineCoding { interface: u16 },
+
+    /// `SET_CONTROL_LINE_STATE`.
+    SetControlLineState { interface: u16, state: ControlLineState },
+
+    /// `SEND_BREAK`, with the duration of the break in milliseconds.
+    /// A duration of `0xFFFF` holds the break until a request with a
+    /// duration of 0 ends it.
+    SendBreak { interface: u16, duration: u16 },
+}
+
+impl ACMRequest {
+    /// Size of a setup packet in bytes.
+    pub const SETUP_LENGTH: usize = 8;
+
+    /// `bmRequestType` of the requests from the host to the interface.
+    pub const REQUEST_TYPE_OUT: u8 = 0x21;
+
+    /// `bmRequestType` of the requests from the interface to the host.
+    pub const REQUEST_TYPE_IN: u8 = 0xA1;
+
+    /// Request code of `SET_LINE_CODING`.
+    pub const SET_LINE_CODING: u8 = 0x20;
+
+    /// Request code of `GET_LINE_CODING`.
+    pub const GET_LINE_CODING: u8 = 0x21;
+
+    /// Request code of `SET_CONTROL_LINE_STATE`.
+    pub const SET_CONTROL_LINE_STATE: u8 = 0x22;
+
+    /// Request code of `SEND_BREAK`.
+    pub const SEND_BREAK: u8 = 0x23;
+
+    /// Parses a request from its setup packet, and from its data stage for
+    /// `SET_LINE_CODING`.
+    pub fn parse(setup: &[u8], data: &[u8]) -> Result<ACMRequest, USBParseError> {
+        if setup.len() < Self::SETUP_LENGTH {
+            return Err( USBParseError::BufferTooShort(setup.len()) );
+        }
+
+        let (request, value, interface, length) = (setup[1], read_u16(setup, 2), read_u16(setup, 4), read_u16(setup, 6));
+
+        let (expected, size) = match request {
+            Self::GET_LINE_CODING => (Self::REQUEST_TYPE_IN, LineCoding::LENGTH),
+            Self::SET_LINE_CODING => (Self::REQUEST_TYPE_OUT, LineCoding::LENGTH),
+            Self::SET_CONTROL_LINE_STATE | Self::SEND_BREAK => (Self::REQUEST_TYPE_OUT, 0),
+
+            _ => return Err( USBParseError::UnknownRequest(request) ),
+        };
+
+        if setup[0] != expected {
+            return Err( USBParseError::InvalidRequestType(setup[0]) );
+        }
+
+        if length != size {
+            return Err( USBParseError::InvalidRequestLength(length) );
+        }
+
+        match request {
+            Self::SET_LINE_CODING => Ok( ACMRequest::SetLineCoding { interface, coding: LineCoding::parse(data)? } ),
+            Self::GET_LINE_CODING => Ok( ACMRequest::GetLineCoding { interface } ),
+            Self::SET_CONTROL_LINE_STATE => Ok( ACMRequest::SetControlLineState { interface, state: ControlLineState(value) } ),
+            _ => Ok( ACMRequest::SendBreak { interface, duration: value } ),
+        }
+    }
+
+    /// Encodes the setup packet of the request.
+    /// The line coding of `SET_LINE_CODING` is sent separately in the data
+    /// stage.
+    pub const fn setup(&self) -> [u8; Self::SETUP_LENGTH] {
+        let value = self.value().to_le_bytes();
+        let interface = self.interface().to_le_bytes();
+        let length = self.length().to_le_bytes();
+
+        [
+            self.request_type(), self.code(),
+            value[0], value[1],
+            interface[0], interface[1],
+            length[0], length[1],
+        ]
+    }
+
+    /// `bmRequestType` of the request.
+    pub const fn request_type(&self) -> u8 {
+        match self {
+            ACMRequest::GetLineCoding { .. } => Self::REQUEST_TYPE_IN,
+            _ => Self::REQUEST_TYPE_OUT,
+        }
+    }
+
+    /// Request code.
+    pub const fn code(&self) -> u8 {
+        match self {
+            ACMRequest::SetLineCoding { .. }       => Self::SET_LINE_CODING,
+            ACMRequest::GetLineCoding { .. }       => Self::GET_LINE_CODING,
+            ACMRequest::SetControlLineState { .. } => Self::SET_CONTROL_LINE_STATE,
+            ACMRequest::SendBreak { .. }           => Self::SEND_BREAK,
+        }
+    }
+
+    /// `wValue` of the request.
+    pub const fn value(&self) -> u16 {
+        match self {
+            ACMRequest::SetControlLineState { state, .. } => state.0,
+            ACMRequest::SendBreak { duration, .. } => *duration,
+            _ => 0,
+        }
+    }
+
+    /// Interface the request is sent to.
+    pub const fn interface(&self) -> u16 {
+        match self {
+            ACMRequest::SetLineCoding { interface, .. }
+            | ACMRequest::GetLineCoding { interface }
+            | ACMRequest::SetControlLineState { interface, .. }
+            | ACMRequest::SendBreak { interface, .. } => *interface,
+        }
+    }
+
+    /// Size of the data stage in bytes.
+    pub const fn length(&self) -> u16 {
+        match self {
+            ACMRequest::SetLineCoding { .. } | ACMRequest::GetLineCoding { .. } => LineCoding::LENGTH,
+            _ => 0,
+        }
+    }
+
+    /// Capability the interface must advertise in its abstract control
+    /// management functional descriptor to accept the request.
+    pub const fn capability(&self) -> ACMCapabilities {
+        match self {
+            ACMRequest::SendBreak { .. } => ACMCapabilities::SEND_BREAK,
+            _ => ACMCapabilities::LINE_CODING,
+        }
+    }
+}
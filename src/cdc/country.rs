@@ -0,0 +1,119 @@
+//! CDC Country Selection functional descriptor.
+//! A structure representing the country selection functional descriptor,
+//! which lists the countries a communications device is qualified for.
+//! Documentation: Section 5.2.3.9 of the CDC 1.2 specification.
+
+
+
+use crate::descriptor::{ DescriptorType, ToBytes, read_u16 };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+/// A country selection functional descriptor borrowed from a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CountrySelectionDescriptor<'a> {
+    /// The whole descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> CountrySelectionDescriptor<'a> {
+    /// Size of this descriptor in bytes, with a single country.
+    pub const LENGTH: u8 = 6;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::CountrySelection.code();
+
+    /// Parses a country selection functional descriptor from the start of
+    /// the given buffer.
+    /// The descriptor must list at least one country, with no trailing byte.
+    pub fn parse(bytes: &'a [u8]) -> Result<CountrySelectionDescriptor<'a>, USBParseError> {
+        let length = super::header(bytes, FunctionalSubtype::CountrySelection, Self::LENGTH)?;
+
+        if (length - 4) % 2 != 0 {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok( CountrySelectionDescriptor { bytes: &bytes[..length] } )
+    }
+
+    /// Encodes a country selection functional descriptor at compile time.
+    /// Panics if there is no country or if the descriptor does not fill the
+    /// array exactly.
+    pub const fn to_array<const N: usize>(release_date_index: u8, countries: &[u16]) -> [u8; N] {
+        assert!(!countries.is_empty(), "a country selection requires at least one country");
+        assert!(N == 4 + 2 * countries.len(), "the country selection does not fill the array");
+        assert!(N <= u8::MAX as usize, "the country selection is longer than bLength allows");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < countries.len() {
+            let code = countries[i].to_le_bytes();
+
+            bytes[4 + 2 * i] = code[0];
+            bytes[5 + 2 * i] = code[1];
+            i += 1;
+        }
+
+        bytes[0] = N as u8;
+        bytes[1] = Self::TYPE;
+        bytes[2] = Self::SUBTYPE;
+        bytes[3] = release_date_index;
+
+        bytes
+    }
+
+    /// Index of the string descriptor holding the release date of the
+    /// ISO 3166 country codes, as `DDMMYYYY`.
+    pub const fn release_date_index(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Number of countries.
+    pub const fn num_countries(&self) -> usize {
+        (self.bytes.len() - 4) / 2
+    }
+
+    /// ISO 3166 codes of the countries the device is qualified for.
+    pub fn countries(&self) -> CountryCodes<'a> {
+        CountryCodes { bytes: &self.bytes[4..] }
+    }
+}
+
+impl<'a> ToBytes for CountrySelectionDescriptor<'a> {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.bytes.to_bytes(buffer)
+    }
+}
+
+/// Iterator over the country codes of a country selection descriptor.
+#[derive(Clone, Debug)]
+pub struct CountryCodes<'a> {
+    /// Codes not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for CountryCodes<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+
+        let code = read_u16(self.bytes, 0);
+
+        self.bytes = &self.bytes[2..];
+
+        Some(code)
+    }
+}
@@ -0,0 +1,389 @@
+//! CDC Ethernet Networking functional descriptor.
+//! A structure representing the Ethernet networking functional descriptor,
+//! which describes the Ethernet interface of ECM and NCM functions, and the
+//! MAC address string it refers to.
+//! Documentation: Section 5.4 and Table 4 of the CDC ECM 1.2 specification.
+
+
+
+use crate::descriptor::{ DescriptorType, StringDescriptor, ToBytes, read_u16, read_u32 };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+/// Size of the Ethernet header counted in `wMaxSegmentSize`.
+const ETHERNET_HEADER: u16 = 14;
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EthernetNetworkingDescriptor {
+    /// Index of the string descriptor holding the MAC address.
+    iMACAddress: u8,
+
+    /// Ethernet statistics collected by the device.
+    bmEthernetStatistics: EthernetStatistics,
+
+    /// Maximum segment size, including the Ethernet header.
+    wMaxSegmentSize: u16,
+
+    /// Number of multicast filters, and whether they are perfect.
+    wNumberMCFilters: u16,
+
+    /// Number of pattern filters used to wake the host.
+    bNumberPowerFilters: u8,
+}
+
+impl EthernetNetworkingDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 13;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::EthernetNetworking.code();
+
+    /// Creates an Ethernet networking functional descriptor without
+    /// multicast or power filters.
+    pub const fn new(mac_address_index: u8, statistics: EthernetStatistics, max_segment_size: u16) -> EthernetNetworkingDescriptor {
+        EthernetNetworkingDescriptor {
+            iMACAddress: mac_address_index,
+            bmEthernetStatistics: statistics,
+            wMaxSegmentSize: max_segment_size,
+            wNumberMCFilters: 0,
+            bNumberPowerFilters: 0,
+        }
+    }
+
+    /// Sets the number of multicast filters, and whether they filter
+    /// perfectly rather than by hash.
+    pub const fn multicast_filters(mut self, count: u16, perfect: bool) -> Self {
+        assert!(count <= 0x7FFF, "the number of multicast filters does not fit in 15 bits");
+
+        self.wNumberMCFilters = count | if perfect { 0 } else { 0x8000 };
+        self
+    }
+
+    /// Sets the number of pattern filters used to wake the host.
+    pub const fn power_filters(mut self, count: u8) -> Self {
+        self.bNumberPowerFilters = count;
+        self
+    }
+
+    /// Parses an Ethernet networking functional descriptor from the start of
+    /// the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<EthernetNetworkingDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::EthernetNetworking, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok(EthernetNetworkingDescriptor {
+            iMACAddress: bytes[3],
+            bmEthernetStatistics: EthernetStatistics( read_u32(bytes, 4) ),
+            wMaxSegmentSize: read_u16(bytes, 8),
+            wNumberMCFilters: read_u16(bytes, 10),
+            bNumberPowerFilters: bytes[12],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let statistics = self.bmEthernetStatistics.0.to_le_bytes();
+        let segment = self.wMaxSegmentSize.to_le_bytes();
+        let filters = self.wNumberMCFilters.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE, Self::SUBTYPE,
+            self.iMACAddress,
+            statistics[0], statistics[1], statistics[2], statistics[3],
+            segment[0], segment[1],
+            filters[0], filters[1],
+            self.bNumberPowerFilters,
+        ]
+    }
+
+    /// Index of the string descriptor holding the MAC address.
+    /// Use `MACAddress::parse` to decode it.
+    pub const fn mac_address_index(&self) -> u8 {
+        self.iMACAddress
+    }
+
+    /// Ethernet statistics collected by the device.
+    pub const fn statistics(&self) -> EthernetStatistics {
+        self.bmEthernetStatistics
+    }
+
+    /// Maximum segment size, including the 14 byte Ethernet header.
+    pub const fn max_segment_size(&self) -> u16 {
+        self.wMaxSegmentSize
+    }
+
+    /// Maximum transmission unit: the maximum segment size without the
+    /// Ethernet header.
+    pub const fn mtu(&self) -> u16 {
+        self.wMaxSegmentSize.saturating_sub(ETHERNET_HEADER)
+    }
+
+    /// Number of multicast filters.
+    pub const fn num_multicast_filters(&self) -> u16 {
+        self.wNumberMCFilters & 0x7FFF
+    }
+
+    /// Returns `true` if the multicast filters are perfect, and `false` if
+    /// they are hashed.
+    pub const fn perfect_multicast_filtering(&self) -> bool {
+        (self.wNumberMCFilters & 0x8000) == 0
+    }
+
+    /// Number of pattern filters used to wake the host.
+    pub const fn num_power_filters(&self) -> u8 {
+        self.bNumberPowerFilters
+    }
+}
+
+impl ToBytes for EthernetNetworkingDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// An Ethernet statistic, as selected by `GET_ETHERNET_STATISTIC`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EthernetStatistic {
+    /// Frames transmitted without errors.
+    TransmitOk,
+
+    /// Frames received without errors.
+    ReceiveOk,
+
+    /// Frames not transmitted, or transmitted with errors.
+    TransmitError,
+
+    /// Frames received with errors that are not delivered.
+    ReceiveError,
+
+    /// Frames missed for lack of buffers.
+    ReceiveNoBuffer,
+
+    /// Directed bytes transmitted without errors.
+    DirectedBytesTransmit,
+
+    /// Directed frames transmitted without errors.
+    DirectedFramesTransmit,
+
+    /// Multicast bytes transmitted without errors.
+    MulticastBytesTransmit,
+
+    /// Multicast frames transmitted without errors.
+    MulticastFramesTransmit,
+
+    /// Broadcast bytes transmitted without errors.
+    BroadcastBytesTransmit,
+
+    /// Broadcast frames transmitted without errors.
+    BroadcastFramesTransmit,
+
+    /// Directed bytes received without errors.
+    DirectedBytesReceive,
+
+    /// Directed frames received without errors.
+    DirectedFramesReceive,
+
+    /// Multicast bytes received without errors.
+    MulticastBytesReceive,
+
+    /// Multicast frames received without errors.
+    MulticastFramesReceive,
+
+    /// Broadcast bytes received without errors.
+    BroadcastBytesReceive,
+
+    /// Broadcast frames received without errors.
+    BroadcastFramesReceive,
+
+    /// Frames received with a CRC or FCS error.
+    ReceiveCRCError,
+
+    /// Length of the transmit queue.
+    TransmitQueueLength,
+
+    /// Frames received with an alignment error.
+    ReceiveErrorAlignment,
+
+    /// Frames transmitted after one collision.
+    TransmitOneCollision,
+
+    /// Frames transmitted after more than one collision.
+    TransmitMoreCollisions,
+
+    /// Frames transmitted after a deferral.
+    TransmitDeferred,
+
+    /// Frames not transmitted because of excessive collisions.
+    TransmitMaxCollisions,
+
+    /// Frames not received because of an overrun.
+    ReceiveOverrun,
+
+    /// Frames not transmitted because of an underrun.
+    TransmitUnderrun,
+
+    /// Frames transmitted without a heartbeat.
+    TransmitHeartbeatFailure,
+
+    /// Times the carrier sense signal was lost while transmitting.
+    TransmitTimesCarrierLost,
+
+    /// Late collisions detected.
+    TransmitLateCollisions,
+}
+
+impl EthernetStatistic {
+    /// All the statistics, in the order of their bits.
+    pub const ALL: [EthernetStatistic; 29] = [
+        EthernetStatistic::TransmitOk,
+        EthernetStatistic::ReceiveOk,
+        EthernetStatistic::TransmitError,
+        EthernetStatistic::ReceiveError,
+        EthernetStatistic::ReceiveNoBuffer,
+        EthernetStatistic::DirectedBytesTransmit,
+        EthernetStatistic::DirectedFramesTransmit,
+        EthernetStatistic::MulticastBytesTransmit,
+        EthernetStatistic::MulticastFramesTransmit,
+        EthernetStatistic::BroadcastBytesTransmit,
+        EthernetStatistic::BroadcastFramesTransmit,
+        EthernetStatistic::DirectedBytesReceive,
+        EthernetStatistic::DirectedFramesReceive,
+        EthernetStatistic::MulticastBytesReceive,
+        EthernetStatistic::MulticastFramesReceive,
+        EthernetStatistic::BroadcastBytesReceive,
+        EthernetStatistic::BroadcastFramesReceive,
+        EthernetStatistic::ReceiveCRCError,
+        EthernetStatistic::TransmitQueueLength,
+        EthernetStatistic::ReceiveErrorAlignment,
+        EthernetStatistic::TransmitOneCollision,
+        EthernetStatistic::TransmitMoreCollisions,
+        EthernetStatistic::TransmitDeferred,
+        EthernetStatistic::TransmitMaxCollisions,
+        EthernetStatistic::ReceiveOverrun,
+        EthernetStatistic::TransmitUnderrun,
+        EthernetStatistic::TransmitHeartbeatFailure,
+        EthernetStatistic::TransmitTimesCarrierLost,
+        EthernetStatistic::TransmitLateCollisions,
+    ];
+
+    /// Bit of the statistic in `bmEthernetStatistics`.
+    pub const fn bit(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Feature selector of the statistic in `GET_ETHERNET_STATISTIC`.
+    pub const fn selector(&self) -> u16 {
+        self.bit() as u16 + 1
+    }
+}
+
+/// Ethernet statistics collected by a device.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EthernetStatistics(pub u32);
+
+impl EthernetStatistics {
+    /// Returns `true` if the device collects the given statistic.
+    pub const fn supports(&self, statistic: EthernetStatistic) -> bool {
+        (self.0 & (1 << statistic.bit())) != 0
+    }
+
+    /// Adds the given statistic.
+    pub const fn with(self, statistic: EthernetStatistic) -> EthernetStatistics {
+        EthernetStatistics(self.0 | (1 << statistic.bit()))
+    }
+
+    /// Statistics collected by the device.
+    pub fn iter(&self) -> impl Iterator<Item = EthernetStatistic> {
+        let statistics = *self;
+
+        EthernetStatistic::ALL.into_iter().filter(move |statistic| statistics.supports(*statistic))
+    }
+}
+
+
+
+/// A 48 bit MAC address.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MACAddress(pub [u8; 6]);
+
+impl MACAddress {
+    /// Size of the string descriptor holding a MAC address.
+    pub const LENGTH: u8 = 26;
+
+    /// Decodes a MAC address from its string descriptor, written as 12
+    /// hexadecimal digits, most significant first.
+    pub fn parse(string: &StringDescriptor) -> Result<MACAddress, USBParseError> {
+        if string.len_utf16() != 12 {
+            return Err( USBParseError::InvalidLength( (2 + string.bytes().len()) as u8 ) );
+        }
+
+        let mut address = [0u8; 6];
+
+        for (i, unit) in string.units().enumerate() {
+            let digit = match unit {
+                0x30..=0x39 => unit - 0x30,
+                0x41..=0x46 => unit - 0x41 + 10,
+                0x61..=0x66 => unit - 0x61 + 10,
+                _ => return Err( USBParseError::InvalidMACAddress(unit) ),
+            };
+
+            address[i / 2] = (address[i / 2] << 4) | digit as u8;
+        }
+
+        Ok( MACAddress(address) )
+    }
+
+    /// Encodes the string descriptor of the MAC address, in upper case
+    /// hexadecimal digits.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        let mut bytes = [0u8; Self::LENGTH as usize];
+
+        bytes[0] = Self::LENGTH;
+        bytes[1] = DescriptorType::String.code();
+
+        let mut i = 0;
+
+        while i < 6 {
+            bytes[2 + 4 * i] = DIGITS[(self.0[i] >> 4) as usize];
+            bytes[4 + 4 * i] = DIGITS[(self.0[i] & 0x0F) as usize];
+            i += 1;
+        }
+
+        bytes
+    }
+}
+
+impl ToBytes for MACAddress {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+impl core::fmt::Display for MACAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+
+        write!(f, "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}", a, b, c, d, e, g)
+    }
+}
@@ -0,0 +1,155 @@
+//! CDC functional descriptors.
+//! Typed dispatch of the class specific interface descriptors following a
+//! communications interface descriptor.
+//! Documentation: Section 5.2.3 of the CDC 1.2 specification.
+
+
+
+use crate::descriptor::{ Descriptors, RawDescriptor, ToBytes };
+use crate::error::*;
+use super::{
+    AbstractControlManagementDescriptor, CallManagementDescriptor, CountrySelectionDescriptor,
+    EthernetNetworkingDescriptor, FunctionalSubtype, HeaderDescriptor, MBIMDescriptor, MBIMExtendedDescriptor,
+    NCMDescriptor, UnionDescriptor, CS_INTERFACE,
+};
+
+
+
+/// A functional descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FunctionalDescriptor<'a> {
+    /// Header functional descriptor.
+    Header(HeaderDescriptor),
+
+    /// Call Management functional descriptor.
+    CallManagement(CallManagementDescriptor),
+
+    /// Abstract Control Management functional descriptor.
+    AbstractControlManagement(AbstractControlManagementDescriptor),
+
+    /// Union functional descriptor.
+    Union(UnionDescriptor<'a>),
+
+    /// Country Selection functional descriptor.
+    CountrySelection(CountrySelectionDescriptor<'a>),
+
+    /// Ethernet Networking functional descriptor.
+    EthernetNetworking(EthernetNetworkingDescriptor),
+
+    /// NCM functional descriptor.
+    NCM(NCMDescriptor),
+
+    /// MBIM functional descriptor.
+    MBIM(MBIMDescriptor),
+
+    /// MBIM Extended functional descriptor.
+    MBIMExtended(MBIMExtendedDescriptor),
+
+    /// Functional descriptor of another subtype, kept as is.
+    Other(RawDescriptor<'a>),
+}
+
+impl<'a> FunctionalDescriptor<'a> {
+    /// Parses a functional descriptor.
+    /// Descriptors of subtypes without a typed structure are kept as is.
+    pub fn parse(raw: RawDescriptor<'a>) -> Result<FunctionalDescriptor<'a>, USBParseError> {
+        let bytes = raw.bytes();
+
+        if bytes[1] != CS_INTERFACE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        if bytes.len() < 3 {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        let descriptor = match FunctionalSubtype::decode(bytes[2]) {
+            FunctionalSubtype::Header => FunctionalDescriptor::Header( HeaderDescriptor::parse(bytes)? ),
+            FunctionalSubtype::CallManagement => FunctionalDescriptor::CallManagement( CallManagementDescriptor::parse(bytes)? ),
+            FunctionalSubtype::AbstractControlManagement => FunctionalDescriptor::AbstractControlManagement( AbstractControlManagementDescriptor::parse(bytes)? ),
+            FunctionalSubtype::Union => FunctionalDescriptor::Union( UnionDescriptor::parse(bytes)? ),
+            FunctionalSubtype::CountrySelection => FunctionalDescriptor::CountrySelection( CountrySelectionDescriptor::parse(bytes)? ),
+            FunctionalSubtype::EthernetNetworking => FunctionalDescriptor::EthernetNetworking( EthernetNetworkingDescriptor::parse(bytes)? ),
+            FunctionalSubtype::NCM => FunctionalDescriptor::NCM( NCMDescriptor::parse(bytes)? ),
+            FunctionalSubtype::MBIM => FunctionalDescriptor::MBIM( MBIMDescriptor::parse(bytes)? ),
+            FunctionalSubtype::MBIMExtended => FunctionalDescriptor::MBIMExtended( MBIMExtendedDescriptor::parse(bytes)? ),
+
+            _ => FunctionalDescriptor::Other(raw),
+        };
+
+        Ok(descriptor)
+    }
+
+    /// Subtype of the descriptor.
+    pub fn subtype(&self) -> FunctionalSubtype {
+        match self {
+            FunctionalDescriptor::Header(_) => FunctionalSubtype::Header,
+            FunctionalDescriptor::CallManagement(_) => FunctionalSubtype::CallManagement,
+            FunctionalDescriptor::AbstractControlManagement(_) => FunctionalSubtype::AbstractControlManagement,
+            FunctionalDescriptor::Union(_) => FunctionalSubtype::Union,
+            FunctionalDescriptor::CountrySelection(_) => FunctionalSubtype::CountrySelection,
+            FunctionalDescriptor::EthernetNetworking(_) => FunctionalSubtype::EthernetNetworking,
+            FunctionalDescriptor::NCM(_) => FunctionalSubtype::NCM,
+            FunctionalDescriptor::MBIM(_) => FunctionalSubtype::MBIM,
+            FunctionalDescriptor::MBIMExtended(_) => FunctionalSubtype::MBIMExtended,
+            FunctionalDescriptor::Other(raw) => FunctionalSubtype::decode( raw.bytes()[2] ),
+        }
+    }
+}
+
+impl<'a> ToBytes for FunctionalDescriptor<'a> {
+    fn size(&self) -> usize {
+        match self {
+            FunctionalDescriptor::Header(descriptor) => descriptor.size(),
+            FunctionalDescriptor::CallManagement(descriptor) => descriptor.size(),
+            FunctionalDescriptor::AbstractControlManagement(descriptor) => descriptor.size(),
+            FunctionalDescriptor::Union(descriptor) => descriptor.size(),
+            FunctionalDescriptor::CountrySelection(descriptor) => descriptor.size(),
+            FunctionalDescriptor::EthernetNetworking(descriptor) => descriptor.size(),
+            FunctionalDescriptor::NCM(descriptor) => descriptor.size(),
+            FunctionalDescriptor::MBIM(descriptor) => descriptor.size(),
+            FunctionalDescriptor::MBIMExtended(descriptor) => descriptor.size(),
+            FunctionalDescriptor::Other(raw) => raw.size(),
+        }
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        match self {
+            FunctionalDescriptor::Header(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::CallManagement(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::AbstractControlManagement(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::Union(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::CountrySelection(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::EthernetNetworking(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::NCM(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::MBIM(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::MBIMExtended(descriptor) => descriptor.to_bytes(buffer),
+            FunctionalDescriptor::Other(raw) => raw.to_bytes(buffer),
+        }
+    }
+}
+
+/// Iterator over the functional descriptors of a communications interface.
+/// Other class specific descriptors and malformed functional descriptors
+/// are skipped.
+#[derive(Clone, Copy, Debug)]
+pub struct FunctionalDescriptors<'a> {
+    /// Descriptors not yet visited.
+    descriptors: Descriptors<'a>,
+}
+
+impl<'a> FunctionalDescriptors<'a> {
+    /// Creates an iterator over the functional descriptors of the given
+    /// chain of descriptors.
+    pub const fn new(descriptors: Descriptors<'a>) -> FunctionalDescriptors<'a> {
+        FunctionalDescriptors { descriptors }
+    }
+}
+
+impl<'a> Iterator for FunctionalDescriptors<'a> {
+    type Item = FunctionalDescriptor<'a>;
+
+    fn next(&mut self) -> Option<FunctionalDescriptor<'a>> {
+        self.descriptors.find_map(|raw| FunctionalDescriptor::parse(raw).ok())
+    }
+}
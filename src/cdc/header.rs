@@ -0,0 +1,73 @@
+//! CDC Header functional descriptor.
+//! A structure representing the header functional descriptor, which starts
+//! the functional descriptors of a communications interface.
+//! Documentation: Section 5.2.3.1 of the CDC 1.2 specification.
+
+
+
+use crate::descriptor::{ BCDVersion, DescriptorType, ToBytes, read_u16 };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeaderDescriptor {
+    /// CDC Specification release number.
+    bcdCDC: BCDVersion,
+}
+
+impl HeaderDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 5;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::Header.code();
+
+    /// CDC 1.1 specification release.
+    pub const CDC11: BCDVersion = BCDVersion::from_raw(0x0110);
+
+    /// CDC 1.2 specification release.
+    pub const CDC12: BCDVersion = BCDVersion::from_raw(0x0120);
+
+    /// Creates a header functional descriptor.
+    pub const fn new(version: BCDVersion) -> HeaderDescriptor {
+        HeaderDescriptor { bcdCDC: version }
+    }
+
+    /// Parses a header functional descriptor from the start of the given
+    /// buffer.
+    pub fn parse(bytes: &[u8]) -> Result<HeaderDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::Header, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok( HeaderDescriptor { bcdCDC: BCDVersion::from_raw( read_u16(bytes, 3) ) } )
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let version = self.bcdCDC.raw().to_le_bytes();
+
+        [Self::LENGTH, Self::TYPE, Self::SUBTYPE, version[0], version[1]]
+    }
+
+    /// CDC Specification release number.
+    pub const fn version(&self) -> BCDVersion {
+        self.bcdCDC
+    }
+}
+
+impl ToBytes for HeaderDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
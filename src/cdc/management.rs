@@ -0,0 +1,186 @@
+//! CDC management functional descriptors.
+//! Structures representing the Call Management and Abstract Control
+//! Management functional descriptors, which describe how a communications
+//! interface handles calls and which requests it supports.
+//! Documentation: Sections 5.3.1 and 5.3.2 of the CDC PSTN 1.2
+//! specification.
+
+
+
+use crate::descriptor::{ DescriptorType, ToBytes };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+/// Call management capabilities of a communications interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CallManagementCapabilities(pub u8);
+
+impl CallManagementCapabilities {
+    /// The device handles call management itself.
+    pub const SELF_MANAGED: CallManagementCapabilities = CallManagementCapabilities(1 << 0);
+
+    /// The device can send and receive call management information over
+    /// the data interface.
+    pub const OVER_DATA_INTERFACE: CallManagementCapabilities = CallManagementCapabilities(1 << 1);
+
+    /// Returns `true` if all the given capabilities are supported.
+    pub const fn contains(&self, other: CallManagementCapabilities) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the given capabilities.
+    pub const fn with(self, other: CallManagementCapabilities) -> CallManagementCapabilities {
+        CallManagementCapabilities(self.0 | other.0)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CallManagementDescriptor {
+    /// Call management capabilities.
+    bmCapabilities: CallManagementCapabilities,
+
+    /// Interface number of the data interface used for call management.
+    bDataInterface: u8,
+}
+
+impl CallManagementDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 5;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::CallManagement.code();
+
+    /// Creates a call management functional descriptor.
+    pub const fn new(capabilities: CallManagementCapabilities, data_interface: u8) -> CallManagementDescriptor {
+        CallManagementDescriptor { bmCapabilities: capabilities, bDataInterface: data_interface }
+    }
+
+    /// Parses a call management functional descriptor from the start of the
+    /// given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<CallManagementDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::CallManagement, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok(CallManagementDescriptor {
+            bmCapabilities: CallManagementCapabilities(bytes[3]),
+            bDataInterface: bytes[4],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        [Self::LENGTH, Self::TYPE, Self::SUBTYPE, self.bmCapabilities.0, self.bDataInterface]
+    }
+
+    /// Call management capabilities.
+    pub const fn capabilities(&self) -> CallManagementCapabilities {
+        self.bmCapabilities
+    }
+
+    /// Interface number of the data interface used for call management.
+    pub const fn data_interface(&self) -> u8 {
+        self.bDataInterface
+    }
+}
+
+impl ToBytes for CallManagementDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// Requests and notifications supported by an Abstract Control Model
+/// interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ACMCapabilities(pub u8);
+
+impl ACMCapabilities {
+    /// `SET_COMM_FEATURE`, `CLEAR_COMM_FEATURE` and `GET_COMM_FEATURE`.
+    pub const COMM_FEATURE: ACMCapabilities = ACMCapabilities(1 << 0);
+
+    /// `SET_LINE_CODING`, `SET_CONTROL_LINE_STATE`, `GET_LINE_CODING` and
+    /// the `SERIAL_STATE` notification.
+    pub const LINE_CODING: ACMCapabilities = ACMCapabilities(1 << 1);
+
+    /// `SEND_BREAK`.
+    pub const SEND_BREAK: ACMCapabilities = ACMCapabilities(1 << 2);
+
+    /// The `NETWORK_CONNECTION` notification.
+    pub const NETWORK_CONNECTION: ACMCapabilities = ACMCapabilities(1 << 3);
+
+    /// Returns `true` if all the given capabilities are supported.
+    pub const fn contains(&self, other: ACMCapabilities) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the given capabilities.
+    pub const fn with(self, other: ACMCapabilities) -> ACMCapabilities {
+        ACMCapabilities(self.0 | other.0)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AbstractControlManagementDescriptor {
+    /// Supported requests and notifications.
+    bmCapabilities: ACMCapabilities,
+}
+
+impl AbstractControlManagementDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 4;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::AbstractControlManagement.code();
+
+    /// Creates an abstract control management functional descriptor.
+    pub const fn new(capabilities: ACMCapabilities) -> AbstractControlManagementDescriptor {
+        AbstractControlManagementDescriptor { bmCapabilities: capabilities }
+    }
+
+    /// Parses an abstract control management functional descriptor from the
+    /// start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<AbstractControlManagementDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::AbstractControlManagement, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok( AbstractControlManagementDescriptor { bmCapabilities: ACMCapabilities(bytes[3]) } )
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        [Self::LENGTH, Self::TYPE, Self::SUBTYPE, self.bmCapabilities.0]
+    }
+
+    /// Supported requests and notifications.
+    pub const fn capabilities(&self) -> ACMCapabilities {
+        self.bmCapabilities
+    }
+}
+
+impl ToBytes for AbstractControlManagementDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
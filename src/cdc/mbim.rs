@@ -0,0 +1,232 @@
+//! CDC MBIM functional descriptors.
+//! Structures representing the MBIM and MBIM Extended functional
+//! descriptors, which describe the control channel and the data path of a
+//! Mobile Broadband Interface Model function.
+//! Documentation: Sections 6.4 and 6.5 of the MBIM 1.0 specification.
+
+
+
+use crate::descriptor::{ BCDVersion, DescriptorType, ToBytes, read_u16 };
+use crate::error::*;
+use super::{ FunctionalSubtype, NetworkCapabilities };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MBIMDescriptor {
+    /// MBIM Specification release number.
+    bcdMBIMVersion: BCDVersion,
+
+    /// Maximum size of a control message, in bytes.
+    wMaxControlMessage: u16,
+
+    /// Number of pattern filters used to wake the host.
+    bNumberFilters: u8,
+
+    /// Maximum size of a pattern filter, in bytes.
+    bMaxFilterSize: u8,
+
+    /// Maximum segment size, in bytes.
+    wMaxSegmentSize: u16,
+
+    /// Optional requests supported by the function.
+    bmNetworkCapabilities: NetworkCapabilities,
+}
+
+impl MBIMDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 12;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::MBIM.code();
+
+    /// MBIM 1.0 specification release.
+    pub const MBIM10: BCDVersion = BCDVersion::from_raw(0x0100);
+
+    /// Creates an MBIM functional descriptor without pattern filters.
+    pub const fn new(version: BCDVersion, max_control_message: u16, max_segment_size: u16, capabilities: NetworkCapabilities) -> MBIMDescriptor {
+        MBIMDescriptor {
+            bcdMBIMVersion: version,
+            wMaxControlMessage: max_control_message,
+            bNumberFilters: 0,
+            bMaxFilterSize: 0,
+            wMaxSegmentSize: max_segment_size,
+            bmNetworkCapabilities: capabilities,
+        }
+    }
+
+    /// Sets the number and maximum size of the pattern filters used to wake
+    /// the host.
+    pub const fn filters(mut self, count: u8, max_size: u8) -> Self {
+        self.bNumberFilters = count;
+        self.bMaxFilterSize = max_size;
+        self
+    }
+
+    /// Parses an MBIM functional descriptor from the start of the given
+    /// buffer.
+    pub fn parse(bytes: &[u8]) -> Result<MBIMDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::MBIM, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok(MBIMDescriptor {
+            bcdMBIMVersion: BCDVersion::from_raw( read_u16(bytes, 3) ),
+            wMaxControlMessage: read_u16(bytes, 5),
+            bNumberFilters: bytes[7],
+            bMaxFilterSize: bytes[8],
+            wMaxSegmentSize: read_u16(bytes, 9),
+            bmNetworkCapabilities: NetworkCapabilities(bytes[11]),
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let version = self.bcdMBIMVersion.raw().to_le_bytes();
+        let control = self.wMaxControlMessage.to_le_bytes();
+        let segment = self.wMaxSegmentSize.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE, Self::SUBTYPE,
+            version[0], version[1],
+            control[0], control[1],
+            self.bNumberFilters,
+            self.bMaxFilterSize,
+            segment[0], segment[1],
+            self.bmNetworkCapabilities.0,
+        ]
+    }
+
+    /// MBIM Specification release number.
+    pub const fn version(&self) -> BCDVersion {
+        self.bcdMBIMVersion
+    }
+
+    /// Maximum size of a control message, in bytes.
+    pub const fn max_control_message(&self) -> u16 {
+        self.wMaxControlMessage
+    }
+
+    /// Number of pattern filters used to wake the host.
+    pub const fn num_filters(&self) -> u8 {
+        self.bNumberFilters
+    }
+
+    /// Maximum size of a pattern filter, in bytes.
+    pub const fn max_filter_size(&self) -> u8 {
+        self.bMaxFilterSize
+    }
+
+    /// Maximum segment size, in bytes.
+    /// MBIM carries IP packets, so this is the default MTU of the function.
+    pub const fn max_segment_size(&self) -> u16 {
+        self.wMaxSegmentSize
+    }
+
+    /// Optional requests supported by the function.
+    /// Only `MAX_DATAGRAM_SIZE` and `NTB_INPUT_SIZE_8` are defined for MBIM.
+    pub const fn capabilities(&self) -> NetworkCapabilities {
+        self.bmNetworkCapabilities
+    }
+}
+
+impl ToBytes for MBIMDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MBIMExtendedDescriptor {
+    /// MBIM Extended Functional Descriptor release number.
+    bcdMBIMExtendedVersion: BCDVersion,
+
+    /// Maximum number of outstanding command messages.
+    bMaxOutstandingCommandMessages: u8,
+
+    /// Operator preferred MTU, in bytes.
+    wMTU: u16,
+}
+
+impl MBIMExtendedDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 8;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::MBIMExtended.code();
+
+    /// Creates an MBIM extended functional descriptor.
+    pub const fn new(version: BCDVersion, max_outstanding_commands: u8, mtu: u16) -> MBIMExtendedDescriptor {
+        MBIMExtendedDescriptor {
+            bcdMBIMExtendedVersion: version,
+            bMaxOutstandingCommandMessages: max_outstanding_commands,
+            wMTU: mtu,
+        }
+    }
+
+    /// Parses an MBIM extended functional descriptor from the start of the
+    /// given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<MBIMExtendedDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::MBIMExtended, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok(MBIMExtendedDescriptor {
+            bcdMBIMExtendedVersion: BCDVersion::from_raw( read_u16(bytes, 3) ),
+            bMaxOutstandingCommandMessages: bytes[5],
+            wMTU: read_u16(bytes, 6),
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let version = self.bcdMBIMExtendedVersion.raw().to_le_bytes();
+        let mtu = self.wMTU.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE, Self::SUBTYPE,
+            version[0], version[1],
+            self.bMaxOutstandingCommandMessages,
+            mtu[0], mtu[1],
+        ]
+    }
+
+    /// MBIM Extended Functional Descriptor release number.
+    pub const fn version(&self) -> BCDVersion {
+        self.bcdMBIMExtendedVersion
+    }
+
+    /// Maximum number of outstanding command messages.
+    pub const fn max_outstanding_commands(&self) -> u8 {
+        self.bMaxOutstandingCommandMessages
+    }
+
+    /// Operator preferred MTU, in bytes.
+    pub const fn mtu(&self) -> u16 {
+        self.wMTU
+    }
+}
+
+impl ToBytes for MBIMExtendedDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
@@ -0,0 +1,74 @@
+//! Communications Device Class.
+//! Functional descriptors of CDC communications interfaces, including the
+//! networking models, the pairing of their control and data interfaces, the
+//! requests and notifications of serial ports, and the transfer blocks of
+//! NCM and MBIM data interfaces.
+
+
+
+mod acm;
+mod country;
+mod ethernet;
+mod functional;
+mod header;
+mod management;
+mod mbim;
+mod ncm;
+mod notification;
+mod ntb;
+mod parameters;
+mod subtype;
+mod union;
+
+
+
+pub use acm::*;
+pub use country::*;
+pub use ethernet::*;
+pub use functional::*;
+pub use header::*;
+pub use management::*;
+pub use mbim::*;
+pub use ncm::*;
+pub use notification::*;
+pub use ntb::*;
+pub use parameters::*;
+pub use subtype::*;
+pub use union::*;
+
+
+
+use crate::descriptor::DescriptorType;
+use crate::error::*;
+
+
+
+/// Descriptor type of the functional descriptors.
+const CS_INTERFACE: u8 = DescriptorType::ClassInterface.code();
+
+/// Checks the header of a functional descriptor of the given subtype at the
+/// start of the given buffer, and returns its length.
+/// The descriptor must hold at least `minimum` bytes.
+pub(crate) fn header(bytes: &[u8], subtype: FunctionalSubtype, minimum: u8) -> Result<usize, USBParseError> {
+    if bytes.len() < minimum as usize {
+        return Err( USBParseError::BufferTooShort(bytes.len()) );
+    }
+
+    if bytes[0] < minimum {
+        return Err( USBParseError::InvalidLength(bytes[0]) );
+    }
+
+    if bytes[1] != CS_INTERFACE {
+        return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+    }
+
+    if bytes[2] != subtype.code() {
+        return Err( USBParseError::InvalidDescriptorSubtype(bytes[2]) );
+    }
+
+    if bytes.len() < bytes[0] as usize {
+        return Err( USBParseError::BufferTooShort(bytes.len()) );
+    }
+
+    Ok( bytes[0] as usize )
+}
@@ -0,0 +1,116 @@
+//! CDC NCM functional descriptor.
+//! A structure representing the NCM functional descriptor, which lists the
+//! optional requests supported by a Network Control Model function.
+//! Documentation: Section 5.2.1 of the CDC NCM 1.0 specification.
+
+
+
+use crate::descriptor::{ BCDVersion, DescriptorType, ToBytes, read_u16 };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+/// Optional requests supported by an NCM or MBIM function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NetworkCapabilities(pub u8);
+
+impl NetworkCapabilities {
+    /// `SET_ETHERNET_PACKET_FILTER`.
+    pub const PACKET_FILTER: NetworkCapabilities = NetworkCapabilities(1 << 0);
+
+    /// `GET_NET_ADDRESS` and `SET_NET_ADDRESS`.
+    pub const NET_ADDRESS: NetworkCapabilities = NetworkCapabilities(1 << 1);
+
+    /// `SEND_ENCAPSULATED_COMMAND` and `GET_ENCAPSULATED_RESPONSE`.
+    pub const ENCAPSULATED_COMMAND: NetworkCapabilities = NetworkCapabilities(1 << 2);
+
+    /// `GET_MAX_DATAGRAM_SIZE` and `SET_MAX_DATAGRAM_SIZE`.
+    pub const MAX_DATAGRAM_SIZE: NetworkCapabilities = NetworkCapabilities(1 << 3);
+
+    /// `GET_CRC_MODE` and `SET_CRC_MODE`.
+    pub const CRC_MODE: NetworkCapabilities = NetworkCapabilities(1 << 4);
+
+    /// `SET_NTB_INPUT_SIZE` with an 8 byte parameter holding the maximum
+    /// number of datagrams.
+    pub const NTB_INPUT_SIZE_8: NetworkCapabilities = NetworkCapabilities(1 << 5);
+
+    /// Returns `true` if all the given capabilities are supported.
+    pub const fn contains(&self, other: NetworkCapabilities) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the given capabilities.
+    pub const fn with(self, other: NetworkCapabilities) -> NetworkCapabilities {
+        NetworkCapabilities(self.0 | other.0)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NCMDescriptor {
+    /// NCM Specification release number.
+    bcdNcmVersion: BCDVersion,
+
+    /// Optional requests supported by the function.
+    bmNetworkCapabilities: NetworkCapabilities,
+}
+
+impl NCMDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 6;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::NCM.code();
+
+    /// NCM 1.0 specification release.
+    pub const NCM10: BCDVersion = BCDVersion::from_raw(0x0100);
+
+    /// Creates an NCM functional descriptor.
+    pub const fn new(version: BCDVersion, capabilities: NetworkCapabilities) -> NCMDescriptor {
+        NCMDescriptor { bcdNcmVersion: version, bmNetworkCapabilities: capabilities }
+    }
+
+    /// Parses an NCM functional descriptor from the start of the given
+    /// buffer.
+    pub fn parse(bytes: &[u8]) -> Result<NCMDescriptor, USBParseError> {
+        if super::header(bytes, FunctionalSubtype::NCM, Self::LENGTH)? != Self::LENGTH as usize {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        Ok(NCMDescriptor {
+            bcdNcmVersion: BCDVersion::from_raw( read_u16(bytes, 3) ),
+            bmNetworkCapabilities: NetworkCapabilities(bytes[5]),
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let version = self.bcdNcmVersion.raw().to_le_bytes();
+
+        [Self::LENGTH, Self::TYPE, Self::SUBTYPE, version[0], version[1], self.bmNetworkCapabilities.0]
+    }
+
+    /// NCM Specification release number.
+    pub const fn version(&self) -> BCDVersion {
+        self.bcdNcmVersion
+    }
+
+    /// Optional requests supported by the function.
+    pub const fn capabilities(&self) -> NetworkCapabilities {
+        self.bmNetworkCapabilities
+    }
+}
+
+impl ToBytes for NCMDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
@@ -0,0 +1,181 @@
+//! Abstract Control Model notifications.
+//! Encoding and decoding of the PSTN notifications sent on the interrupt
+//! endpoint of serial ports, which report the state of the line.
+//! Documentation: Section 6.5 of the CDC PSTN 1.2 specification.
+
+
+
+use crate::descriptor::{ ToBytes, read_u16 };
+use crate::error::*;
+use super::ACMCapabilities;
+
+
+
+/// State of the UART carried by `SERIAL_STATE`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerialState(pub u16);
+
+impl SerialState {
+    /// Data Carrier Detect (`bRxCarrier`).
+    pub const DCD: SerialState = SerialState(1 << 0);
+
+    /// Data Set Ready (`bTxCarrier`).
+    pub const DSR: SerialState = SerialState(1 << 1);
+
+    /// A break was detected (`bBreak`).
+    pub const BREAK: SerialState = SerialState(1 << 2);
+
+    /// Ring Indicator (`bRingSignal`).
+    pub const RING: SerialState = SerialState(1 << 3);
+
+    /// A framing error occurred (`bFraming`).
+    pub const FRAMING: SerialState = SerialState(1 << 4);
+
+    /// A parity error occurred (`bParity`).
+    pub const PARITY: SerialState = SerialState(1 << 5);
+
+    /// Received data was lost (`bOverRun`).
+    pub const OVERRUN: SerialState = SerialState(1 << 6);
+
+    /// Returns `true` if all the given signals are set.
+    pub const fn contains(&self, other: SerialState) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Sets the given signals.
+    pub const fn with(self, other: SerialState) -> SerialState {
+        SerialState(self.0 | other.0)
+    }
+}
+
+
+
+/// A PSTN notification sent by an Abstract Control Model interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACMNotification {
+    /// `NETWORK_CONNECTION`, telling if the line is connected.
+    NetworkConnection { interface: u16, connected: bool },
+
+    /// `SERIAL_STATE`.
+    SerialState { interface: u16, state: SerialState },
+}
+
+impl ACMNotification {
+    /// Size of the notification header in bytes.
+    pub const HEADER_LENGTH: usize = 8;
+
+    /// `bmRequestType` of the notifications.
+    pub const REQUEST_TYPE: u8 = 0xA1;
+
+    /// Notification code of `NETWORK_CONNECTION`.
+    pub const NETWORK_CONNECTION: u8 = 0x00;
+
+    /// Notification code of `SERIAL_STATE`.
+    pub const SERIAL_STATE: u8 = 0x20;
+
+    /// Parses a notification from the start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<ACMNotification, USBParseError> {
+        if bytes.len() < Self::HEADER_LENGTH {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::REQUEST_TYPE {
+            return Err( USBParseError::InvalidRequestType(bytes[0]) );
+        }
+
+        let (value, interface, length) = (read_u16(bytes, 2), read_u16(bytes, 4), read_u16(bytes, 6));
+
+        let size = match bytes[1] {
+            Self::NETWORK_CONNECTION => 0,
+            Self::SERIAL_STATE => 2,
+
+            code => return Err( USBParseError::UnknownNotification(code) ),
+        };
+
+        if length != size {
+            return Err( USBParseError::InvalidRequestLength(length) );
+        }
+
+        if bytes.len() < Self::HEADER_LENGTH + size as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        match bytes[1] {
+            Self::NETWORK_CONNECTION => Ok( ACMNotification::NetworkConnection { interface, connected: value != 0 } ),
+            _ => Ok( ACMNotification::SerialState { interface, state: SerialState( read_u16(bytes, 8) ) } ),
+        }
+    }
+
+    /// Encodes the notification at compile time.
+    /// Only the first `size()` bytes belong to the notification, the data of
+    /// `NETWORK_CONNECTION` is left zeroed.
+    pub const fn to_array(&self) -> [u8; Self::HEADER_LENGTH + 2] {
+        let value = self.value().to_le_bytes();
+        let interface = self.interface().to_le_bytes();
+        let length = self.length().to_le_bytes();
+
+        let data = match self {
+            ACMNotification::SerialState { state, .. } => state.0.to_le_bytes(),
+            _ => [0, 0],
+        };
+
+        [
+            Self::REQUEST_TYPE, self.code(),
+            value[0], value[1],
+            interface[0], interface[1],
+            length[0], length[1],
+            data[0], data[1],
+        ]
+    }
+
+    /// Notification code.
+    pub const fn code(&self) -> u8 {
+        match self {
+            ACMNotification::NetworkConnection { .. } => Self::NETWORK_CONNECTION,
+            ACMNotification::SerialState { .. }       => Self::SERIAL_STATE,
+        }
+    }
+
+    /// `wValue` of the notification.
+    pub const fn value(&self) -> u16 {
+        match self {
+            ACMNotification::NetworkConnection { connected, .. } => *connected as u16,
+            _ => 0,
+        }
+    }
+
+    /// Interface sending the notification.
+    pub const fn interface(&self) -> u16 {
+        match self {
+            ACMNotification::NetworkConnection { interface, .. }
+            | ACMNotification::SerialState { interface, .. } => *interface,
+        }
+    }
+
+    /// Size of the data following the header in bytes.
+    pub const fn length(&self) -> u16 {
+        match self {
+            ACMNotification::SerialState { .. } => 2,
+            _ => 0,
+        }
+    }
+
+    /// Capability the interface must advertise in its abstract control
+    /// management functional descriptor to send the notification.
+    pub const fn capability(&self) -> ACMCapabilities {
+        match self {
+            ACMNotification::NetworkConnection { .. } => ACMCapabilities::NETWORK_CONNECTION,
+            ACMNotification::SerialState { .. }       => ACMCapabilities::LINE_CODING,
+        }
+    }
+}
+
+impl ToBytes for ACMNotification {
+    fn size(&self) -> usize {
+        Self::HEADER_LENGTH + self.length() as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..self.size()].to_bytes(buffer)
+    }
+}
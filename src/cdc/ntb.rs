@@ -0,0 +1,566 @@
+//! Network Transfer Blocks.
+//! Parsing of the NTBs received on the data interface of NCM and MBIM
+//! functions, and packing of outgoing datagrams into NTBs.
+//! Documentation: Section 3 of the CDC NCM 1.0 specification.
+
+
+
+use crate::descriptor::{ read_u16, read_u32, write_u16, write_u32 };
+use crate::error::*;
+use super::NtbAlignment;
+
+
+
+/// Signature of a 16 bit NTB header: "NCMH".
+const NTH16_SIGNATURE: u32 = 0x484D_434E;
+
+/// Signature of a 32 bit NTB header: "ncmh".
+const NTH32_SIGNATURE: u32 = 0x686D_636E;
+
+
+
+/// Format of an NTB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NtbFormat {
+    /// NTB with 16 bit offsets and lengths.
+    Ntb16,
+
+    /// NTB with 32 bit offsets and lengths.
+    Ntb32,
+}
+
+impl NtbFormat {
+    /// Signature of the NTB header.
+    pub const fn nth_signature(&self) -> u32 {
+        match self {
+            NtbFormat::Ntb16 => NTH16_SIGNATURE,
+            NtbFormat::Ntb32 => NTH32_SIGNATURE,
+        }
+    }
+
+    /// Signature of the NDPs of NCM functions, whose datagrams carry a CRC
+    /// or not.
+    pub const fn ndp_signature(&self, crc: bool) -> u32 {
+        match (self, crc) {
+            (NtbFormat::Ntb16, false) => 0x304D_434E,
+            (NtbFormat::Ntb16, true) => 0x314D_434E,
+            (NtbFormat::Ntb32, false) => 0x306D_636E,
+            (NtbFormat::Ntb32, true) => 0x316D_636E,
+        }
+    }
+
+    /// Size of the NTB header in bytes.
+    pub const fn nth_length(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => 12,
+            NtbFormat::Ntb32 => 16,
+        }
+    }
+
+    /// Size of the NDP header in bytes, before its datagram pointers.
+    pub const fn ndp_header_length(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => 8,
+            NtbFormat::Ntb32 => 16,
+        }
+    }
+
+    /// Size of a datagram pointer in bytes.
+    pub const fn pointer_length(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => 4,
+            NtbFormat::Ntb32 => 8,
+        }
+    }
+
+    /// Largest NTB of the format.
+    pub const fn max_block_length(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => u16::MAX as usize,
+            NtbFormat::Ntb32 => u32::MAX as usize,
+        }
+    }
+
+    /// Smallest NDP: one datagram pointer and the terminating pointer.
+    const fn min_ndp_length(&self) -> usize {
+        self.ndp_header_length() + 2 * self.pointer_length()
+    }
+
+    /// Reads an offset or length field.
+    const fn read(&self, bytes: &[u8], offset: usize) -> usize {
+        match self {
+            NtbFormat::Ntb16 => read_u16(bytes, offset) as usize,
+            NtbFormat::Ntb32 => read_u32(bytes, offset) as usize,
+        }
+    }
+
+    /// Writes an offset or length field.
+    /// The value must fit in the field.
+    fn write(&self, bytes: &mut [u8], offset: usize, value: usize) {
+        match self {
+            NtbFormat::Ntb16 => write_u16(bytes, offset, value as u16),
+            NtbFormat::Ntb32 => write_u32(bytes, offset, value as u32),
+        }
+    }
+
+    /// Offset of the block length in the NTB header.
+    const fn block_length_offset(&self) -> usize {
+        8
+    }
+
+    /// Offset of the first NDP index in the NTB header.
+    const fn ndp_index_offset(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => 10,
+            NtbFormat::Ntb32 => 12,
+        }
+    }
+
+    /// Offset of the next NDP index in the NDP header.
+    const fn next_ndp_offset(&self) -> usize {
+        match self {
+            NtbFormat::Ntb16 => 6,
+            NtbFormat::Ntb32 => 8,
+        }
+    }
+}
+
+
+
+/// An NTB borrowed from a received transfer.
+/// All NDPs and datagram pointers are checked to lie within the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ntb<'a> {
+    /// The whole block, up to its block length.
+    bytes: &'a [u8],
+
+    /// Format of the block.
+    format: NtbFormat,
+}
+
+impl<'a> Ntb<'a> {
+    /// Parses an NTB from the start of the given buffer.
+    /// The format is told by the signature of the header. NDPs of any
+    /// signature are accepted, and must not form a loop.
+    pub fn parse(bytes: &'a [u8]) -> Result<Ntb<'a>, USBParseError> {
+        if bytes.len() < 4 {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let format = match read_u32(bytes, 0) {
+            NTH16_SIGNATURE => NtbFormat::Ntb16,
+            NTH32_SIGNATURE => NtbFormat::Ntb32,
+            signature => return Err( USBParseError::InvalidNtbSignature(signature) ),
+        };
+
+        if bytes.len() < format.nth_length() {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let header = read_u16(bytes, 4) as usize;
+
+        if header != format.nth_length() {
+            return Err( USBParseError::InvalidNtbLength(header as u32) );
+        }
+
+        let length = format.read(bytes, format.block_length_offset());
+
+        if length < format.nth_length() + format.min_ndp_length() {
+            return Err( USBParseError::InvalidNtbLength(length as u32) );
+        }
+
+        if bytes.len() < length {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let ntb = Ntb { bytes: &bytes[..length], format };
+
+        let mut index = format.read(bytes, format.ndp_index_offset());
+        let mut remaining = length / format.min_ndp_length();
+
+        if index == 0 {
+            return Err( USBParseError::NtbIndexOutOfRange(0) );
+        }
+
+        while index != 0 {
+            if remaining == 0 {
+                return Err( USBParseError::NtbIndexOutOfRange(index as u32) );
+            }
+
+            index = ntb.check_ndp(index)?;
+            remaining -= 1;
+        }
+
+        Ok(ntb)
+    }
+
+    /// Checks the NDP at the given offset and its datagram pointers.
+    /// Returns the offset of the next NDP.
+    fn check_ndp(&self, index: usize) -> Result<usize, USBParseError> {
+        let format = self.format;
+
+        if !index.is_multiple_of(4) || (index < format.nth_length()) || (format.ndp_header_length() > self.bytes.len().saturating_sub(index)) {
+            return Err( USBParseError::NtbIndexOutOfRange(index as u32) );
+        }
+
+        let length = read_u16(self.bytes, index + 4) as usize;
+
+        if (length < format.min_ndp_length()) || !length.is_multiple_of(4) {
+            return Err( USBParseError::InvalidNtbLength(length as u32) );
+        }
+
+        if length > self.bytes.len() - index {
+            return Err( USBParseError::NtbIndexOutOfRange(index as u32) );
+        }
+
+        for (offset, size) in (Pointers { ndp: &self.bytes[index..index + length], format }) {
+            if (offset > self.bytes.len()) || (size > self.bytes.len() - offset) {
+                return Err( USBParseError::NtbIndexOutOfRange(offset as u32) );
+            }
+        }
+
+        Ok( format.read(self.bytes, index + format.next_ndp_offset()) )
+    }
+
+    /// Raw bytes of the block.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Format of the block.
+    pub const fn format(&self) -> NtbFormat {
+        self.format
+    }
+
+    /// Sequence number of the block.
+    pub const fn sequence(&self) -> u16 {
+        read_u16(self.bytes, 6)
+    }
+
+    /// Size of the block in bytes.
+    pub const fn block_length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// NDPs of the block, in chain order.
+    pub fn ndps(&self) -> Ndps<'a> {
+        Ndps {
+            block: self.bytes,
+            format: self.format,
+            index: self.format.read(self.bytes, self.format.ndp_index_offset()),
+        }
+    }
+
+    /// Datagrams of all the NDPs of the block.
+    pub fn datagrams(&self) -> NtbDatagrams<'a> {
+        NtbDatagrams { ndps: self.ndps(), current: None }
+    }
+}
+
+/// An NDP: a list of datagrams of an NTB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ndp<'a> {
+    /// The whole block.
+    block: &'a [u8],
+
+    /// The NDP, up to its length.
+    ndp: &'a [u8],
+
+    /// Format of the block.
+    format: NtbFormat,
+}
+
+impl<'a> Ndp<'a> {
+    /// Signature of the NDP.
+    /// NCM signatures are given by `NtbFormat::ndp_signature`, MBIM
+    /// signatures also hold a session ID.
+    pub const fn signature(&self) -> u32 {
+        read_u32(self.ndp, 0)
+    }
+
+    /// Size of the NDP in bytes.
+    pub const fn len(&self) -> usize {
+        self.ndp.len()
+    }
+
+    /// Returns `true` if the NDP lists no datagram.
+    pub fn is_empty(&self) -> bool {
+        self.datagrams().next().is_none()
+    }
+
+    /// Datagrams listed by the NDP, up to the first null pointer.
+    pub fn datagrams(&self) -> Datagrams<'a> {
+        Datagrams {
+            block: self.block,
+            pointers: Pointers { ndp: self.ndp, format: self.format },
+        }
+    }
+}
+
+/// Iterator over the NDPs of an NTB.
+#[derive(Clone, Debug)]
+pub struct Ndps<'a> {
+    /// The whole block.
+    block: &'a [u8],
+
+    /// Format of the block.
+    format: NtbFormat,
+
+    /// Offset of the next NDP, or 0 at the end of the chain.
+    index: usize,
+}
+
+impl<'a> Iterator for Ndps<'a> {
+    type Item = Ndp<'a>;
+
+    fn next(&mut self) -> Option<Ndp<'a>> {
+        if self.index == 0 {
+            return None;
+        }
+
+        let length = read_u16(self.block, self.index + 4) as usize;
+        let ndp = &self.block[self.index..self.index + length];
+
+        self.index = self.format.read(ndp, self.format.next_ndp_offset());
+
+        Some( Ndp { block: self.block, ndp, format: self.format } )
+    }
+}
+
+/// Iterator over the datagram pointers of an NDP, as offsets and lengths.
+#[derive(Clone, Debug)]
+struct Pointers<'a> {
+    /// Pointers not yet visited, after the NDP header.
+    ndp: &'a [u8],
+
+    /// Format of the block.
+    format: NtbFormat,
+}
+
+impl<'a> Iterator for Pointers<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let format = self.format;
+
+        if self.ndp.len() < format.ndp_header_length() + format.pointer_length() {
+            return None;
+        }
+
+        let pointer = &self.ndp[format.ndp_header_length()..];
+        let half = format.pointer_length() / 2;
+
+        let offset = format.read(pointer, 0);
+        let length = format.read(pointer, half);
+
+        if (offset == 0) || (length == 0) {
+            self.ndp = &[];
+            return None;
+        }
+
+        self.ndp = &self.ndp[format.pointer_length()..];
+
+        Some( (offset, length) )
+    }
+}
+
+/// Iterator over the datagrams of an NDP.
+#[derive(Clone, Debug)]
+pub struct Datagrams<'a> {
+    /// The whole block.
+    block: &'a [u8],
+
+    /// Datagram pointers not yet visited.
+    pointers: Pointers<'a>,
+}
+
+impl<'a> Iterator for Datagrams<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let (offset, length) = self.pointers.next()?;
+
+        Some( &self.block[offset..offset + length] )
+    }
+}
+
+/// Iterator over the datagrams of all the NDPs of an NTB.
+#[derive(Clone, Debug)]
+pub struct NtbDatagrams<'a> {
+    /// NDPs not yet visited.
+    ndps: Ndps<'a>,
+
+    /// Datagrams of the current NDP.
+    current: Option<Datagrams<'a>>,
+}
+
+impl<'a> Iterator for NtbDatagrams<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        loop {
+            if let Some(datagram) = self.current.as_mut().and_then(|datagrams| datagrams.next()) {
+                return Some(datagram);
+            }
+
+            self.current = Some( self.ndps.next()?.datagrams() );
+        }
+    }
+}
+
+
+
+/// Packs outgoing datagrams into an NTB with a single NDP of up to `N`
+/// datagrams.
+/// Datagrams are placed after the header following the alignment rules of
+/// the direction, and the NDP is placed after the last datagram. Padding
+/// bytes are zeroed.
+#[derive(Debug)]
+pub struct NtbBuilder<'b, const N: usize> {
+    /// Output buffer, limited to the maximum NTB size.
+    buffer: &'b mut [u8],
+
+    /// Format of the block.
+    format: NtbFormat,
+
+    /// Alignment rules of the direction.
+    alignment: NtbAlignment,
+
+    /// Sequence number of the block.
+    sequence: u16,
+
+    /// Signature of the NDP.
+    signature: u32,
+
+    /// Offsets and lengths of the datagrams packed so far.
+    datagrams: [(usize, usize); N],
+
+    /// Number of datagrams packed so far.
+    count: usize,
+
+    /// End of the last datagram.
+    end: usize,
+}
+
+impl<'b, const N: usize> NtbBuilder<'b, N> {
+    /// Starts an NTB in the given buffer.
+    /// The buffer should be no longer than the maximum NTB size of the
+    /// direction. The NDP signature is that of NCM without CRC.
+    pub fn new(buffer: &'b mut [u8], format: NtbFormat, alignment: NtbAlignment, sequence: u16) -> NtbBuilder<'b, N> {
+        NtbBuilder {
+            buffer,
+            format,
+            alignment,
+            sequence,
+            signature: format.ndp_signature(false),
+            datagrams: [(0, 0); N],
+            count: 0,
+            end: format.nth_length(),
+        }
+    }
+
+    /// Sets the signature of the NDP, such as an MBIM session signature.
+    pub fn ndp_signature(mut self, signature: u32) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    /// Number of datagrams packed so far.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Size of the block with the datagrams packed so far.
+    pub fn size(&self) -> usize {
+        self.layout(self.count, self.end).1
+    }
+
+    /// Returns `true` if a datagram of the given length fits in the block.
+    pub fn fits(&self, length: usize) -> bool {
+        self.check(length).is_ok()
+    }
+
+    /// Packs a datagram into the block.
+    pub fn push(&mut self, datagram: &[u8]) -> Result<(), USBSerializeError> {
+        let offset = self.check(datagram.len())?;
+
+        self.buffer[self.end..offset].fill(0);
+        self.buffer[offset..offset + datagram.len()].copy_from_slice(datagram);
+
+        self.datagrams[self.count] = (offset, datagram.len());
+        self.count += 1;
+        self.end = offset + datagram.len();
+
+        Ok(())
+    }
+
+    /// Writes the header and the NDP of the block.
+    /// Returns the size of the block.
+    pub fn finish(self) -> Result<usize, USBSerializeError> {
+        let format = self.format;
+        let (ndp, size) = self.layout(self.count, self.end);
+
+        if size > self.buffer.len() {
+            return Err( USBSerializeError::BufferTooShort(size) );
+        }
+
+        let buffer = &mut self.buffer[..size];
+
+        buffer[self.end..].fill(0);
+
+        write_u32(buffer, 0, format.nth_signature());
+        write_u16(buffer, 4, format.nth_length() as u16);
+        write_u16(buffer, 6, self.sequence);
+        format.write(buffer, format.block_length_offset(), size);
+        format.write(buffer, format.ndp_index_offset(), ndp);
+
+        write_u32(buffer, ndp, self.signature);
+        write_u16(buffer, ndp + 4, (size - ndp) as u16);
+
+        let half = format.pointer_length() / 2;
+
+        for (i, (offset, length)) in self.datagrams[..self.count].iter().enumerate() {
+            let pointer = ndp + format.ndp_header_length() + i * format.pointer_length();
+
+            format.write(buffer, pointer, *offset);
+            format.write(buffer, pointer + half, *length);
+        }
+
+        Ok(size)
+    }
+
+    /// Checks that a datagram of the given length fits in the block.
+    /// Returns its offset.
+    fn check(&self, length: usize) -> Result<usize, USBSerializeError> {
+        if length == 0 {
+            return Err( USBSerializeError::EmptyDatagram );
+        }
+
+        if self.count == N {
+            return Err( USBSerializeError::TooManyDatagrams(N) );
+        }
+
+        let offset = self.alignment.datagram_offset(self.end);
+        let (ndp, size) = self.layout(self.count + 1, offset + length);
+
+        if (size > self.format.max_block_length()) || (size - ndp > u16::MAX as usize) {
+            return Err( USBSerializeError::TooLong(size) );
+        }
+
+        if size > self.buffer.len() {
+            return Err( USBSerializeError::BufferTooShort(size) );
+        }
+
+        Ok(offset)
+    }
+
+    /// Offset of the NDP and size of the block for the given number of
+    /// datagrams ending at the given offset.
+    fn layout(&self, count: usize, end: usize) -> (usize, usize) {
+        let format = self.format;
+        let ndp = self.alignment.ndp_offset(end);
+        let pointers = if count == 0 { 2 } else { count + 1 };
+
+        (ndp, ndp + format.ndp_header_length() + pointers * format.pointer_length())
+    }
+}
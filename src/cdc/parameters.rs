@@ -0,0 +1,212 @@
+//! NTB parameters.
+//! A structure representing the NTB Parameter Structure returned by
+//! `GET_NTB_PARAMETERS`, which gives the sizes and alignment rules of the
+//! transfer blocks in both directions.
+//! Documentation: Section 6.2.1 of the CDC NCM 1.0 specification.
+
+
+
+use crate::descriptor::{ ToBytes, read_u16, read_u32 };
+use crate::error::*;
+
+
+
+/// NTB formats supported by a function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NtbFormats(pub u16);
+
+impl NtbFormats {
+    /// 16 bit NTBs, which all functions support.
+    pub const NTB16: NtbFormats = NtbFormats(1 << 0);
+
+    /// 32 bit NTBs.
+    pub const NTB32: NtbFormats = NtbFormats(1 << 1);
+
+    /// Returns `true` if all the given formats are supported.
+    pub const fn contains(&self, other: NtbFormats) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the given formats.
+    pub const fn with(self, other: NtbFormats) -> NtbFormats {
+        NtbFormats(self.0 | other.0)
+    }
+}
+
+
+
+/// Alignment rules of the NTBs sent in one direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NtbAlignment {
+    /// Datagrams start at an offset whose remainder modulo this divisor is
+    /// `remainder`.
+    pub divisor: u16,
+
+    /// Remainder of the datagram offsets modulo `divisor`.
+    pub remainder: u16,
+
+    /// Alignment of the NDPs, a power of two of at least 4.
+    pub ndp_alignment: u16,
+}
+
+impl NtbAlignment {
+    /// Loosest alignment rules: datagrams at any offset, NDPs on 4 bytes.
+    pub const MINIMUM: NtbAlignment = NtbAlignment { divisor: 1, remainder: 0, ndp_alignment: 4 };
+
+    /// First offset at or after the given one where a datagram may start.
+    pub const fn datagram_offset(&self, offset: usize) -> usize {
+        let divisor = if self.divisor == 0 { 1 } else { self.divisor as usize };
+        let remainder = self.remainder as usize % divisor;
+
+        offset + (remainder + divisor - offset % divisor) % divisor
+    }
+
+    /// First offset at or after the given one where an NDP may start.
+    pub const fn ndp_offset(&self, offset: usize) -> usize {
+        let alignment = if self.ndp_alignment < 4 { 4 } else { self.ndp_alignment as usize };
+
+        offset.div_ceil(alignment) * alignment
+    }
+}
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NtbParameters {
+    /// NTB formats supported by the function.
+    bmNtbFormatsSupported: NtbFormats,
+
+    /// Maximum size of the IN NTBs, in bytes.
+    dwNtbInMaxSize: u32,
+
+    /// Alignment rules of the IN NTBs.
+    input: NtbAlignment,
+
+    /// Maximum size of the OUT NTBs, in bytes.
+    dwNtbOutMaxSize: u32,
+
+    /// Alignment rules of the OUT NTBs.
+    output: NtbAlignment,
+
+    /// Maximum number of datagrams in an OUT NTB, or 0 for no limit.
+    wNtbOutMaxDatagrams: u16,
+}
+
+impl NtbParameters {
+    /// Size of this structure in bytes.
+    pub const LENGTH: u16 = 28;
+
+    /// Request code of `GET_NTB_PARAMETERS`.
+    pub const REQUEST: u8 = 0x80;
+
+    /// Creates an NTB Parameter Structure.
+    pub const fn new(formats: NtbFormats, in_max_size: u32, input: NtbAlignment, out_max_size: u32, output: NtbAlignment, out_max_datagrams: u16) -> NtbParameters {
+        NtbParameters {
+            bmNtbFormatsSupported: formats,
+            dwNtbInMaxSize: in_max_size,
+            input,
+            dwNtbOutMaxSize: out_max_size,
+            output,
+            wNtbOutMaxDatagrams: out_max_datagrams,
+        }
+    }
+
+    /// Parses an NTB Parameter Structure from the start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<NtbParameters, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if read_u16(bytes, 0) != Self::LENGTH {
+            return Err( USBParseError::InvalidNtbLength( read_u16(bytes, 0) as u32 ) );
+        }
+
+        Ok(NtbParameters {
+            bmNtbFormatsSupported: NtbFormats( read_u16(bytes, 2) ),
+            dwNtbInMaxSize: read_u32(bytes, 4),
+            input: NtbAlignment {
+                divisor: read_u16(bytes, 8),
+                remainder: read_u16(bytes, 10),
+                ndp_alignment: read_u16(bytes, 12),
+            },
+            dwNtbOutMaxSize: read_u32(bytes, 16),
+            output: NtbAlignment {
+                divisor: read_u16(bytes, 20),
+                remainder: read_u16(bytes, 22),
+                ndp_alignment: read_u16(bytes, 24),
+            },
+            wNtbOutMaxDatagrams: read_u16(bytes, 26),
+        })
+    }
+
+    /// Encodes the structure.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let length = Self::LENGTH.to_le_bytes();
+        let formats = self.bmNtbFormatsSupported.0.to_le_bytes();
+        let in_max = self.dwNtbInMaxSize.to_le_bytes();
+        let in_divisor = self.input.divisor.to_le_bytes();
+        let in_remainder = self.input.remainder.to_le_bytes();
+        let in_alignment = self.input.ndp_alignment.to_le_bytes();
+        let out_max = self.dwNtbOutMaxSize.to_le_bytes();
+        let out_divisor = self.output.divisor.to_le_bytes();
+        let out_remainder = self.output.remainder.to_le_bytes();
+        let out_alignment = self.output.ndp_alignment.to_le_bytes();
+        let out_datagrams = self.wNtbOutMaxDatagrams.to_le_bytes();
+
+        [
+            length[0], length[1],
+            formats[0], formats[1],
+            in_max[0], in_max[1], in_max[2], in_max[3],
+            in_divisor[0], in_divisor[1],
+            in_remainder[0], in_remainder[1],
+            in_alignment[0], in_alignment[1],
+            0, 0,
+            out_max[0], out_max[1], out_max[2], out_max[3],
+            out_divisor[0], out_divisor[1],
+            out_remainder[0], out_remainder[1],
+            out_alignment[0], out_alignment[1],
+            out_datagrams[0], out_datagrams[1],
+        ]
+    }
+
+    /// NTB formats supported by the function.
+    pub const fn formats(&self) -> NtbFormats {
+        self.bmNtbFormatsSupported
+    }
+
+    /// Maximum size of the IN NTBs, in bytes.
+    pub const fn in_max_size(&self) -> u32 {
+        self.dwNtbInMaxSize
+    }
+
+    /// Alignment rules of the IN NTBs, followed by the device.
+    pub const fn input(&self) -> NtbAlignment {
+        self.input
+    }
+
+    /// Maximum size of the OUT NTBs, in bytes.
+    pub const fn out_max_size(&self) -> u32 {
+        self.dwNtbOutMaxSize
+    }
+
+    /// Alignment rules of the OUT NTBs, followed by the host.
+    pub const fn output(&self) -> NtbAlignment {
+        self.output
+    }
+
+    /// Maximum number of datagrams in an OUT NTB, or 0 for no limit.
+    pub const fn out_max_datagrams(&self) -> u16 {
+        self.wNtbOutMaxDatagrams
+    }
+}
+
+impl ToBytes for NtbParameters {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
@@ -0,0 +1,205 @@
+//! CDC functional descriptor subtypes.
+//! The `bDescriptorSubtype` codes of the class specific interface
+//! descriptors of communications interfaces.
+//! Documentation: Table 13 of the CDC 1.2 specification.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionalSubtype {
+    /// Header functional descriptor (00h).
+    Header,
+
+    /// Call Management functional descriptor (01h).
+    CallManagement,
+
+    /// Abstract Control Management functional descriptor (02h).
+    AbstractControlManagement,
+
+    /// Direct Line Management functional descriptor (03h).
+    DirectLineManagement,
+
+    /// Telephone Ringer functional descriptor (04h).
+    TelephoneRinger,
+
+    /// Telephone Call and Line State Reporting Capabilities functional
+    /// descriptor (05h).
+    TelephoneCallStateReporting,
+
+    /// Union functional descriptor (06h).
+    Union,
+
+    /// Country Selection functional descriptor (07h).
+    CountrySelection,
+
+    /// Telephone Operational Modes functional descriptor (08h).
+    TelephoneOperationalModes,
+
+    /// USB Terminal functional descriptor (09h).
+    USBTerminal,
+
+    /// Network Channel Terminal functional descriptor (0Ah).
+    NetworkChannelTerminal,
+
+    /// Protocol Unit functional descriptor (0Bh).
+    ProtocolUnit,
+
+    /// Extension Unit functional descriptor (0Ch).
+    ExtensionUnit,
+
+    /// Multi-Channel Management functional descriptor (0Dh).
+    MultiChannelManagement,
+
+    /// CAPI Control Management functional descriptor (0Eh).
+    CAPIControlManagement,
+
+    /// Ethernet Networking functional descriptor (0Fh).
+    EthernetNetworking,
+
+    /// ATM Networking functional descriptor (10h).
+    ATMNetworking,
+
+    /// Wireless Handset Control Model functional descriptor (11h).
+    WirelessHandsetControl,
+
+    /// Mobile Direct Line Model functional descriptor (12h).
+    MobileDirectLine,
+
+    /// MDLM Detail functional descriptor (13h).
+    MobileDirectLineDetail,
+
+    /// Device Management Model functional descriptor (14h).
+    DeviceManagement,
+
+    /// OBEX functional descriptor (15h).
+    OBEX,
+
+    /// Command Set functional descriptor (16h).
+    CommandSet,
+
+    /// Command Set Detail functional descriptor (17h).
+    CommandSetDetail,
+
+    /// Telephone Control Model functional descriptor (18h).
+    TelephoneControl,
+
+    /// OBEX Service Identifier functional descriptor (19h).
+    OBEXServiceIdentifier,
+
+    /// NCM functional descriptor (1Ah).
+    NCM,
+
+    /// MBIM functional descriptor (1Bh).
+    MBIM,
+
+    /// MBIM Extended functional descriptor (1Ch).
+    MBIMExtended,
+
+    /// Vendor specific functional descriptor (80h to FEh), kept as is.
+    VendorSpecific(u8),
+
+    /// Unknown functional descriptor subtype, kept as is.
+    Other(u8),
+}
+
+impl FunctionalSubtype {
+    /// Decodes a functional descriptor subtype without failing.
+    /// Unknown codes are kept in the `Other` variant so they convert back
+    /// to the same byte. Use `TryFrom<u8>` to reject them instead.
+    pub const fn decode(code: u8) -> FunctionalSubtype {
+        match code {
+            0x00 => FunctionalSubtype::Header,
+            0x01 => FunctionalSubtype::CallManagement,
+            0x02 => FunctionalSubtype::AbstractControlManagement,
+            0x03 => FunctionalSubtype::DirectLineManagement,
+            0x04 => FunctionalSubtype::TelephoneRinger,
+            0x05 => FunctionalSubtype::TelephoneCallStateReporting,
+            0x06 => FunctionalSubtype::Union,
+            0x07 => FunctionalSubtype::CountrySelection,
+            0x08 => FunctionalSubtype::TelephoneOperationalModes,
+            0x09 => FunctionalSubtype::USBTerminal,
+            0x0A => FunctionalSubtype::NetworkChannelTerminal,
+            0x0B => FunctionalSubtype::ProtocolUnit,
+            0x0C => FunctionalSubtype::ExtensionUnit,
+            0x0D => FunctionalSubtype::MultiChannelManagement,
+            0x0E => FunctionalSubtype::CAPIControlManagement,
+            0x0F => FunctionalSubtype::EthernetNetworking,
+            0x10 => FunctionalSubtype::ATMNetworking,
+            0x11 => FunctionalSubtype::WirelessHandsetControl,
+            0x12 => FunctionalSubtype::MobileDirectLine,
+            0x13 => FunctionalSubtype::MobileDirectLineDetail,
+            0x14 => FunctionalSubtype::DeviceManagement,
+            0x15 => FunctionalSubtype::OBEX,
+            0x16 => FunctionalSubtype::CommandSet,
+            0x17 => FunctionalSubtype::CommandSetDetail,
+            0x18 => FunctionalSubtype::TelephoneControl,
+            0x19 => FunctionalSubtype::OBEXServiceIdentifier,
+            0x1A => FunctionalSubtype::NCM,
+            0x1B => FunctionalSubtype::MBIM,
+            0x1C => FunctionalSubtype::MBIMExtended,
+
+            0x80..=0xFE => FunctionalSubtype::VendorSpecific(code),
+
+            _ => FunctionalSubtype::Other(code),
+        }
+    }
+
+    /// Raw functional descriptor subtype.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            FunctionalSubtype::Header                      => 0x00,
+            FunctionalSubtype::CallManagement              => 0x01,
+            FunctionalSubtype::AbstractControlManagement   => 0x02,
+            FunctionalSubtype::DirectLineManagement        => 0x03,
+            FunctionalSubtype::TelephoneRinger             => 0x04,
+            FunctionalSubtype::TelephoneCallStateReporting => 0x05,
+            FunctionalSubtype::Union                       => 0x06,
+            FunctionalSubtype::CountrySelection            => 0x07,
+            FunctionalSubtype::TelephoneOperationalModes   => 0x08,
+            FunctionalSubtype::USBTerminal                 => 0x09,
+            FunctionalSubtype::NetworkChannelTerminal      => 0x0A,
+            FunctionalSubtype::ProtocolUnit                => 0x0B,
+            FunctionalSubtype::ExtensionUnit               => 0x0C,
+            FunctionalSubtype::MultiChannelManagement      => 0x0D,
+            FunctionalSubtype::CAPIControlManagement       => 0x0E,
+            FunctionalSubtype::EthernetNetworking          => 0x0F,
+            FunctionalSubtype::ATMNetworking               => 0x10,
+            FunctionalSubtype::WirelessHandsetControl      => 0x11,
+            FunctionalSubtype::MobileDirectLine            => 0x12,
+            FunctionalSubtype::MobileDirectLineDetail      => 0x13,
+            FunctionalSubtype::DeviceManagement            => 0x14,
+            FunctionalSubtype::OBEX                        => 0x15,
+            FunctionalSubtype::CommandSet                  => 0x16,
+            FunctionalSubtype::CommandSetDetail            => 0x17,
+            FunctionalSubtype::TelephoneControl            => 0x18,
+            FunctionalSubtype::OBEXServiceIdentifier       => 0x19,
+            FunctionalSubtype::NCM                         => 0x1A,
+            FunctionalSubtype::MBIM                        => 0x1B,
+            FunctionalSubtype::MBIMExtended                => 0x1C,
+
+            FunctionalSubtype::VendorSpecific(code) => code,
+            FunctionalSubtype::Other(code) => code,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for FunctionalSubtype {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<FunctionalSubtype, USBParseError> {
+        match FunctionalSubtype::decode(code) {
+            FunctionalSubtype::Other(code) => Err( USBParseError::UnknownFunctionalSubtype(code) ),
+            subtype => Ok(subtype),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for FunctionalSubtype {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -0,0 +1,113 @@
+//! CDC Union functional descriptor.
+//! A structure representing the union functional descriptor, which groups
+//! a communications interface with its subordinate interfaces, and the
+//! pairing of CDC control and data interfaces.
+//! Documentation: Section 5.2.3.2 of the CDC 1.2 specification.
+
+
+
+use crate::class::class::Class;
+use crate::descriptor::{ Configuration, DescriptorType, Interface, ToBytes };
+use crate::error::*;
+use super::FunctionalSubtype;
+
+
+
+/// A union functional descriptor borrowed from a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnionDescriptor<'a> {
+    /// The whole descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> UnionDescriptor<'a> {
+    /// Size of this descriptor in bytes, with a single subordinate
+    /// interface.
+    pub const LENGTH: u8 = 5;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::ClassInterface.code();
+
+    /// Descriptor subtype of this descriptor.
+    pub const SUBTYPE: u8 = FunctionalSubtype::Union.code();
+
+    /// Parses a union functional descriptor from the start of the given
+    /// buffer.
+    /// The descriptor must list at least one subordinate interface.
+    pub fn parse(bytes: &'a [u8]) -> Result<UnionDescriptor<'a>, USBParseError> {
+        let length = super::header(bytes, FunctionalSubtype::Union, Self::LENGTH)?;
+
+        Ok( UnionDescriptor { bytes: &bytes[..length] } )
+    }
+
+    /// Encodes a union functional descriptor at compile time.
+    /// Panics if there is no subordinate interface or if the descriptor does
+    /// not fill the array exactly.
+    pub const fn to_array<const N: usize>(control: u8, subordinates: &[u8]) -> [u8; N] {
+        assert!(!subordinates.is_empty(), "a union requires at least one subordinate interface");
+        assert!(N == 4 + subordinates.len(), "the union does not fill the array");
+        assert!(N <= u8::MAX as usize, "the union is longer than bLength allows");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < subordinates.len() {
+            bytes[4 + i] = subordinates[i];
+            i += 1;
+        }
+
+        bytes[0] = N as u8;
+        bytes[1] = Self::TYPE;
+        bytes[2] = Self::SUBTYPE;
+        bytes[3] = control;
+
+        bytes
+    }
+
+    /// Interface number of the controlling communications interface.
+    pub const fn control_interface(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Interface numbers of the subordinate interfaces.
+    pub fn subordinate_interfaces(&self) -> &'a [u8] {
+        &self.bytes[4..]
+    }
+
+    /// CDC Data interface of the union in the given configuration.
+    /// Prefers the first alternate setting of a subordinate CDC Data
+    /// interface with endpoints, as the default alternate setting of some
+    /// models has none. Returns `None` if no subordinate interface is a CDC
+    /// Data interface.
+    pub fn data_interface<'c>(&self, configuration: &Configuration<'c>) -> Option<Interface<'c>> {
+        let mut first = None;
+
+        for interface in configuration.interfaces() {
+            if !self.subordinate_interfaces().contains(&interface.number()) {
+                continue;
+            }
+
+            if !matches!(interface.class(), Class::CDCData(_)) {
+                continue;
+            }
+
+            if interface.endpoints().next().is_some() {
+                return Some(interface);
+            }
+
+            first = first.or( Some(interface) );
+        }
+
+        first
+    }
+}
+
+impl<'a> ToBytes for UnionDescriptor<'a> {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.bytes.to_bytes(buffer)
+    }
+}
@@ -19,7 +19,7 @@ pub enum Class {
     /// Base Class 01h (Audio) [Interface].
     /// This base class is defined for audio capable devices that conform to
     /// the Audio Device Class Specification.
-    Audio(AudioSubClass),
+    Audio(AudioSubClass, AudioProtocol),
 
     /// Base Class 02h (Communications and CDC Control) [Both].
     /// This base class is defined for devices that conform to the
@@ -29,7 +29,7 @@ pub enum Class {
     /// Base Class 03h (HID - human Interface Device) [Interface].
     /// This base class is defined for devices that conform to the HID Device
     /// Class Specification.
-    HumanInterfaceDevice(HIDSubClass),
+    HumanInterfaceDevice(HIDSubClass, HIDProtocol),
 
     /// Base Class 05h (Physical) [Interface].
     /// This base class is defined for devices that conform to the Physical
@@ -41,7 +41,7 @@ pub enum Class {
     /// Device Class Specification.
     StillImaging,
 
-    /// Base Class 75h (Printer) [Interface].
+    /// Base Class 07h (Printer) [Interface].
     /// This base class is defined for devices that conform to the Printer
     /// Device Class Specification.
     Printer(PrinterProtocol),
@@ -74,7 +74,7 @@ pub enum Class {
     /// Base Class 0Eh (Video) [Interface].
     /// This base class is defined for devices that conform to the Video Device
     /// Class Specification.
-    Video(VideoSubClass),
+    Video(VideoSubClass, VideoProtocol),
 
     /// Base Class 0Fh (Personal Healthcare) [Interface].
     /// This base class is defined for devices that conform to the Personal
@@ -84,7 +84,7 @@ pub enum Class {
     /// Base Class 10h (Audio / Video Devices) [Interface].
     /// The USB Audio / Video (AV) Device Class definition describes the
     /// methods used to communicate with devices or functions embedded in
-    /// composite devices that are used to manipulate audio, ideo, voice and
+    /// composite devices that are used to manipulate audio, video, voice and
     /// all image and sound related functionality.
     AudioVideo(AVSubClass),
 
@@ -98,6 +98,16 @@ pub enum Class {
     /// Bridge Device Class Specification.
     TypeCBridge,
 
+    /// Base Class 13h (Bulk Display Protocol Device) [Interface].
+    /// This base class is defined for devices that conform to the USB Bulk
+    /// Display Protocol Device Class Specification.
+    BulkDisplay,
+
+    /// Base Class 14h (MCTP over USB Protocol Endpoint Device) [Both].
+    /// This base class is defined for devices that conform to the MCTP over
+    /// USB Protocol Endpoint Device Class Specification.
+    MCTP(MCTPProtocol),
+
     /// Base Class 3Ch (I3C Device Class) [Interface].
     /// This base class is defined for devices that conform to the USB I3C
     /// Device Class Specification.
@@ -113,7 +123,7 @@ pub enum Class {
 
     /// Base Class EFh (Miscellaneous) [Both].
     /// This base class is defined for miscellaneous device definitions.
-    Miscellaneous(MiscellanousSubClass),
+    Miscellaneous(MiscellaneousSubClass),
 
     /// Base Class FEh (Application Specific) [Interface].
     /// This base class is defined for devices that conform to several class
@@ -123,26 +133,148 @@ pub enum Class {
     /// Base Class FFh (Vendor Specific) [Both].
     /// This base class is defined for vendors to use as they please.
     VendorSpecific(u8, u8),
+
+    /// Class triple not defined by the USB-IF, kept as is.
+    /// Only produced by `Class::decode`.
+    Unknown {
+        class: u8,
+        subclass: u8,
+        protocol: u8,
+    },
 }
 
 impl Class {
+    /// Decodes a class triple without failing.
+    /// Unknown sub class and protocol codes are kept in the `Other` variants
+    /// of their enums, including the sub class and protocol pairs of the
+    /// Diagnostic, Wireless, Miscellaneous and Application Specific classes.
+    /// Triples that cannot be represented otherwise, such as unassigned base
+    /// classes, are kept in `Class::Unknown`. Every result converts back to
+    /// the same triple. Use `Class::try_from` for strict decoding.
+    pub fn decode(code: (u8, u8, u8)) -> Class {
+        let (class, subclass, protocol) = code;
+
+        match code {
+            (0x01, s, p) => Class::Audio( AudioSubClass::decode(s), AudioProtocol::decode(p) ),
+
+            (0x02, s, p) => Class::CDCControl( CDCControlSubClass::decode(s), CDCControlProtocol::decode(p) ),
+
+            (0x03, s, p) => Class::HumanInterfaceDevice( HIDSubClass::decode(s), HIDProtocol::decode(p) ),
+
+            (0x05, s, 0) => Class::Physical( PhysicalSubClass::decode(s) ),
+
+            (0x07, 1, p) => Class::Printer( PrinterProtocol::decode(p) ),
+
+            (0x08, s, p) => Class::MassStorage( MassStorageSubClass::decode(s), MassStorageProtocol::decode(p) ),
+
+            (0x09, 0, p) => Class::Hub( HubSpeed::decode(p) ),
+
+            (0x0A, 0, p) => Class::CDCData( CDCDataProtocol::decode(p) ),
+
+            (0x0B, 0, p) => Class::SmartCard( SmartCardProtocol::decode(p) ),
+
+            (0x0E, s, p) => Class::Video( VideoSubClass::decode(s), VideoProtocol::decode(p) ),
+
+            (0x10, s, 0) => Class::AudioVideo( AVSubClass::decode(s) ),
+
+            (0x14, 0, p) => Class::MCTP( MCTPProtocol::decode(p) ),
+
+            (0xDC, s, p) => Class::Diagnostic( DiagnosticSubClass::decode((s, p)) ),
+
+            (0xE0, s, p) => Class::Wireless( WirelessSubClass::decode((s, p)) ),
+
+            (0xEF, s, p) => Class::Miscellaneous( MiscellaneousSubClass::decode((s, p)) ),
+
+            (0xFE, s, p) => Class::ApplicationSpecific( ApplicationSpecificSubClass::decode((s, p)) ),
+
+            _ => Class::try_from(code).unwrap_or(Class::Unknown { class, subclass, protocol }),
+        }
+    }
+
+    /// Returns the class, subclass and protocol codes of this class.
+    pub const fn code(&self) -> (u8, u8, u8) {
+        match *self {
+            Class::Device => (0x00, 0x00, 0x00),
+
+            Class::Audio(s, p) => (0x01, s.code(), p.code()),
+
+            Class::CDCControl(s, p) => (0x02, s.code(), p.code()),
+
+            Class::HumanInterfaceDevice(s, p) => (0x03, s.code(), p.code()),
+
+            Class::Physical(s) => (0x05, s.code(), 0x00),
+
+            Class::StillImaging => (0x06, 0x01, 0x01),
+
+            Class::Printer(p) => (0x07, 0x01, p.code()),
+
+            Class::MassStorage(s, p) => (0x08, s.code(), p.code()),
+
+            Class::Hub(p) => (0x09, 0x00, p.code()),
+
+            Class::CDCData(p) => (0x0A, 0x00, p.code()),
+
+            Class::SmartCard(p) => (0x0B, 0x00, p.code()),
+
+            Class::ContentSecurity => (0x0D, 0x00, 0x00),
+
+            Class::Video(s, p) => (0x0E, s.code(), p.code()),
+
+            Class::PersonalHealthcare => (0x0F, 0x00, 0x00),
+
+            Class::AudioVideo(s) => (0x10, s.code(), 0x00),
+
+            Class::Billboard => (0x11, 0x00, 0x00),
+
+            Class::TypeCBridge => (0x12, 0x00, 0x00),
+
+            Class::BulkDisplay => (0x13, 0x00, 0x00),
+
+            Class::MCTP(p) => (0x14, 0x00, p.code()),
+
+            Class::I3CDevice => (0x3C, 0x00, 0x00),
+
+            Class::Diagnostic(d) => {
+                let (s, p) = d.code();
+                (0xDC, s, p)
+            },
+
+            Class::Wireless(w) => {
+                let (s, p) = w.code();
+                (0xE0, s, p)
+            },
+
+            Class::Miscellaneous(m) => {
+                let (s, p) = m.code();
+                (0xEF, s, p)
+            },
+
+            Class::ApplicationSpecific(a) => {
+                let (s, p) = a.code();
+                (0xFE, s, p)
+            },
+
+            Class::VendorSpecific(s, p) => (0xFF, s, p),
+
+            Class::Unknown { class, subclass, protocol } => (class, subclass, protocol),
+        }
+    }
+
     /// Returns `true` if the class code can be used in a device descriptor.
     pub const fn devdesc(&self) -> bool {
-        match *self {
+        matches!(*self,
             Class::Device        | Class::CDCControl(_, _) |
-            Class::Hub(_)        | Class::Billboard        | 
+            Class::Hub(_)        | Class::Billboard        |
+            Class::MCTP(_)       |
             Class::Diagnostic(_) | Class::Miscellaneous(_) |
-            Class::VendorSpecific(_,_) => true,
-            _ => false,
-        }
+            Class::VendorSpecific(_,_)
+        )
     }
 
     /// Returns `true` if the class code can be used in an interface descriptor.
+    /// Unknown classes are assumed to be valid at interface level.
     pub const fn ifdesc(&self) -> bool {
-        match *self {
-            Class::Device | Class::Hub(_) | Class::Billboard => false,
-            _ => true,
-        }
+        !matches!(*self, Class::Device | Class::Hub(_) | Class::Billboard)
     }
 }
 
@@ -161,17 +293,11 @@ impl TryFrom<(u8, u8, u8)> for Class {
             },
 
             // Parse Audio class.
-            0x01 => match code {
-                (_, 1, 0) => Ok( Class::Audio( AudioSubClass::Control ) ),
-                (_, 1, p) => Err( USBParseError::UnknownProtocol(p) ),
-
-                (_, 2, 0) => Ok( Class::Audio( AudioSubClass::Streaming ) ),
-                (_, 2, p) => Err( USBParseError::UnknownProtocol(p) ),
-
-                (_, 3, 0) => Ok( Class::Audio( AudioSubClass::MIDIStreaming ) ),
-                (_, 3, p) => Err( USBParseError::UnknownProtocol(p) ),
+            0x01 => {
+                let subclass = AudioSubClass::try_from(code.1)?;
+                let protocol = AudioProtocol::try_from(code.2)?;
 
-                (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
+                Ok( Class::Audio(subclass, protocol) )
             },
 
             // Parse Communication and CDC Control class.
@@ -183,23 +309,22 @@ impl TryFrom<(u8, u8, u8)> for Class {
             },
 
             // Parse Human Interface Device class.
-            0x03 => match code {
-                (_, 0, 1) => Ok( Class::HumanInterfaceDevice( HIDSubClass::Keyboard ) ),
-                (_, 0, 2) => Ok( Class::HumanInterfaceDevice( HIDSubClass::Mouse ) ),
-                (_, 0, p) => Err( USBParseError::UnknownProtocol(p) ),
+            0x03 => {
+                let subclass = HIDSubClass::try_from(code.1)?;
+                let protocol = HIDProtocol::try_from(code.2)?;
 
-                (_, 1, 0) => Ok( Class::HumanInterfaceDevice( HIDSubClass::Boot ) ),
-                (_, 1, p) => Err( USBParseError::UnknownProtocol(p) ),
-
-                (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
+                Ok( Class::HumanInterfaceDevice(subclass, protocol) )
             },
 
             // Parse Physical class.
-            0x05 => { todo!()},
+            0x05 => match code {
+                (_, s, 0) => Ok( Class::Physical( PhysicalSubClass::try_from(s)? ) ),
+                (_, _, p) => Err( USBParseError::UnknownProtocol(p) ),
+            },
 
             // Parse Still Imaging class.
             0x06 => match code {
-                (_, 1, 1) => Ok( Class::Device ),
+                (_, 1, 1) => Ok( Class::StillImaging ),
                 (_, 1, p) => Err( USBParseError::UnknownProtocol(p) ),
                 (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
             },
@@ -209,6 +334,7 @@ impl TryFrom<(u8, u8, u8)> for Class {
                 (_, 1, 1) => Ok( Class::Printer( PrinterProtocol::Unidirectional ) ),
                 (_, 1, 2) => Ok( Class::Printer( PrinterProtocol::Bidirectional ) ),
                 (_, 1, 3) => Ok( Class::Printer( PrinterProtocol::Bidirectional1284 ) ),
+                (_, 1, 4) => Ok( Class::Printer( PrinterProtocol::IPPOverUSB ) ),
 
                 (_, 1, 0xFF) => Ok( Class::Printer( PrinterProtocol::VendorSpecific ) ),
 
@@ -227,36 +353,13 @@ impl TryFrom<(u8, u8, u8)> for Class {
 
             // Parse Hub class.
             0x09 => match code.1 {
-                0x00 => match code.2 {
-                    0x00 => Ok( Class::Hub( HubSpeed::FullSpeed ) ),
-                    0x00 => Ok( Class::Hub( HubSpeed::HighSpeedSingle ) ),
-                    0x00 => Ok( Class::Hub( HubSpeed::HighSpeedMultiple ) ),
-
-                    p => Err( USBParseError::UnknownProtocol(p) ),
-                },
+                0x00 => Ok( Class::Hub( HubSpeed::try_from(code.2)? ) ),
                 s => Err( USBParseError::UnknownSubClass(s) ),
             },
 
             // Parse CDC Data class.
             0x0A => match code.1 {
-                0x00 => match code.2 {
-                    0x00 => Ok( Class::CDCData( CDCDataProtocol::USB ) ),
-                    0x01 => Ok( Class::CDCData( CDCDataProtocol::NTB ) ),
-                    0x30 => Ok( Class::CDCData( CDCDataProtocol::I430 ) ),
-                    0x31 => Ok( Class::CDCData( CDCDataProtocol::HDLC ) ),
-                    0x50 => Ok( Class::CDCData( CDCDataProtocol::Q921M ) ),
-                    0x51 => Ok( Class::CDCData( CDCDataProtocol::Q921 ) ),
-                    0x52 => Ok( Class::CDCData( CDCDataProtocol::Q921TM ) ),
-                    0x90 => Ok( Class::CDCData( CDCDataProtocol::V42bis ) ),
-                    0x91 => Ok( Class::CDCData( CDCDataProtocol::Q931 ) ),
-                    0x92 => Ok( Class::CDCData( CDCDataProtocol::V120 ) ),
-                    0x93 => Ok( Class::CDCData( CDCDataProtocol::CAPI2 ) ),
-                    0xFD => Ok( Class::CDCData( CDCDataProtocol::HostBased ) ),
-                    0xFE => Ok( Class::CDCData( CDCDataProtocol::Described ) ),
-                    0xFF => Ok( Class::CDCData( CDCDataProtocol::VendorSpecific ) ),
-
-                    p => Err( USBParseError::UnknownProtocol(p) ),
-                },
+                0x00 => Ok( Class::CDCData( CDCDataProtocol::try_from(code.2)? ) ),
 
                 s => Err( USBParseError::UnknownSubClass(s) ),
             },
@@ -280,21 +383,19 @@ impl TryFrom<(u8, u8, u8)> for Class {
             },
 
             // Parse Video class.
-            0x0E => match code {
-                (_, 1, 1) => Ok( Class::Video( VideoSubClass::Control ) ),
-                (_, 1, p) => Err( USBParseError::UnknownProtocol(p) ),
-
-                (_, 2, 1) => Ok( Class::Video( VideoSubClass::Streaming ) ),
-                (_, 2, p) => Err( USBParseError::UnknownProtocol(p) ),
-
-                (_, 3, 1) => Ok( Class::Video( VideoSubClass::InterfaceCollection ) ),
-                (_, 3, p) => Err( USBParseError::UnknownProtocol(p) ),
+            0x0E => {
+                let subclass = VideoSubClass::try_from(code.1)?;
+                let protocol = VideoProtocol::try_from(code.2)?;
 
-                (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
+                Ok( Class::Video(subclass, protocol) )
             },
 
             // Parse Personal Healthcare class.
-            0x0F => { todo!() },
+            0x0F => match code {
+                (_, 0, 0) => Ok( Class::PersonalHealthcare ),
+                (_, 0, p) => Err( USBParseError::UnknownProtocol(p) ),
+                (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
+            },
 
             // Parse Audio/Video Device class.
             0x10 => match code {
@@ -324,6 +425,20 @@ impl TryFrom<(u8, u8, u8)> for Class {
                 (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
             },
 
+            // Parse Bulk Display Protocol Device class.
+            0x13 => match code {
+                (_, 0, 0) => Ok( Class::BulkDisplay ),
+                (_, 0, p) => Err( USBParseError::UnknownProtocol(p) ),
+                (_, s, _) => Err( USBParseError::UnknownSubClass(s) ),
+            },
+
+            // Parse MCTP over USB Protocol Endpoint Device class.
+            0x14 => match code.1 {
+                0x00 => Ok( Class::MCTP( MCTPProtocol::try_from(code.2)? ) ),
+
+                s => Err( USBParseError::UnknownSubClass(s) ),
+            },
+
             // Parse I3C Device class.
             0x3C => match code {
                 (_, 0, 0) => Ok( Class::I3CDevice ),
@@ -332,19 +447,28 @@ impl TryFrom<(u8, u8, u8)> for Class {
             },
 
             // Parse Diagnostic Device class.
-            0xDC => { todo!() }
+            0xDC => Ok( Class::Diagnostic( DiagnosticSubClass::try_from((code.1, code.2))? ) ),
 
             // Parse Wireless Controller Device class.
-            0xE0 => { todo!() }
+            0xE0 => Ok( Class::Wireless( WirelessSubClass::try_from((code.1, code.2))? ) ),
 
             // Parse Miscellaneous Device class.
-            0xEF => { todo!() }
+            0xEF => Ok( Class::Miscellaneous( MiscellaneousSubClass::try_from((code.1, code.2))? ) ),
 
             // Parse Application Specific class.
-            0xFE => { todo!() }
+            0xFE => Ok( Class::ApplicationSpecific( ApplicationSpecificSubClass::try_from((code.1, code.2))? ) ),
 
             // Parse Vendor Specific class.
             0xFF => Ok( Class::VendorSpecific(code.1, code.2) ),
+
+            // Unassigned base class codes.
+            c => Err( USBParseError::UnknownClass(c) ),
         }
     }
 }
+
+impl From<Class> for (u8, u8, u8) {
+    fn from(class: Class) -> (u8, u8, u8) {
+        class.code()
+    }
+}
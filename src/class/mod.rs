@@ -2,6 +2,7 @@
 
 
 
+#[allow(clippy::module_inception)]
 pub mod class;
 pub mod protocol;
 pub mod subclass;
@@ -9,9 +9,16 @@ use crate::error::USBParseError;
 pub enum FirmwareUpgradeProtocol {
     /// Device Firmware Upgrade.
     Default,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for FirmwareUpgradeProtocol {}
+impl super::USBProtocol for FirmwareUpgradeProtocol {
+    fn decode(byte: u8) -> FirmwareUpgradeProtocol {
+        FirmwareUpgradeProtocol::try_from(byte).unwrap_or(FirmwareUpgradeProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for FirmwareUpgradeProtocol {
     type Error = USBParseError;
@@ -25,8 +32,19 @@ impl core::convert::TryFrom<u8> for FirmwareUpgradeProtocol {
     }
 }
 
+impl FirmwareUpgradeProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            FirmwareUpgradeProtocol::Default => 0x01,
+
+            FirmwareUpgradeProtocol::Other(byte) => byte,
+        }
+    }
+}
+
 impl core::convert::Into<u8> for FirmwareUpgradeProtocol {
     fn into(self) -> u8 {
-        0x01
+        self.code()
     }
 }
@@ -9,9 +9,16 @@ use crate::error::USBParseError;
 pub enum IRDABridgeProtocol {
     /// IRDA Bridge Device.
     Default,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for IRDABridgeProtocol {}
+impl super::USBProtocol for IRDABridgeProtocol {
+    fn decode(byte: u8) -> IRDABridgeProtocol {
+        IRDABridgeProtocol::try_from(byte).unwrap_or(IRDABridgeProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for IRDABridgeProtocol {
     type Error = USBParseError;
@@ -25,8 +32,19 @@ impl core::convert::TryFrom<u8> for IRDABridgeProtocol {
     }
 }
 
+impl IRDABridgeProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            IRDABridgeProtocol::Default => 0x00,
+
+            IRDABridgeProtocol::Other(byte) => byte,
+        }
+    }
+}
+
 impl core::convert::Into<u8> for IRDABridgeProtocol {
     fn into(self) -> u8 {
-        0x01
+        self.code()
     }
 }
@@ -14,4 +14,4 @@ pub use self::test::*;
 
 
 
-pub(self) use super::USBProtocol;
+use super::USBProtocol;
@@ -12,9 +12,16 @@ pub enum USBTestAndMeasureProtocol {
 
     /// USB488 conformant protocol.
     USB488,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for USBTestAndMeasureProtocol {}
+impl super::USBProtocol for USBTestAndMeasureProtocol {
+    fn decode(byte: u8) -> USBTestAndMeasureProtocol {
+        USBTestAndMeasureProtocol::try_from(byte).unwrap_or(USBTestAndMeasureProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for USBTestAndMeasureProtocol {
     type Error = USBParseError;
@@ -29,11 +36,20 @@ impl core::convert::TryFrom<u8> for USBTestAndMeasureProtocol {
     }
 }
 
-impl core::convert::Into<u8> for USBTestAndMeasureProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl USBTestAndMeasureProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             USBTestAndMeasureProtocol::Default => 0x00,
             USBTestAndMeasureProtocol::USB488  => 0x01,
+
+            USBTestAndMeasureProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for USBTestAndMeasureProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -0,0 +1,60 @@
+//! Audio Device protocols.
+
+
+
+use crate::error::USBParseError;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioProtocol {
+    /// Audio Device Class 1.0.
+    Version1,
+
+    /// Audio Device Class 2.0 (`IP_VERSION_02_00`).
+    Version2,
+
+    /// Audio Device Class 3.0 (`IP_VERSION_03_00`).
+    Version3,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
+}
+
+impl super::USBProtocol for AudioProtocol {
+    fn decode(byte: u8) -> AudioProtocol {
+        AudioProtocol::try_from(byte).unwrap_or(AudioProtocol::Other(byte))
+    }
+}
+
+impl core::convert::TryFrom<u8> for AudioProtocol {
+    type Error = USBParseError;
+
+    fn try_from(byte: u8) -> Result<AudioProtocol, USBParseError> {
+        match byte {
+            0x00 => Ok(AudioProtocol::Version1),
+            0x20 => Ok(AudioProtocol::Version2),
+            0x30 => Ok(AudioProtocol::Version3),
+
+            _ => Err( USBParseError::UnknownProtocol(byte) ),
+        }
+    }
+}
+
+impl AudioProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            AudioProtocol::Version1 => 0x00,
+            AudioProtocol::Version2 => 0x20,
+            AudioProtocol::Version3 => 0x30,
+
+            AudioProtocol::Other(byte) => byte,
+        }
+    }
+}
+
+impl core::convert::Into<u8> for AudioProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
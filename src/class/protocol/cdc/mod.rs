@@ -49,8 +49,73 @@ pub enum CDCDataProtocol {
 
     /// Vendor specific.
     VendorSpecific,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
+}
+
+impl super::USBProtocol for CDCDataProtocol {
+    fn decode(byte: u8) -> CDCDataProtocol {
+        CDCDataProtocol::try_from(byte).unwrap_or(CDCDataProtocol::Other(byte))
+    }
 }
 
+impl TryFrom<u8> for CDCDataProtocol {
+    type Error = USBParseError;
+
+    fn try_from(byte: u8) -> Result<Self, USBParseError> {
+        match byte {
+            0x00 => Ok( CDCDataProtocol::USB ),
+            0x01 => Ok( CDCDataProtocol::NTB ),
+            0x30 => Ok( CDCDataProtocol::I430 ),
+            0x31 => Ok( CDCDataProtocol::HDLC ),
+            0x50 => Ok( CDCDataProtocol::Q921M ),
+            0x51 => Ok( CDCDataProtocol::Q921 ),
+            0x52 => Ok( CDCDataProtocol::Q921TM ),
+            0x90 => Ok( CDCDataProtocol::V42bis ),
+            0x91 => Ok( CDCDataProtocol::Q931 ),
+            0x92 => Ok( CDCDataProtocol::V120 ),
+            0x93 => Ok( CDCDataProtocol::CAPI2 ),
+            0xFD => Ok( CDCDataProtocol::HostBased ),
+            0xFE => Ok( CDCDataProtocol::Described ),
+
+            0xFF => Ok( CDCDataProtocol::VendorSpecific ),
+
+            p => Err( USBParseError::UnknownProtocol(p) ),
+        }
+    }
+}
+
+impl CDCDataProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            CDCDataProtocol::USB       => 0x00,
+            CDCDataProtocol::NTB       => 0x01,
+            CDCDataProtocol::I430      => 0x30,
+            CDCDataProtocol::HDLC      => 0x31,
+            CDCDataProtocol::Q921M     => 0x50,
+            CDCDataProtocol::Q921      => 0x51,
+            CDCDataProtocol::Q921TM    => 0x52,
+            CDCDataProtocol::V42bis    => 0x90,
+            CDCDataProtocol::Q931      => 0x91,
+            CDCDataProtocol::V120      => 0x92,
+            CDCDataProtocol::CAPI2     => 0x93,
+            CDCDataProtocol::HostBased => 0xFD,
+            CDCDataProtocol::Described => 0xFE,
+
+            CDCDataProtocol::VendorSpecific => 0xFF,
+
+            CDCDataProtocol::Other(byte) => byte,
+        }
+    }
+}
+
+impl Into<u8> for CDCDataProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
 
 
 
@@ -85,9 +150,16 @@ pub enum CDCControlProtocol {
 
     /// Vendor specific.
     VendorSpecific,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for CDCControlProtocol {}
+impl super::USBProtocol for CDCControlProtocol {
+    fn decode(byte: u8) -> CDCControlProtocol {
+        CDCControlProtocol::try_from(byte).unwrap_or(CDCControlProtocol::Other(byte))
+    }
+}
 
 impl TryFrom<u8> for CDCControlProtocol {
     type Error = USBParseError;
@@ -106,13 +178,16 @@ impl TryFrom<u8> for CDCControlProtocol {
             0xFE => Ok( CDCControlProtocol::External ),
 
             0xFF => Ok( CDCControlProtocol::VendorSpecific ),
+
+            p => Err( USBParseError::UnknownProtocol(p) ),
         }
     }
 }
 
-impl Into<u8> for CDCControlProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl CDCControlProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             CDCControlProtocol::USB          => 0x00,
             CDCControlProtocol::V250         => 0x01,
             CDCControlProtocol::PCCA101      => 0x02,
@@ -125,6 +200,14 @@ impl Into<u8> for CDCControlProtocol {
             CDCControlProtocol::External => 0xFE,
 
             CDCControlProtocol::VendorSpecific => 0xFF,
+
+            CDCControlProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl Into<u8> for CDCControlProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -7,20 +7,31 @@ use crate::error::USBParseError;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HIDProtocol {
+    /// No protocol.
+    None,
+
     /// Keyboard protocol.
     Keyboard,
 
     /// Mouse protocol.
     Mouse,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for HIDProtocol {}
+impl super::USBProtocol for HIDProtocol {
+    fn decode(byte: u8) -> HIDProtocol {
+        HIDProtocol::try_from(byte).unwrap_or(HIDProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for HIDProtocol {
     type Error = USBParseError;
 
     fn try_from(byte: u8) -> Result<HIDProtocol, USBParseError> {
         match byte {
+            0x00 => Ok(HIDProtocol::None),
             0x01 => Ok(HIDProtocol::Keyboard),
             0x02 => Ok(HIDProtocol::Mouse),
 
@@ -29,11 +40,21 @@ impl core::convert::TryFrom<u8> for HIDProtocol {
     }
 }
 
-impl core::convert::Into<u8> for HIDProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl HIDProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            HIDProtocol::None     => 0x00,
             HIDProtocol::Keyboard => 0x01,
             HIDProtocol::Mouse    => 0x02,
+
+            HIDProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for HIDProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -15,9 +15,16 @@ pub enum HubSpeed {
 
     /// High Speed Hub with multiple TT.
     HighSpeedMultiple,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for HubSpeed {}
+impl super::USBProtocol for HubSpeed {
+    fn decode(byte: u8) -> HubSpeed {
+        HubSpeed::try_from(byte).unwrap_or(HubSpeed::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for HubSpeed {
     type Error = USBParseError;
@@ -33,12 +40,21 @@ impl core::convert::TryFrom<u8> for HubSpeed {
     }
 }
 
-impl core::convert::Into<u8> for HubSpeed {
-    fn into(self) -> u8 {
-        match self {
+impl HubSpeed {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             HubSpeed::FullSpeed         => 0x00,
             HubSpeed::HighSpeedSingle   => 0x01,
             HubSpeed::HighSpeedMultiple => 0x02,
+
+            HubSpeed::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for HubSpeed {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -0,0 +1,55 @@
+//! MCTP over USB protocols.
+
+
+
+use crate::error::USBParseError;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MCTPProtocol {
+    /// MCTP 1.x endpoint.
+    Version1,
+
+    /// MCTP 2.x endpoint.
+    Version2,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
+}
+
+impl super::USBProtocol for MCTPProtocol {
+    fn decode(byte: u8) -> MCTPProtocol {
+        MCTPProtocol::try_from(byte).unwrap_or(MCTPProtocol::Other(byte))
+    }
+}
+
+impl core::convert::TryFrom<u8> for MCTPProtocol {
+    type Error = USBParseError;
+
+    fn try_from(byte: u8) -> Result<MCTPProtocol, USBParseError> {
+        match byte {
+            0x01 => Ok(MCTPProtocol::Version1),
+            0x02 => Ok(MCTPProtocol::Version2),
+
+            _ => Err( USBParseError::UnknownProtocol(byte) ),
+        }
+    }
+}
+
+impl MCTPProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            MCTPProtocol::Version1 => 0x01,
+            MCTPProtocol::Version2 => 0x02,
+
+            MCTPProtocol::Other(byte) => byte,
+        }
+    }
+}
+
+impl core::convert::Into<u8> for MCTPProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -3,9 +3,11 @@
 
 
 mod app;
+mod audio;
 mod cdc;
 mod hid;
 mod hub;
+mod mctp;
 mod printer;
 mod smartcard;
 mod stillimage;
@@ -16,9 +18,11 @@ mod wireless;
 
 
 pub use self::app::*;
+pub use self::audio::*;
 pub use self::cdc::*;
 pub use self::hid::*;
 pub use self::hub::*;
+pub use self::mctp::*;
 pub use self::printer::*;
 pub use self::smartcard::*;
 pub use self::stillimage::*;
@@ -29,4 +33,9 @@ pub use self::wireless::*;
 
 
 /// Common trait for all protocols.
-pub trait USBProtocol: TryFrom<u8> + Into<u8> {}
+pub trait USBProtocol: TryFrom<u8> + Into<u8> {
+    /// Decodes a protocol code without failing.
+    /// Unknown codes are kept in the `Other` variant so they convert back to
+    /// the same byte. Use `TryFrom<u8>` to reject them instead.
+    fn decode(byte: u8) -> Self;
+}
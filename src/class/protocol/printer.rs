@@ -17,11 +17,21 @@ pub enum PrinterProtocol {
     /// 1284.4 compatible bidirectional interface.
     Bidirectional1284,
 
+    /// IPP over USB interface.
+    IPPOverUSB,
+
     /// Vendor Specific.
     VendorSpecific,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for PrinterProtocol {}
+impl super::USBProtocol for PrinterProtocol {
+    fn decode(byte: u8) -> PrinterProtocol {
+        PrinterProtocol::try_from(byte).unwrap_or(PrinterProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for PrinterProtocol {
     type Error = USBParseError;
@@ -31,6 +41,7 @@ impl core::convert::TryFrom<u8> for PrinterProtocol {
             0x01 => Ok(PrinterProtocol::Unidirectional),
             0x02 => Ok(PrinterProtocol::Bidirectional),
             0x03 => Ok(PrinterProtocol::Bidirectional1284),
+            0x04 => Ok(PrinterProtocol::IPPOverUSB),
 
             0xFF => Ok(PrinterProtocol::VendorSpecific),
 
@@ -39,13 +50,23 @@ impl core::convert::TryFrom<u8> for PrinterProtocol {
     }
 }
 
-impl core::convert::Into<u8> for PrinterProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl PrinterProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             PrinterProtocol::Unidirectional    => 0x01,
             PrinterProtocol::Bidirectional     => 0x02,
             PrinterProtocol::Bidirectional1284 => 0x03,
+            PrinterProtocol::IPPOverUSB        => 0x04,
             PrinterProtocol::VendorSpecific    => 0xFF,
+
+            PrinterProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for PrinterProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -15,9 +15,16 @@ pub enum SmartCardProtocol {
 
     /// Control transfers, optional IN.
     ControlOptionalInterrupt,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for SmartCardProtocol {}
+impl super::USBProtocol for SmartCardProtocol {
+    fn decode(byte: u8) -> SmartCardProtocol {
+        SmartCardProtocol::try_from(byte).unwrap_or(SmartCardProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for SmartCardProtocol {
     type Error = USBParseError;
@@ -33,12 +40,21 @@ impl core::convert::TryFrom<u8> for SmartCardProtocol {
     }
 }
 
-impl core::convert::Into<u8> for SmartCardProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl SmartCardProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             SmartCardProtocol::Bulk                     => 0x00,
             SmartCardProtocol::ControlWithoutInterrupt  => 0x01,
             SmartCardProtocol::ControlOptionalInterrupt => 0x02,
+
+            SmartCardProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for SmartCardProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -9,9 +9,16 @@ use crate::error::USBParseError;
 pub enum StillImageProtocol {
     /// Default and only value allowed.
     Default,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for StillImageProtocol {}
+impl super::USBProtocol for StillImageProtocol {
+    fn decode(byte: u8) -> StillImageProtocol {
+        StillImageProtocol::try_from(byte).unwrap_or(StillImageProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for StillImageProtocol {
     type Error = USBParseError;
@@ -25,8 +32,19 @@ impl core::convert::TryFrom<u8> for StillImageProtocol {
     }
 }
 
+impl StillImageProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            StillImageProtocol::Default => 0x01,
+
+            StillImageProtocol::Other(byte) => byte,
+        }
+    }
+}
+
 impl core::convert::Into<u8> for StillImageProtocol {
     fn into(self) -> u8 {
-        0x01
+        self.code()
     }
 }
@@ -23,9 +23,16 @@ pub enum MassStorageProtocol {
 
     /// Vendor Specific.
     VendorSpecific,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for MassStorageProtocol {}
+impl super::USBProtocol for MassStorageProtocol {
+    fn decode(byte: u8) -> MassStorageProtocol {
+        MassStorageProtocol::try_from(byte).unwrap_or(MassStorageProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for MassStorageProtocol {
     type Error = USBParseError;
@@ -43,14 +50,23 @@ impl core::convert::TryFrom<u8> for MassStorageProtocol {
     }
 }
 
-impl core::convert::Into<u8> for MassStorageProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl MassStorageProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             MassStorageProtocol::CBIWithInterrupt    => 0x00,
             MassStorageProtocol::CBIWithoutInterrupt => 0x01,
             MassStorageProtocol::BulkOnly            => 0x50,
             MassStorageProtocol::UAS                 => 0x62,
             MassStorageProtocol::VendorSpecific      => 0xFF,
+
+            MassStorageProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for MassStorageProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -7,17 +7,28 @@ use crate::error::USBParseError;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum VideoProtocol {
+    /// Undefined protocol (UVC 1.0 and 1.1).
+    Undefined,
+
     /// Protocol 15.
     Protocol15,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for VideoProtocol {}
+impl super::USBProtocol for VideoProtocol {
+    fn decode(byte: u8) -> VideoProtocol {
+        VideoProtocol::try_from(byte).unwrap_or(VideoProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for VideoProtocol {
     type Error = USBParseError;
 
     fn try_from(byte: u8) -> Result<VideoProtocol, USBParseError> {
         match byte {
+            0x00 => Ok(VideoProtocol::Undefined),
             0x01 => Ok(VideoProtocol::Protocol15),
 
             _ => Err( USBParseError::UnknownProtocol(byte) ),
@@ -25,8 +36,20 @@ impl core::convert::TryFrom<u8> for VideoProtocol {
     }
 }
 
+impl VideoProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            VideoProtocol::Undefined  => 0x00,
+            VideoProtocol::Protocol15 => 0x01,
+
+            VideoProtocol::Other(byte) => byte,
+        }
+    }
+}
+
 impl core::convert::Into<u8> for VideoProtocol {
     fn into(self) -> u8 {
-        0x01
+        self.code()
     }
 }
@@ -18,9 +18,16 @@ pub enum WirelessBaseProtocol {
 
     /// Bluetooth AMP Controller.
     BluetoothAMPController,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for WirelessBaseProtocol {}
+impl super::USBProtocol for WirelessBaseProtocol {
+    fn decode(byte: u8) -> WirelessBaseProtocol {
+        WirelessBaseProtocol::try_from(byte).unwrap_or(WirelessBaseProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for WirelessBaseProtocol {
     type Error = USBParseError;
@@ -37,13 +44,22 @@ impl core::convert::TryFrom<u8> for WirelessBaseProtocol {
     }
 }
 
-impl core::convert::Into<u8> for WirelessBaseProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl WirelessBaseProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             WirelessBaseProtocol::BluetoothProgramming      => 0x01,
             WirelessBaseProtocol::UWBRadioControl           => 0x02,
             WirelessBaseProtocol::RemoteNDIS                => 0x03,
             WirelessBaseProtocol::BluetoothAMPController    => 0x04,
+
+            WirelessBaseProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for WirelessBaseProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -12,4 +12,4 @@ pub use self::wire::*;
 
 
 
-pub(self) use super::USBProtocol;
+use super::USBProtocol;
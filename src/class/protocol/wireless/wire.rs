@@ -15,9 +15,16 @@ pub enum WirelessAdapterProtocol {
 
     /// Device Wire Adapter Isochronous interface.
     DeviceWireAdapterII,
+
+    /// Unknown protocol code, kept as is.
+    Other(u8),
 }
 
-impl super::USBProtocol for WirelessAdapterProtocol {}
+impl super::USBProtocol for WirelessAdapterProtocol {
+    fn decode(byte: u8) -> WirelessAdapterProtocol {
+        WirelessAdapterProtocol::try_from(byte).unwrap_or(WirelessAdapterProtocol::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for WirelessAdapterProtocol {
     type Error = USBParseError;
@@ -33,12 +40,21 @@ impl core::convert::TryFrom<u8> for WirelessAdapterProtocol {
     }
 }
 
-impl core::convert::Into<u8> for WirelessAdapterProtocol {
-    fn into(self) -> u8 {
-        match self {
+impl WirelessAdapterProtocol {
+    /// Raw protocol code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             WirelessAdapterProtocol::HostWireAdapterCDI   => 0x01,
             WirelessAdapterProtocol::DeviceWireAdapterCDI => 0x02,
             WirelessAdapterProtocol::DeviceWireAdapterII  => 0x03,
+
+            WirelessAdapterProtocol::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for WirelessAdapterProtocol {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -0,0 +1,71 @@
+//! Application Specific sub classes.
+
+
+
+use crate::error::*;
+use crate::class::protocol::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApplicationSpecificSubClass {
+    /// Device Firmware Upgrade.
+    FirmwareUpgrade(FirmwareUpgradeProtocol),
+
+    /// IRDA Bridge device.
+    IRDABridge(IRDABridgeProtocol),
+
+    /// USB Test and Measurement Device.
+    TestAndMeasurement(USBTestAndMeasureProtocol),
+
+    /// Unknown sub class and protocol codes, kept as is.
+    Other(u8, u8),
+}
+
+impl ApplicationSpecificSubClass {
+    /// Decodes a sub class and protocol code pair without failing.
+    /// Unknown protocols are kept in the `Other` variant of their protocol,
+    /// and unknown sub classes in the `Other` variant of this enum, so they
+    /// convert back to the same codes. Use `TryFrom<(u8, u8)>` to reject
+    /// them instead.
+    pub fn decode(code: (u8, u8)) -> ApplicationSpecificSubClass {
+        match code {
+            (0x01, p) => ApplicationSpecificSubClass::FirmwareUpgrade( FirmwareUpgradeProtocol::decode(p) ),
+            (0x02, p) => ApplicationSpecificSubClass::IRDABridge( IRDABridgeProtocol::decode(p) ),
+            (0x03, p) => ApplicationSpecificSubClass::TestAndMeasurement( USBTestAndMeasureProtocol::decode(p) ),
+
+            (s, p) => ApplicationSpecificSubClass::Other(s, p),
+        }
+    }
+
+    /// Raw sub class and protocol codes.
+    pub const fn code(&self) -> (u8, u8) {
+        match *self {
+            ApplicationSpecificSubClass::FirmwareUpgrade(p)    => (0x01, p.code()),
+            ApplicationSpecificSubClass::IRDABridge(p)         => (0x02, p.code()),
+            ApplicationSpecificSubClass::TestAndMeasurement(p) => (0x03, p.code()),
+
+            ApplicationSpecificSubClass::Other(s, p) => (s, p),
+        }
+    }
+}
+
+impl core::convert::TryFrom<(u8, u8)> for ApplicationSpecificSubClass {
+    type Error = USBParseError;
+
+    fn try_from(code: (u8, u8)) -> Result<ApplicationSpecificSubClass, USBParseError> {
+        match code.0 {
+            0x01 => Ok( ApplicationSpecificSubClass::FirmwareUpgrade( FirmwareUpgradeProtocol::try_from(code.1)? ) ),
+            0x02 => Ok( ApplicationSpecificSubClass::IRDABridge( IRDABridgeProtocol::try_from(code.1)? ) ),
+            0x03 => Ok( ApplicationSpecificSubClass::TestAndMeasurement( USBTestAndMeasureProtocol::try_from(code.1)? ) ),
+
+            s => Err( USBParseError::UnknownSubClass(s) ),
+        }
+    }
+}
+
+impl core::convert::Into<(u8, u8)> for ApplicationSpecificSubClass {
+    fn into(self) -> (u8, u8) {
+        self.code()
+    }
+}
@@ -16,9 +16,16 @@ pub enum AVSubClass {
 
     /// AV Audio Streaming Interface.
     AudioStreaming,
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
 }
 
-impl super::USBSubClass for AVSubClass {}
+impl super::USBSubClass for AVSubClass {
+    fn decode(byte: u8) -> AVSubClass {
+        AVSubClass::try_from(byte).unwrap_or(AVSubClass::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for AVSubClass {
     type Error = USBParseError;
@@ -34,21 +41,33 @@ impl core::convert::TryFrom<u8> for AVSubClass {
     }
 }
 
-impl core::convert::Into<u8> for AVSubClass {
-    fn into(self) -> u8 {
-        match self {
+impl AVSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             AVSubClass::Control        => 0x01,
             AVSubClass::VideoStreaming => 0x02,
             AVSubClass::AudioStreaming => 0x03,
+
+            AVSubClass::Other(byte) => byte,
         }
     }
 }
 
+impl core::convert::Into<u8> for AVSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
 
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AudioSubClass {
-    /// Video Control Interface.
+    /// Undefined sub class, used by audio function interface associations.
+    Undefined,
+
+    /// Audio Control Interface.
     Control,
 
     /// Audio Streaming Interface.
@@ -56,15 +75,23 @@ pub enum AudioSubClass {
 
     /// MIDI Streaming Interface.
     MIDIStreaming,
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
 }
 
-impl super::USBSubClass for AudioSubClass {}
+impl super::USBSubClass for AudioSubClass {
+    fn decode(byte: u8) -> AudioSubClass {
+        AudioSubClass::try_from(byte).unwrap_or(AudioSubClass::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for AudioSubClass {
     type Error = USBParseError;
 
     fn try_from(byte: u8) -> Result<AudioSubClass, USBParseError> {
         match byte {
+            0x00 => Ok(AudioSubClass::Undefined),
             0x01 => Ok(AudioSubClass::Control),
             0x02 => Ok(AudioSubClass::Streaming),
             0x03 => Ok(AudioSubClass::MIDIStreaming),
@@ -74,16 +101,26 @@ impl core::convert::TryFrom<u8> for AudioSubClass {
     }
 }
 
-impl core::convert::Into<u8> for AudioSubClass {
-    fn into(self) -> u8 {
-        match self {
+impl AudioSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            AudioSubClass::Undefined     => 0x00,
             AudioSubClass::Control       => 0x01,
             AudioSubClass::Streaming     => 0x02,
             AudioSubClass::MIDIStreaming => 0x03,
+
+            AudioSubClass::Other(byte) => byte,
         }
     }
 }
 
+impl core::convert::Into<u8> for AudioSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
 
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -96,9 +133,16 @@ pub enum VideoSubClass {
 
     /// Video Interface Collection.
     InterfaceCollection,
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
 }
 
-impl super::USBSubClass for VideoSubClass {}
+impl super::USBSubClass for VideoSubClass {
+    fn decode(byte: u8) -> VideoSubClass {
+        VideoSubClass::try_from(byte).unwrap_or(VideoSubClass::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for VideoSubClass {
     type Error = USBParseError;
@@ -114,12 +158,21 @@ impl core::convert::TryFrom<u8> for VideoSubClass {
     }
 }
 
-impl core::convert::Into<u8> for VideoSubClass {
-    fn into(self) -> u8 {
-        match self {
+impl VideoSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             VideoSubClass::Control             => 0x01,
             VideoSubClass::Streaming           => 0x02,
             VideoSubClass::InterfaceCollection => 0x03,
+
+            VideoSubClass::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for VideoSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
@@ -47,11 +47,21 @@ pub enum CDCControlSubClass {
     /// Network Control Model.
     NetworkControl,
 
-    /// Vendor specific.
-    VendorSpecific,
+    /// Mobile Broadband Interface Model.
+    MBIM,
+
+    /// Vendor specific, codes 80h to FFh.
+    VendorSpecific(u8),
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
 }
 
-impl super::USBSubClass for CDCControlSubClass {}
+impl super::USBSubClass for CDCControlSubClass {
+    fn decode(byte: u8) -> CDCControlSubClass {
+        CDCControlSubClass::try_from(byte).unwrap_or(CDCControlSubClass::Other(byte))
+    }
+}
 
 impl TryFrom<u8> for CDCControlSubClass {
     type Error = USBParseError;
@@ -71,17 +81,19 @@ impl TryFrom<u8> for CDCControlSubClass {
             0x0B => Ok( CDCControlSubClass::OBEX ),
             0x0C => Ok( CDCControlSubClass::EthernetEmulation ),
             0x0D => Ok( CDCControlSubClass::NetworkControl ),
+            0x0E => Ok( CDCControlSubClass::MBIM ),
 
-            0x80..=0xFF => Ok( CDCControlSubClass::VendorSpecific ),
+            0x80..=0xFF => Ok( CDCControlSubClass::VendorSpecific(byte) ),
 
             s => Err( USBParseError::UnknownSubClass(s) ),
         }
     }
 }
 
-impl Into<u8> for CDCControlSubClass {
-    fn into(self) -> u8 {
-        match self {
+impl CDCControlSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             CDCControlSubClass::DirectLine         => 0x01,
             CDCControlSubClass::Abstract           => 0x02,
             CDCControlSubClass::Telephone          => 0x03,
@@ -95,8 +107,17 @@ impl Into<u8> for CDCControlSubClass {
             CDCControlSubClass::OBEX               => 0x0B,
             CDCControlSubClass::EthernetEmulation  => 0x0C,
             CDCControlSubClass::NetworkControl     => 0x0D,
+            CDCControlSubClass::MBIM               => 0x0E,
 
-            CDCControlSubClass::VendorSpecific => 0xFF,
+            CDCControlSubClass::VendorSpecific(byte) => byte,
+
+            CDCControlSubClass::Other(byte) => byte,
         }
     }
 }
+
+impl Into<u8> for CDCControlSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
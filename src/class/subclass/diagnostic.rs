@@ -0,0 +1,134 @@
+//! Diagnostic Device sub classes.
+//! Each sub class defines its own protocols, so both codes are decoded
+//! together.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticSubClass {
+    /// USB2 Compliance Device.
+    USB2Compliance,
+
+    /// Debug Target, vendor defined.
+    DebugTargetVendor,
+
+    /// Debug Target, GNU Remote Debug Command Set.
+    DebugTargetGNU,
+
+    /// Trace on DbC, undefined protocol.
+    DbCTraceUndefined,
+
+    /// Trace on DbC, vendor defined protocol.
+    DbCTraceVendor,
+
+    /// Dfx on DbC, undefined protocol.
+    DbCDfxUndefined,
+
+    /// Dfx on DbC, vendor defined protocol.
+    DbCDfxVendor,
+
+    /// Trace over General Purpose endpoint on DvC, vendor defined protocol.
+    DvCTraceGPVendor,
+
+    /// Trace over General Purpose endpoint on DvC, GNU protocol.
+    DvCTraceGPGNU,
+
+    /// Dfx on DvC, undefined protocol.
+    DvCDfxUndefined,
+
+    /// Dfx on DvC, vendor defined protocol.
+    DvCDfxVendor,
+
+    /// Trace on DvC, undefined protocol.
+    DvCTraceUndefined,
+
+    /// Trace on DvC, vendor defined protocol.
+    DvCTraceVendor,
+
+    /// Undefined sub class 08h.
+    Undefined,
+
+    /// Unknown sub class and protocol codes, kept as is.
+    Other(u8, u8),
+}
+
+impl core::convert::TryFrom<(u8, u8)> for DiagnosticSubClass {
+    type Error = USBParseError;
+
+    fn try_from(code: (u8, u8)) -> Result<DiagnosticSubClass, USBParseError> {
+        match code {
+            (0x01, 0x01) => Ok( DiagnosticSubClass::USB2Compliance ),
+            (0x01, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x02, 0x00) => Ok( DiagnosticSubClass::DebugTargetVendor ),
+            (0x02, 0x01) => Ok( DiagnosticSubClass::DebugTargetGNU ),
+            (0x02, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x03, 0x00) => Ok( DiagnosticSubClass::DbCTraceUndefined ),
+            (0x03, 0x01) => Ok( DiagnosticSubClass::DbCTraceVendor ),
+            (0x03, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x04, 0x00) => Ok( DiagnosticSubClass::DbCDfxUndefined ),
+            (0x04, 0x01) => Ok( DiagnosticSubClass::DbCDfxVendor ),
+            (0x04, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x05, 0x00) => Ok( DiagnosticSubClass::DvCTraceGPVendor ),
+            (0x05, 0x01) => Ok( DiagnosticSubClass::DvCTraceGPGNU ),
+            (0x05, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x06, 0x00) => Ok( DiagnosticSubClass::DvCDfxUndefined ),
+            (0x06, 0x01) => Ok( DiagnosticSubClass::DvCDfxVendor ),
+            (0x06, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x07, 0x00) => Ok( DiagnosticSubClass::DvCTraceUndefined ),
+            (0x07, 0x01) => Ok( DiagnosticSubClass::DvCTraceVendor ),
+            (0x07, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x08, 0x00) => Ok( DiagnosticSubClass::Undefined ),
+            (0x08, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (s, _) => Err( USBParseError::UnknownSubClass(s) ),
+        }
+    }
+}
+
+impl DiagnosticSubClass {
+    /// Decodes a sub class and protocol code pair without failing.
+    /// Unknown pairs are kept in the `Other` variant so they convert back to
+    /// the same codes. Use `TryFrom<(u8, u8)>` to reject them instead.
+    pub fn decode(code: (u8, u8)) -> DiagnosticSubClass {
+        DiagnosticSubClass::try_from(code).unwrap_or(DiagnosticSubClass::Other(code.0, code.1))
+    }
+
+    /// Raw sub class and protocol codes.
+    pub const fn code(&self) -> (u8, u8) {
+        match *self {
+            DiagnosticSubClass::USB2Compliance    => (0x01, 0x01),
+            DiagnosticSubClass::DebugTargetVendor => (0x02, 0x00),
+            DiagnosticSubClass::DebugTargetGNU    => (0x02, 0x01),
+            DiagnosticSubClass::DbCTraceUndefined => (0x03, 0x00),
+            DiagnosticSubClass::DbCTraceVendor    => (0x03, 0x01),
+            DiagnosticSubClass::DbCDfxUndefined   => (0x04, 0x00),
+            DiagnosticSubClass::DbCDfxVendor      => (0x04, 0x01),
+            DiagnosticSubClass::DvCTraceGPVendor  => (0x05, 0x00),
+            DiagnosticSubClass::DvCTraceGPGNU     => (0x05, 0x01),
+            DiagnosticSubClass::DvCDfxUndefined   => (0x06, 0x00),
+            DiagnosticSubClass::DvCDfxVendor      => (0x06, 0x01),
+            DiagnosticSubClass::DvCTraceUndefined => (0x07, 0x00),
+            DiagnosticSubClass::DvCTraceVendor    => (0x07, 0x01),
+            DiagnosticSubClass::Undefined         => (0x08, 0x00),
+
+            DiagnosticSubClass::Other(s, p) => (s, p),
+        }
+    }
+}
+
+impl core::convert::Into<(u8, u8)> for DiagnosticSubClass {
+    fn into(self) -> (u8, u8) {
+        self.code()
+    }
+}
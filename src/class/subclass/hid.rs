@@ -2,14 +2,55 @@
 
 
 
+use crate::error::*;
+
+
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HIDSubClass {
-    /// Boot sub class.
+    /// No subclass.
+    None,
+
+    /// Boot interface subclass.
     Boot,
 
-    /// Keyboard protocol.
-    Keyboard,
+    /// Unknown sub class code, kept as is.
+    Other(u8),
+}
+
+impl super::USBSubClass for HIDSubClass {
+    fn decode(byte: u8) -> HIDSubClass {
+        HIDSubClass::try_from(byte).unwrap_or(HIDSubClass::Other(byte))
+    }
+}
+
+impl core::convert::TryFrom<u8> for HIDSubClass {
+    type Error = USBParseError;
+
+    fn try_from(byte: u8) -> Result<HIDSubClass, USBParseError> {
+        match byte {
+            0x00 => Ok(HIDSubClass::None),
+            0x01 => Ok(HIDSubClass::Boot),
+
+            _ => Err( USBParseError::UnknownSubClass(byte) ),
+        }
+    }
+}
+
+impl HIDSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            HIDSubClass::None => 0x00,
+            HIDSubClass::Boot => 0x01,
+
+            HIDSubClass::Other(byte) => byte,
+        }
+    }
+}
 
-    /// Mouse protocol.
-    Mouse,
+impl core::convert::Into<u8> for HIDSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
 }
@@ -0,0 +1,162 @@
+//! Miscellaneous Device sub classes.
+//! Each sub class defines its own protocols, so both codes are decoded
+//! together.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MiscellaneousSubClass {
+    /// Active Sync device.
+    ActiveSync,
+
+    /// Palm Sync.
+    PalmSync,
+
+    /// Interface Association Descriptor.
+    InterfaceAssociation,
+
+    /// Wire Adapter Multifunction Peripheral programming interface.
+    WireAdapterMultifunction,
+
+    /// Cable Based Association Framework.
+    CableBasedAssociation,
+
+    /// RNDIS over Ethernet.
+    RNDISEthernet,
+
+    /// RNDIS over WiFi.
+    RNDISWiFi,
+
+    /// RNDIS over WiMAX.
+    RNDISWiMAX,
+
+    /// RNDIS over WWAN.
+    RNDISWWAN,
+
+    /// RNDIS for Raw IPv4.
+    RNDISRawIPv4,
+
+    /// RNDIS for Raw IPv6.
+    RNDISRawIPv6,
+
+    /// RNDIS for GPRS.
+    RNDISGPRS,
+
+    /// USB3 Vision Control Interface.
+    USB3VisionControl,
+
+    /// USB3 Vision Event Interface.
+    USB3VisionEvent,
+
+    /// USB3 Vision Streaming Interface.
+    USB3VisionStreaming,
+
+    /// Stream Transport Efficient Protocol for content protection.
+    STEP,
+
+    /// Stream Transport Efficient Protocol for Raw content protection.
+    STEPRaw,
+
+    /// DVB Command Interface in the interface association descriptor.
+    CommandInterfaceIAD,
+
+    /// DVB Command Interface in the interface descriptor.
+    CommandInterface,
+
+    /// DVB Media Interface in the interface descriptor.
+    MediaInterface,
+
+    /// Unknown sub class and protocol codes, kept as is.
+    Other(u8, u8),
+}
+
+impl core::convert::TryFrom<(u8, u8)> for MiscellaneousSubClass {
+    type Error = USBParseError;
+
+    fn try_from(code: (u8, u8)) -> Result<MiscellaneousSubClass, USBParseError> {
+        match code {
+            (0x01, 0x01) => Ok( MiscellaneousSubClass::ActiveSync ),
+            (0x01, 0x02) => Ok( MiscellaneousSubClass::PalmSync ),
+            (0x01, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x02, 0x01) => Ok( MiscellaneousSubClass::InterfaceAssociation ),
+            (0x02, 0x02) => Ok( MiscellaneousSubClass::WireAdapterMultifunction ),
+            (0x02, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x03, 0x01) => Ok( MiscellaneousSubClass::CableBasedAssociation ),
+            (0x03, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x04, 0x01) => Ok( MiscellaneousSubClass::RNDISEthernet ),
+            (0x04, 0x02) => Ok( MiscellaneousSubClass::RNDISWiFi ),
+            (0x04, 0x03) => Ok( MiscellaneousSubClass::RNDISWiMAX ),
+            (0x04, 0x04) => Ok( MiscellaneousSubClass::RNDISWWAN ),
+            (0x04, 0x05) => Ok( MiscellaneousSubClass::RNDISRawIPv4 ),
+            (0x04, 0x06) => Ok( MiscellaneousSubClass::RNDISRawIPv6 ),
+            (0x04, 0x07) => Ok( MiscellaneousSubClass::RNDISGPRS ),
+            (0x04, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x05, 0x00) => Ok( MiscellaneousSubClass::USB3VisionControl ),
+            (0x05, 0x01) => Ok( MiscellaneousSubClass::USB3VisionEvent ),
+            (0x05, 0x02) => Ok( MiscellaneousSubClass::USB3VisionStreaming ),
+            (0x05, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x06, 0x01) => Ok( MiscellaneousSubClass::STEP ),
+            (0x06, 0x02) => Ok( MiscellaneousSubClass::STEPRaw ),
+            (0x06, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (0x07, 0x00) => Ok( MiscellaneousSubClass::CommandInterfaceIAD ),
+            (0x07, 0x01) => Ok( MiscellaneousSubClass::CommandInterface ),
+            (0x07, 0x02) => Ok( MiscellaneousSubClass::MediaInterface ),
+            (0x07, p) => Err( USBParseError::UnknownProtocol(p) ),
+
+            (s, _) => Err( USBParseError::UnknownSubClass(s) ),
+        }
+    }
+}
+
+impl MiscellaneousSubClass {
+    /// Decodes a sub class and protocol code pair without failing.
+    /// Unknown pairs are kept in the `Other` variant so they convert back to
+    /// the same codes. Use `TryFrom<(u8, u8)>` to reject them instead.
+    pub fn decode(code: (u8, u8)) -> MiscellaneousSubClass {
+        MiscellaneousSubClass::try_from(code).unwrap_or(MiscellaneousSubClass::Other(code.0, code.1))
+    }
+
+    /// Raw sub class and protocol codes.
+    pub const fn code(&self) -> (u8, u8) {
+        match *self {
+            MiscellaneousSubClass::ActiveSync               => (0x01, 0x01),
+            MiscellaneousSubClass::PalmSync                 => (0x01, 0x02),
+            MiscellaneousSubClass::InterfaceAssociation     => (0x02, 0x01),
+            MiscellaneousSubClass::WireAdapterMultifunction => (0x02, 0x02),
+            MiscellaneousSubClass::CableBasedAssociation    => (0x03, 0x01),
+            MiscellaneousSubClass::RNDISEthernet            => (0x04, 0x01),
+            MiscellaneousSubClass::RNDISWiFi                => (0x04, 0x02),
+            MiscellaneousSubClass::RNDISWiMAX               => (0x04, 0x03),
+            MiscellaneousSubClass::RNDISWWAN                => (0x04, 0x04),
+            MiscellaneousSubClass::RNDISRawIPv4             => (0x04, 0x05),
+            MiscellaneousSubClass::RNDISRawIPv6             => (0x04, 0x06),
+            MiscellaneousSubClass::RNDISGPRS                => (0x04, 0x07),
+            MiscellaneousSubClass::USB3VisionControl        => (0x05, 0x00),
+            MiscellaneousSubClass::USB3VisionEvent          => (0x05, 0x01),
+            MiscellaneousSubClass::USB3VisionStreaming      => (0x05, 0x02),
+            MiscellaneousSubClass::STEP                     => (0x06, 0x01),
+            MiscellaneousSubClass::STEPRaw                  => (0x06, 0x02),
+            MiscellaneousSubClass::CommandInterfaceIAD      => (0x07, 0x00),
+            MiscellaneousSubClass::CommandInterface         => (0x07, 0x01),
+            MiscellaneousSubClass::MediaInterface           => (0x07, 0x02),
+
+            MiscellaneousSubClass::Other(s, p) => (s, p),
+        }
+    }
+}
+
+impl core::convert::Into<(u8, u8)> for MiscellaneousSubClass {
+    fn into(self) -> (u8, u8) {
+        self.code()
+    }
+}
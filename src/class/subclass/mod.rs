@@ -3,19 +3,34 @@
 
 
 
+mod app;
 mod av;
 mod cdc;
+mod diagnostic;
 mod hid;
+mod misc;
+mod physical;
 mod storage;
+mod wireless;
 
 
 
+pub use app::*;
 pub use av::*;
 pub use cdc::*;
+pub use diagnostic::*;
 pub use hid::*;
+pub use misc::*;
+pub use physical::*;
 pub use storage::*;
+pub use wireless::*;
 
 
 
 /// Common trait for all protocols.
-pub trait USBSubClass: TryFrom<u8> + Into<u8> {}
+pub trait USBSubClass: TryFrom<u8> + Into<u8> {
+    /// Decodes a sub class code without failing.
+    /// Unknown codes are kept in the `Other` variant so they convert back to
+    /// the same byte. Use `TryFrom<u8>` to reject them instead.
+    fn decode(byte: u8) -> Self;
+}
@@ -0,0 +1,51 @@
+//! Physical Interface Device sub classes.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhysicalSubClass {
+    /// No sub class defined by the Physical Interface Device specification.
+    Undefined,
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
+}
+
+impl super::USBSubClass for PhysicalSubClass {
+    fn decode(byte: u8) -> PhysicalSubClass {
+        PhysicalSubClass::try_from(byte).unwrap_or(PhysicalSubClass::Other(byte))
+    }
+}
+
+impl core::convert::TryFrom<u8> for PhysicalSubClass {
+    type Error = USBParseError;
+
+    fn try_from(byte: u8) -> Result<PhysicalSubClass, USBParseError> {
+        match byte {
+            0x00 => Ok(PhysicalSubClass::Undefined),
+
+            _ => Err( USBParseError::UnknownSubClass(byte) ),
+        }
+    }
+}
+
+impl PhysicalSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            PhysicalSubClass::Undefined => 0x00,
+
+            PhysicalSubClass::Other(byte) => byte,
+        }
+    }
+}
+
+impl core::convert::Into<u8> for PhysicalSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
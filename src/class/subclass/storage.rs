@@ -31,9 +31,16 @@ pub enum MassStorageSubClass {
 
     /// Vendor specific.
     VendorSpecific,
+
+    /// Unknown sub class code, kept as is.
+    Other(u8),
 }
 
-impl super::USBSubClass for MassStorageSubClass {}
+impl super::USBSubClass for MassStorageSubClass {
+    fn decode(byte: u8) -> MassStorageSubClass {
+        MassStorageSubClass::try_from(byte).unwrap_or(MassStorageSubClass::Other(byte))
+    }
+}
 
 impl core::convert::TryFrom<u8> for MassStorageSubClass {
     type Error = USBParseError;
@@ -54,9 +61,10 @@ impl core::convert::TryFrom<u8> for MassStorageSubClass {
     }
 }
 
-impl core::convert::Into<u8> for MassStorageSubClass {
-    fn into(self) -> u8 {
-        match self {
+impl MassStorageSubClass {
+    /// Raw sub class code.
+    pub const fn code(&self) -> u8 {
+        match *self {
             MassStorageSubClass::NotReported    => 0x00,
             MassStorageSubClass::RBC            => 0x01,
             MassStorageSubClass::MMC5           => 0x02,
@@ -65,6 +73,14 @@ impl core::convert::Into<u8> for MassStorageSubClass {
             MassStorageSubClass::LSDFS          => 0x07,
             MassStorageSubClass::IEEE1667       => 0x08,
             MassStorageSubClass::VendorSpecific => 0xFF,
+
+            MassStorageSubClass::Other(byte) => byte,
         }
     }
 }
+
+impl core::convert::Into<u8> for MassStorageSubClass {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
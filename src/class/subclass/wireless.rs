@@ -0,0 +1,65 @@
+//! Wireless Controller sub classes.
+
+
+
+use crate::error::*;
+use crate::class::protocol::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WirelessSubClass {
+    /// Radio frequency controller.
+    RadioFrequency(WirelessBaseProtocol),
+
+    /// Wireless USB Wire Adapter.
+    WireAdapter(WirelessAdapterProtocol),
+
+    /// Unknown sub class and protocol codes, kept as is.
+    Other(u8, u8),
+}
+
+impl WirelessSubClass {
+    /// Decodes a sub class and protocol code pair without failing.
+    /// Unknown protocols are kept in the `Other` variant of their protocol,
+    /// and unknown sub classes in the `Other` variant of this enum, so they
+    /// convert back to the same codes. Use `TryFrom<(u8, u8)>` to reject
+    /// them instead.
+    pub fn decode(code: (u8, u8)) -> WirelessSubClass {
+        match code {
+            (0x01, p) => WirelessSubClass::RadioFrequency( WirelessBaseProtocol::decode(p) ),
+            (0x02, p) => WirelessSubClass::WireAdapter( WirelessAdapterProtocol::decode(p) ),
+
+            (s, p) => WirelessSubClass::Other(s, p),
+        }
+    }
+
+    /// Raw sub class and protocol codes.
+    pub const fn code(&self) -> (u8, u8) {
+        match *self {
+            WirelessSubClass::RadioFrequency(p) => (0x01, p.code()),
+            WirelessSubClass::WireAdapter(p)    => (0x02, p.code()),
+
+            WirelessSubClass::Other(s, p) => (s, p),
+        }
+    }
+}
+
+impl core::convert::TryFrom<(u8, u8)> for WirelessSubClass {
+    type Error = USBParseError;
+
+    fn try_from(code: (u8, u8)) -> Result<WirelessSubClass, USBParseError> {
+        match code.0 {
+            0x01 => Ok( WirelessSubClass::RadioFrequency( WirelessBaseProtocol::try_from(code.1)? ) ),
+            0x02 => Ok( WirelessSubClass::WireAdapter( WirelessAdapterProtocol::try_from(code.1)? ) ),
+
+            s => Err( USBParseError::UnknownSubClass(s) ),
+        }
+    }
+}
+
+impl core::convert::Into<(u8, u8)> for WirelessSubClass {
+    fn into(self) -> (u8, u8) {
+        self.code()
+    }
+}
@@ -0,0 +1,120 @@
+//! USB Interface Association descriptor.
+//! A structure representing the interface association descriptor, which
+//! groups the interfaces of a function in a composite device.
+//! Documentation: Section 9.6.4 of the USB 3.0 specification.
+
+
+
+use crate::class::class::Class;
+use crate::error::*;
+use super::{ DescriptorType, ToBytes };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterfaceAssociationDescriptor {
+    /// Number of the first interface of the function.
+    bFirstInterface: u8,
+
+    /// Number of contiguous interfaces of the function.
+    bInterfaceCount: u8,
+
+    /// USB-IF class code, subclass code and protocol code of the function.
+    class: Class,
+
+    /// Index of string descriptor describing this function.
+    iFunction: u8,
+}
+
+impl InterfaceAssociationDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 8;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::InterfaceAssociation.code();
+
+    /// Creates an interface association descriptor.
+    pub const fn new(first_interface: u8, interface_count: u8, class: Class, string_index: u8) -> InterfaceAssociationDescriptor {
+        InterfaceAssociationDescriptor {
+            bFirstInterface: first_interface,
+            bInterfaceCount: interface_count,
+            class,
+            iFunction: string_index,
+        }
+    }
+
+    /// Parses an interface association descriptor from the start of the
+    /// given buffer.
+    /// Unknown class codes are kept as is, but classes that are only valid
+    /// in a device descriptor are rejected.
+    pub fn parse(bytes: &[u8]) -> Result<InterfaceAssociationDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        let class = Class::decode( (bytes[4], bytes[5], bytes[6]) );
+
+        if !class.ifdesc() {
+            return Err( USBParseError::InvalidClassLevel(bytes[4]) );
+        }
+
+        Ok(InterfaceAssociationDescriptor {
+            bFirstInterface: bytes[2],
+            bInterfaceCount: bytes[3],
+            class,
+            iFunction: bytes[7],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let (class, subclass, protocol) = self.class.code();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            self.bFirstInterface,
+            self.bInterfaceCount,
+            class, subclass, protocol,
+            self.iFunction,
+        ]
+    }
+
+    /// Number of the first interface of the function.
+    pub const fn first_interface(&self) -> u8 {
+        self.bFirstInterface
+    }
+
+    /// Number of contiguous interfaces of the function.
+    pub const fn interface_count(&self) -> u8 {
+        self.bInterfaceCount
+    }
+
+    /// Function class.
+    pub const fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Index of string descriptor describing this function.
+    pub const fn string_index(&self) -> u8 {
+        self.iFunction
+    }
+}
+
+impl ToBytes for InterfaceAssociationDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
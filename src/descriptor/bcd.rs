@@ -0,0 +1,83 @@
+//! Binary-coded decimal version numbers.
+//! Used in `bcdUSB`, `bcdDevice` and the class specific release numbers.
+
+
+
+/// A version number encoded as `JJ.M.N` in binary-coded decimal.
+/// The major version uses the high byte, the minor and sub-minor versions
+/// use the high and low nibbles of the low byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BCDVersion(u16);
+
+impl BCDVersion {
+    /// USB 1.1 specification release.
+    pub const USB11: BCDVersion = BCDVersion(0x0110);
+
+    /// USB 2.0 specification release.
+    pub const USB20: BCDVersion = BCDVersion(0x0200);
+
+    /// USB 2.1 specification release (BOS capable USB 2.0 device).
+    pub const USB21: BCDVersion = BCDVersion(0x0210);
+
+    /// USB 3.0 specification release.
+    pub const USB30: BCDVersion = BCDVersion(0x0300);
+
+    /// USB 3.1 specification release.
+    pub const USB31: BCDVersion = BCDVersion(0x0310);
+
+    /// USB 3.2 specification release.
+    pub const USB32: BCDVersion = BCDVersion(0x0320);
+
+    /// Creates a version from its raw BCD encoding.
+    pub const fn from_raw(raw: u16) -> BCDVersion {
+        BCDVersion(raw)
+    }
+
+    /// Creates a version from its decimal components.
+    /// Components that do not fit in their BCD digits are truncated.
+    pub const fn new(major: u8, minor: u8, subminor: u8) -> BCDVersion {
+        let major = (((major / 10) % 10) << 4) | (major % 10);
+        let low = ((minor % 10) << 4) | (subminor % 10);
+
+        BCDVersion(((major as u16) << 8) | low as u16)
+    }
+
+    /// Returns the raw BCD encoding.
+    pub const fn raw(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the major version.
+    pub const fn major(&self) -> u8 {
+        let high = (self.0 >> 8) as u8;
+        (high >> 4) * 10 + (high & 0x0F)
+    }
+
+    /// Returns the minor version.
+    pub const fn minor(&self) -> u8 {
+        ((self.0 >> 4) & 0x0F) as u8
+    }
+
+    /// Returns the sub-minor version.
+    pub const fn subminor(&self) -> u8 {
+        (self.0 & 0x0F) as u8
+    }
+}
+
+impl core::fmt::Display for BCDVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}.{}{}", self.major(), self.minor(), self.subminor())
+    }
+}
+
+impl From<u16> for BCDVersion {
+    fn from(raw: u16) -> BCDVersion {
+        BCDVersion(raw)
+    }
+}
+
+impl From<BCDVersion> for u16 {
+    fn from(version: BCDVersion) -> u16 {
+        version.0
+    }
+}
@@ -0,0 +1,126 @@
+//! USB Binary Object Store descriptor.
+//! A structure representing the BOS descriptor and the device capabilities
+//! that follow it.
+//! Documentation: Section 9.6.2 of the USB 3.2 specification.
+
+
+
+use crate::error::*;
+use super::{ BosWriter, DescriptorType, Descriptors, DeviceCapability, RawDescriptor, ToBytes, read_u16 };
+
+
+
+/// The Binary Object Store: the BOS descriptor followed by its device
+/// capability descriptors.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug)]
+pub struct BosDescriptor<'a> {
+    /// Total length of the BOS descriptor and its capabilities.
+    wTotalLength: u16,
+
+    /// Number of device capability descriptors.
+    bNumDeviceCaps: u8,
+
+    /// Device capability descriptors.
+    body: &'a [u8],
+}
+
+impl<'a> BosDescriptor<'a> {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 5;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::BOS.code();
+
+    /// Parses the Binary Object Store from the given buffer.
+    /// The buffer must contain at least `wTotalLength` bytes. Every device
+    /// capability is checked to be well formed.
+    pub fn parse(bytes: &'a [u8]) -> Result<BosDescriptor<'a>, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        let total = read_u16(bytes, 2);
+
+        if total < Self::LENGTH as u16 {
+            return Err( USBParseError::InvalidTotalLength(total) );
+        }
+
+        if bytes.len() < total as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let body = &bytes[Self::LENGTH as usize..total as usize];
+
+        let mut rest = body;
+
+        while !rest.is_empty() {
+            let (raw, tail) = RawDescriptor::split(rest)?;
+            DeviceCapability::parse(raw)?;
+            rest = tail;
+        }
+
+        Ok(BosDescriptor {
+            wTotalLength: total,
+            bNumDeviceCaps: bytes[4],
+            body,
+        })
+    }
+
+    /// Total length of the BOS descriptor and its capabilities.
+    pub const fn total_length(&self) -> u16 {
+        self.wTotalLength
+    }
+
+    /// Number of device capabilities reported by the BOS descriptor.
+    pub const fn num_capabilities(&self) -> u8 {
+        self.bNumDeviceCaps
+    }
+
+    /// Device capabilities of the device.
+    pub const fn capabilities(&self) -> Capabilities<'a> {
+        Capabilities { descriptors: Descriptors::new(self.body) }
+    }
+}
+
+impl<'a> ToBytes for BosDescriptor<'a> {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize + self.body.len()
+    }
+
+    /// Writes the store with its total length and number of capabilities
+    /// recomputed from its descriptors.
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let mut writer = BosWriter::new(buffer)?;
+
+        writer.push(self.body)?;
+
+        writer.finish()
+    }
+}
+
+/// Iterator over the device capabilities of a Binary Object Store.
+#[derive(Clone, Copy, Debug)]
+pub struct Capabilities<'a> {
+    /// Capability descriptors not yet visited.
+    descriptors: Descriptors<'a>,
+}
+
+impl<'a> Iterator for Capabilities<'a> {
+    type Item = DeviceCapability<'a>;
+
+    fn next(&mut self) -> Option<DeviceCapability<'a>> {
+        let raw = self.descriptors.next()?;
+
+        // Capabilities are validated when parsing the store.
+        DeviceCapability::parse(raw).ok()
+    }
+}
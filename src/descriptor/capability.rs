@@ -0,0 +1,616 @@
+//! USB Device Capability descriptors.
+//! Capabilities reported through the Binary Object Store.
+//! Documentation: Section 9.6.2 of the USB 3.2 specification.
+
+
+
+use crate::class::class::Class;
+use crate::error::*;
+use super::{ BCDVersion, DescriptorType, RawDescriptor, Speed, ToBytes, read_u16, read_u32, reserve, write_u16, write_u32 };
+
+
+
+/// Copies a 16 byte UUID at the given offset.
+fn read_uuid(bytes: &[u8], offset: usize) -> [u8; 16] {
+    let mut uuid = [0u8; 16];
+    uuid.copy_from_slice(&bytes[offset..offset + 16]);
+    uuid
+}
+
+
+
+/// A device capability described in the Binary Object Store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceCapability<'a> {
+    /// USB 2.0 Extension (02h).
+    USB20Extension(USB20Extension),
+
+    /// SuperSpeed USB (03h).
+    SuperSpeed(SuperSpeedCapability),
+
+    /// Container ID (04h).
+    ContainerID(ContainerID),
+
+    /// Platform (05h).
+    Platform(PlatformCapability<'a>),
+
+    /// SuperSpeedPlus USB (0Ah).
+    SuperSpeedPlus(SuperSpeedPlusCapability<'a>),
+
+    /// Precision Time Measurement (0Bh).
+    PrecisionTimeMeasurement,
+
+    /// Billboard (0Dh).
+    Billboard(BillboardCapability<'a>),
+
+    /// Configuration Summary (10h).
+    ConfigurationSummary(ConfigurationSummary<'a>),
+
+    /// Any other capability, kept as is.
+    Unknown(RawDescriptor<'a>),
+}
+
+impl<'a> DeviceCapability<'a> {
+    /// Descriptor type of a device capability descriptor.
+    pub const TYPE: u8 = DescriptorType::DeviceCapability.code();
+
+    /// Decodes a device capability descriptor.
+    pub fn parse(raw: RawDescriptor<'a>) -> Result<DeviceCapability<'a>, USBParseError> {
+        if raw.descriptor_type() != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(raw.descriptor_type()) );
+        }
+
+        let bytes = raw.bytes();
+
+        if bytes.len() < 3 {
+            return Err( USBParseError::InvalidLength(raw.length()) );
+        }
+
+        let minimum = match bytes[2] {
+            0x02 => 7,
+            0x03 => 10,
+            0x04 => 20,
+            0x05 => 20,
+            0x0A => match bytes.len() >= 8 {
+                true => 12 + 4 * ((read_u32(bytes, 4) & 0x1F) as usize + 1),
+                _ => 12,
+            },
+            0x0B => 3,
+            0x0D => match bytes.len() >= 5 {
+                true => 44 + 4 * bytes[4] as usize,
+                _ => 44,
+            },
+            0x10 => match bytes.len() >= 9 {
+                true => 9 + bytes[8] as usize,
+                _ => 9,
+            },
+            _ => 3,
+        };
+
+        if bytes.len() < minimum {
+            return Err( USBParseError::InvalidLength(raw.length()) );
+        }
+
+        let capability = match bytes[2] {
+            0x02 => DeviceCapability::USB20Extension( USB20Extension { bmAttributes: read_u32(bytes, 3) } ),
+
+            0x03 => DeviceCapability::SuperSpeed( SuperSpeedCapability {
+                bmAttributes: bytes[3],
+                wSpeedsSupported: read_u16(bytes, 4),
+                bFunctionalitySupport: bytes[6],
+                bU1DevExitLat: bytes[7],
+                wU2DevExitLat: read_u16(bytes, 8),
+            } ),
+
+            0x04 => DeviceCapability::ContainerID( ContainerID( read_uuid(bytes, 4) ) ),
+
+            0x05 => DeviceCapability::Platform( PlatformCapability { bytes } ),
+
+            0x0A => DeviceCapability::SuperSpeedPlus( SuperSpeedPlusCapability { bytes } ),
+
+            0x0B => DeviceCapability::PrecisionTimeMeasurement,
+
+            0x0D => DeviceCapability::Billboard( BillboardCapability { bytes } ),
+
+            0x10 => DeviceCapability::ConfigurationSummary( ConfigurationSummary { bytes } ),
+
+            _ => DeviceCapability::Unknown(raw),
+        };
+
+        Ok(capability)
+    }
+}
+
+impl<'a> ToBytes for DeviceCapability<'a> {
+    fn size(&self) -> usize {
+        match self {
+            DeviceCapability::USB20Extension(_) => 7,
+            DeviceCapability::SuperSpeed(_) => 10,
+            DeviceCapability::ContainerID(_) => 20,
+            DeviceCapability::Platform(c) => c.bytes.len(),
+            DeviceCapability::SuperSpeedPlus(c) => c.bytes.len(),
+            DeviceCapability::PrecisionTimeMeasurement => 3,
+            DeviceCapability::Billboard(c) => c.bytes.len(),
+            DeviceCapability::ConfigurationSummary(c) => c.bytes.len(),
+            DeviceCapability::Unknown(raw) => raw.bytes().len(),
+        }
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let size = self.size();
+
+        let (code, bytes) = match self {
+            DeviceCapability::Platform(c) => return c.bytes.to_bytes(buffer),
+            DeviceCapability::SuperSpeedPlus(c) => return c.bytes.to_bytes(buffer),
+            DeviceCapability::Billboard(c) => return c.bytes.to_bytes(buffer),
+            DeviceCapability::ConfigurationSummary(c) => return c.bytes.to_bytes(buffer),
+            DeviceCapability::Unknown(raw) => return raw.to_bytes(buffer),
+
+            DeviceCapability::USB20Extension(c) => {
+                let bytes = reserve(buffer, size)?;
+                write_u32(bytes, 3, c.bmAttributes);
+                (0x02, bytes)
+            },
+
+            DeviceCapability::SuperSpeed(c) => {
+                let bytes = reserve(buffer, size)?;
+                bytes[3] = c.bmAttributes;
+                write_u16(bytes, 4, c.wSpeedsSupported);
+                bytes[6] = c.bFunctionalitySupport;
+                bytes[7] = c.bU1DevExitLat;
+                write_u16(bytes, 8, c.wU2DevExitLat);
+                (0x03, bytes)
+            },
+
+            DeviceCapability::ContainerID(c) => {
+                let bytes = reserve(buffer, size)?;
+                bytes[3] = 0;
+                bytes[4..20].copy_from_slice(&c.0);
+                (0x04, bytes)
+            },
+
+            DeviceCapability::PrecisionTimeMeasurement => (0x0B, reserve(buffer, size)?),
+        };
+
+        bytes[0] = size as u8;
+        bytes[1] = Self::TYPE;
+        bytes[2] = code;
+
+        Ok(size)
+    }
+}
+
+
+
+/// USB 2.0 Extension capability.
+/// Reports Link Power Management support and its BESL values.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct USB20Extension {
+    /// Capability attributes.
+    bmAttributes: u32,
+}
+
+impl USB20Extension {
+    /// Creates the capability from its raw attributes.
+    pub const fn new(attributes: u32) -> USB20Extension {
+        USB20Extension { bmAttributes: attributes }
+    }
+
+    /// Raw capability attributes.
+    pub const fn attributes(&self) -> u32 {
+        self.bmAttributes
+    }
+
+    /// Returns `true` if the device supports Link Power Management.
+    pub const fn lpm(&self) -> bool {
+        (self.bmAttributes & (1 << 1)) != 0
+    }
+
+    /// Returns `true` if the device supports BESL and alternate HIRD.
+    pub const fn besl(&self) -> bool {
+        (self.bmAttributes & (1 << 2)) != 0
+    }
+
+    /// Recommended baseline BESL value, if reported.
+    pub const fn baseline_besl(&self) -> Option<u8> {
+        match (self.bmAttributes & (1 << 3)) != 0 {
+            true => Some( ((self.bmAttributes >> 8) & 0x0F) as u8 ),
+            _ => None,
+        }
+    }
+
+    /// Recommended deep BESL value, if reported.
+    pub const fn deep_besl(&self) -> Option<u8> {
+        match (self.bmAttributes & (1 << 4)) != 0 {
+            true => Some( ((self.bmAttributes >> 12) & 0x0F) as u8 ),
+            _ => None,
+        }
+    }
+}
+
+
+
+/// SuperSpeed USB capability.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuperSpeedCapability {
+    /// Capability attributes.
+    bmAttributes: u8,
+
+    /// Speeds supported by the device.
+    wSpeedsSupported: u16,
+
+    /// Lowest speed at which all the functionality is available.
+    bFunctionalitySupport: u8,
+
+    /// U1 device exit latency, in microseconds.
+    bU1DevExitLat: u8,
+
+    /// U2 device exit latency, in microseconds.
+    wU2DevExitLat: u16,
+}
+
+impl SuperSpeedCapability {
+    /// Creates the capability from its raw fields.
+    pub const fn new(attributes: u8, speeds_supported: u16, functionality_support: u8, u1_exit_latency: u8, u2_exit_latency: u16) -> SuperSpeedCapability {
+        SuperSpeedCapability {
+            bmAttributes: attributes,
+            wSpeedsSupported: speeds_supported,
+            bFunctionalitySupport: functionality_support,
+            bU1DevExitLat: u1_exit_latency,
+            wU2DevExitLat: u2_exit_latency,
+        }
+    }
+
+    /// Returns `true` if the device can generate Latency Tolerance Messages.
+    pub const fn ltm(&self) -> bool {
+        (self.bmAttributes & (1 << 1)) != 0
+    }
+
+    /// Raw bitmap of supported speeds.
+    pub const fn speeds_supported(&self) -> u16 {
+        self.wSpeedsSupported
+    }
+
+    /// Returns `true` if the device supports operating at the given speed.
+    /// SuperSpeedPlus is reported through its own capability.
+    pub const fn supports(&self, speed: Speed) -> bool {
+        let bit = match speed {
+            Speed::Low => 0,
+            Speed::Full => 1,
+            Speed::High => 2,
+            Speed::Super => 3,
+            Speed::SuperPlus => return false,
+        };
+
+        (self.wSpeedsSupported & (1 << bit)) != 0
+    }
+
+    /// Lowest speed at which all the functionality is available.
+    pub const fn functionality_support(&self) -> Option<Speed> {
+        match self.bFunctionalitySupport {
+            0 => Some(Speed::Low),
+            1 => Some(Speed::Full),
+            2 => Some(Speed::High),
+            3 => Some(Speed::Super),
+            _ => None,
+        }
+    }
+
+    /// U1 device exit latency, in microseconds.
+    pub const fn u1_exit_latency(&self) -> u8 {
+        self.bU1DevExitLat
+    }
+
+    /// U2 device exit latency, in microseconds.
+    pub const fn u2_exit_latency(&self) -> u16 {
+        self.wU2DevExitLat
+    }
+}
+
+
+
+/// Container ID capability.
+/// A UUID shared by all the functions of a physical device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContainerID(pub [u8; 16]);
+
+
+
+/// Platform capability.
+/// A platform specific capability identified by a UUID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlatformCapability<'a> {
+    /// The whole capability descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> PlatformCapability<'a> {
+    /// UUID identifying the platform capability.
+    pub fn uuid(&self) -> [u8; 16] {
+        read_uuid(self.bytes, 4)
+    }
+
+    /// Capability specific data.
+    pub fn data(&self) -> &'a [u8] {
+        &self.bytes[20..]
+    }
+}
+
+
+
+/// SuperSpeedPlus USB capability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuperSpeedPlusCapability<'a> {
+    /// The whole capability descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> SuperSpeedPlusCapability<'a> {
+    /// Raw capability attributes.
+    pub const fn attributes(&self) -> u32 {
+        read_u32(self.bytes, 4)
+    }
+
+    /// Number of sublink speed attributes.
+    pub const fn sublink_speed_attribute_count(&self) -> u8 {
+        (self.attributes() & 0x1F) as u8 + 1
+    }
+
+    /// Number of sublink speed IDs.
+    pub const fn sublink_speed_id_count(&self) -> u8 {
+        ((self.attributes() >> 5) & 0x0F) as u8 + 1
+    }
+
+    /// Sublink speed ID of the minimum functional speed.
+    pub const fn min_speed_id(&self) -> u8 {
+        (read_u16(self.bytes, 8) & 0x0F) as u8
+    }
+
+    /// Minimum functional receive lane count.
+    pub const fn min_rx_lanes(&self) -> u8 {
+        ((read_u16(self.bytes, 8) >> 8) & 0x0F) as u8
+    }
+
+    /// Minimum functional transmit lane count.
+    pub const fn min_tx_lanes(&self) -> u8 {
+        ((read_u16(self.bytes, 8) >> 12) & 0x0F) as u8
+    }
+
+    /// Sublink speed attributes.
+    pub fn sublink_speeds(&self) -> SublinkSpeeds<'a> {
+        let count = self.sublink_speed_attribute_count() as usize;
+
+        SublinkSpeeds { bytes: &self.bytes[12..12 + 4 * count] }
+    }
+}
+
+/// Iterator over the sublink speed attributes of a SuperSpeedPlus capability.
+#[derive(Clone, Debug)]
+pub struct SublinkSpeeds<'a> {
+    /// Attributes not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for SublinkSpeeds<'a> {
+    type Item = SublinkSpeed;
+
+    fn next(&mut self) -> Option<SublinkSpeed> {
+        if self.bytes.len() < 4 {
+            return None;
+        }
+
+        let attribute = read_u32(self.bytes, 0);
+        self.bytes = &self.bytes[4..];
+
+        Some( SublinkSpeed(attribute) )
+    }
+}
+
+/// A sublink speed attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SublinkSpeed(pub u32);
+
+impl SublinkSpeed {
+    /// Sublink speed attribute ID.
+    pub const fn id(&self) -> u8 {
+        (self.0 & 0x0F) as u8
+    }
+
+    /// Lane speed exponent: 0 for b/s, 1 for Kb/s, 2 for Mb/s, 3 for Gb/s.
+    pub const fn exponent(&self) -> u8 {
+        ((self.0 >> 4) & 0x03) as u8
+    }
+
+    /// Returns `true` if the sublink is asymmetric.
+    pub const fn asymmetric(&self) -> bool {
+        (self.0 & (1 << 6)) != 0
+    }
+
+    /// Returns `true` if the attribute describes the transmit direction of
+    /// an asymmetric sublink.
+    pub const fn transmit(&self) -> bool {
+        (self.0 & (1 << 7)) != 0
+    }
+
+    /// Link protocol: 0 for SuperSpeed, 1 for SuperSpeedPlus.
+    pub const fn protocol(&self) -> u8 {
+        ((self.0 >> 14) & 0x03) as u8
+    }
+
+    /// Lane speed mantissa.
+    pub const fn mantissa(&self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    /// Lane speed in bits per second.
+    pub const fn bits_per_second(&self) -> u64 {
+        let scale: u64 = match self.exponent() {
+            0 => 1,
+            1 => 1_000,
+            2 => 1_000_000,
+            _ => 1_000_000_000,
+        };
+
+        self.mantissa() as u64 * scale
+    }
+}
+
+
+
+/// Billboard capability.
+/// Reports the Alternate Modes of a USB Type-C device and their state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BillboardCapability<'a> {
+    /// The whole capability descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> BillboardCapability<'a> {
+    /// Index of string descriptor with the additional information URL.
+    pub const fn additional_info_url_index(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Number of Alternate Modes supported.
+    pub const fn num_alternate_modes(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    /// Index of the preferred Alternate Mode.
+    pub const fn preferred_alternate_mode(&self) -> u8 {
+        self.bytes[5]
+    }
+
+    /// Raw VCONN power needed by the adapter for full functionality.
+    pub const fn vconn_power(&self) -> u16 {
+        read_u16(self.bytes, 6)
+    }
+
+    /// Configuration state of the Alternate Mode at the given index.
+    pub const fn state(&self, index: u8) -> Option<AlternateModeState> {
+        if index >= self.num_alternate_modes() || index >= 128 {
+            return None;
+        }
+
+        let byte = self.bytes[8 + (index as usize / 4)];
+
+        let state = match (byte >> ((index % 4) * 2)) & 0x03 {
+            0 => AlternateModeState::Unspecified,
+            1 => AlternateModeState::NotAttempted,
+            2 => AlternateModeState::Unsuccessful,
+            _ => AlternateModeState::Successful,
+        };
+
+        Some(state)
+    }
+
+    /// Billboard Capability version.
+    pub const fn version(&self) -> BCDVersion {
+        BCDVersion::from_raw( read_u16(self.bytes, 40) )
+    }
+
+    /// Raw additional failure information.
+    pub const fn additional_failure_info(&self) -> u8 {
+        self.bytes[42]
+    }
+
+    /// Alternate Modes supported by the device.
+    pub fn alternate_modes(&self) -> AlternateModes<'a> {
+        let count = self.num_alternate_modes() as usize;
+
+        AlternateModes { bytes: &self.bytes[44..44 + 4 * count] }
+    }
+}
+
+/// Configuration state of a Billboard Alternate Mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlternateModeState {
+    /// Unspecified error.
+    Unspecified,
+
+    /// Configuration not attempted or exited.
+    NotAttempted,
+
+    /// Configuration attempted but unsuccessful.
+    Unsuccessful,
+
+    /// Configuration successful.
+    Successful,
+}
+
+/// An Alternate Mode listed in a Billboard capability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlternateMode {
+    /// Standard or Vendor ID.
+    pub svid: u16,
+
+    /// Index of the mode within the SVID.
+    pub mode: u8,
+
+    /// Index of string descriptor describing the mode.
+    pub string_index: u8,
+}
+
+/// Iterator over the Alternate Modes of a Billboard capability.
+#[derive(Clone, Debug)]
+pub struct AlternateModes<'a> {
+    /// Alternate Modes not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for AlternateModes<'a> {
+    type Item = AlternateMode;
+
+    fn next(&mut self) -> Option<AlternateMode> {
+        if self.bytes.len() < 4 {
+            return None;
+        }
+
+        let mode = AlternateMode {
+            svid: read_u16(self.bytes, 0),
+            mode: self.bytes[2],
+            string_index: self.bytes[3],
+        };
+
+        self.bytes = &self.bytes[4..];
+
+        Some(mode)
+    }
+}
+
+
+
+/// Configuration Summary capability.
+/// Lists the configurations that implement a given function class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigurationSummary<'a> {
+    /// The whole capability descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> ConfigurationSummary<'a> {
+    /// Configuration Summary version.
+    pub const fn version(&self) -> BCDVersion {
+        BCDVersion::from_raw( read_u16(self.bytes, 3) )
+    }
+
+    /// Raw class, subclass and protocol codes of the function.
+    pub const fn class_code(&self) -> (u8, u8, u8) {
+        (self.bytes[5], self.bytes[6], self.bytes[7])
+    }
+
+    /// Class of the function.
+    /// Unknown codes are kept as is.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// Indices of the configurations that implement the function.
+    pub fn configurations(&self) -> &'a [u8] {
+        let count = self.bytes[8] as usize;
+
+        &self.bytes[9..9 + count]
+    }
+}
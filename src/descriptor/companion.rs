@@ -0,0 +1,214 @@
+//! USB Endpoint Companion descriptors.
+//! Structures representing the SuperSpeed Endpoint Companion descriptor and
+//! the SuperSpeedPlus Isochronous Endpoint Companion descriptor, which
+//! follow the endpoint descriptors of USB 3.x devices.
+//! Documentation: Sections 9.6.7 and 9.6.8 of the USB 3.2 specification.
+
+
+
+use crate::error::*;
+use super::{ DescriptorType, ToBytes, read_u16, read_u32 };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuperSpeedEndpointCompanion {
+    /// Number of additional packets the endpoint can send or receive as part
+    /// of a burst (0 to 15).
+    bMaxBurst: u8,
+
+    /// Maximum number of streams (bulk) or packets per service interval
+    /// (isochronous).
+    bmAttributes: u8,
+
+    /// Total number of bytes moved every service interval by a periodic
+    /// endpoint.
+    wBytesPerInterval: u16,
+}
+
+impl SuperSpeedEndpointCompanion {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 6;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::SuperSpeedEndpointCompanion.code();
+
+    /// Creates a SuperSpeed endpoint companion descriptor.
+    pub const fn new(max_burst: u8, attributes: u8, bytes_per_interval: u16) -> SuperSpeedEndpointCompanion {
+        SuperSpeedEndpointCompanion {
+            bMaxBurst: max_burst,
+            bmAttributes: attributes,
+            wBytesPerInterval: bytes_per_interval,
+        }
+    }
+
+    /// Parses a SuperSpeed endpoint companion descriptor from the start of
+    /// the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<SuperSpeedEndpointCompanion, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        Ok( Self::from_bytes(bytes) )
+    }
+
+    /// Decodes a companion descriptor that has already been validated.
+    pub(crate) const fn from_bytes(bytes: &[u8]) -> SuperSpeedEndpointCompanion {
+        SuperSpeedEndpointCompanion {
+            bMaxBurst: bytes[2],
+            bmAttributes: bytes[3],
+            wBytesPerInterval: read_u16(bytes, 4),
+        }
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let bytes = self.wBytesPerInterval.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            self.bMaxBurst,
+            self.bmAttributes,
+            bytes[0], bytes[1],
+        ]
+    }
+
+    /// Raw `bMaxBurst` field: the number of packets in a burst, minus one.
+    pub const fn max_burst(&self) -> u8 {
+        self.bMaxBurst
+    }
+
+    /// Number of packets the endpoint can send or receive in a burst.
+    /// `bMaxBurst` is not range checked, so the size may exceed 16.
+    pub const fn burst_size(&self) -> u16 {
+        self.bMaxBurst as u16 + 1
+    }
+
+    /// Raw endpoint companion attributes.
+    pub const fn attributes(&self) -> u8 {
+        self.bmAttributes
+    }
+
+    /// Raw `MaxStreams` field of a bulk endpoint (0 to 16).
+    pub const fn max_streams(&self) -> u8 {
+        self.bmAttributes & 0x1F
+    }
+
+    /// Number of streams supported by a bulk endpoint.
+    /// Returns 0 if the endpoint does not support streams.
+    pub const fn streams(&self) -> u32 {
+        match self.max_streams() {
+            0 => 0,
+            n => 1 << n,
+        }
+    }
+
+    /// Raw `Mult` field of an isochronous endpoint: the number of bursts in
+    /// a service interval, minus one.
+    pub const fn mult(&self) -> u8 {
+        self.bmAttributes & 0x03
+    }
+
+    /// Returns `true` if an isochronous endpoint is followed by a
+    /// SuperSpeedPlus Isochronous Endpoint Companion descriptor.
+    /// `mult` is then ignored.
+    pub const fn ssp_iso_companion(&self) -> bool {
+        (self.bmAttributes & (1 << 7)) != 0
+    }
+
+    /// Total number of bytes moved every service interval by a periodic
+    /// endpoint.
+    pub const fn bytes_per_interval(&self) -> u16 {
+        self.wBytesPerInterval
+    }
+}
+
+impl ToBytes for SuperSpeedEndpointCompanion {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuperSpeedPlusIsochronousCompanion {
+    /// Total number of bytes moved every service interval.
+    dwBytesPerInterval: u32,
+}
+
+impl SuperSpeedPlusIsochronousCompanion {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 8;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion.code();
+
+    /// Creates a SuperSpeedPlus isochronous endpoint companion descriptor.
+    pub const fn new(bytes_per_interval: u32) -> SuperSpeedPlusIsochronousCompanion {
+        SuperSpeedPlusIsochronousCompanion { dwBytesPerInterval: bytes_per_interval }
+    }
+
+    /// Parses a SuperSpeedPlus isochronous endpoint companion descriptor
+    /// from the start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<SuperSpeedPlusIsochronousCompanion, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        Ok( Self::from_bytes(bytes) )
+    }
+
+    /// Decodes a companion descriptor that has already been validated.
+    pub(crate) const fn from_bytes(bytes: &[u8]) -> SuperSpeedPlusIsochronousCompanion {
+        SuperSpeedPlusIsochronousCompanion { dwBytesPerInterval: read_u32(bytes, 4) }
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let bytes = self.dwBytesPerInterval.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            0, 0,
+            bytes[0], bytes[1], bytes[2], bytes[3],
+        ]
+    }
+
+    /// Total number of bytes moved every service interval.
+    pub const fn bytes_per_interval(&self) -> u32 {
+        self.dwBytesPerInterval
+    }
+}
+
+impl ToBytes for SuperSpeedPlusIsochronousCompanion {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
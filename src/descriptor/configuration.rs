@@ -0,0 +1,432 @@
+//! USB Configuration descriptor.
+//! A structure representing the USB configuration descriptor and the full
+//! configuration returned by `GET_DESCRIPTOR(CONFIGURATION)`.
+//! Documentation: Section 9.6.3 of the USB 3.0 specification.
+
+
+
+use crate::error::*;
+use super::{
+    Associations, ConfigurationWriter, DescriptorType, Descriptors, Functions, Interfaces, RawDescriptor, ToBytes,
+    EndpointDescriptor, InterfaceAssociationDescriptor, InterfaceDescriptor,
+    read_u16,
+    tree,
+};
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigurationDescriptor {
+    /// Total length of data returned for this configuration.
+    wTotalLength: u16,
+
+    /// Number of interfaces supported by this configuration.
+    bNumInterfaces: u8,
+
+    /// Value to use as an argument to `SET_CONFIGURATION`.
+    bConfigurationValue: u8,
+
+    /// Index of string descriptor describing this configuration.
+    iConfiguration: u8,
+
+    /// Configuration characteristics.
+    bmAttributes: u8,
+
+    /// Maximum power consumption, in 2 mA units (8 mA for SuperSpeed).
+    bMaxPower: u8,
+}
+
+impl ConfigurationDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 9;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::Configuration.code();
+
+    /// Creates a configuration descriptor.
+    /// `wTotalLength` and `bNumInterfaces` are filled in when the
+    /// configuration is emitted through a `ConfigurationWriter`. The
+    /// reserved bit 7 of the attributes is always set.
+    pub const fn new(value: u8, string_index: u8, attributes: u8, max_power: u8) -> ConfigurationDescriptor {
+        ConfigurationDescriptor {
+            wTotalLength: Self::LENGTH as u16,
+            bNumInterfaces: 0,
+            bConfigurationValue: value,
+            iConfiguration: string_index,
+            bmAttributes: attributes | 0x80,
+            bMaxPower: max_power,
+        }
+    }
+
+    /// Parses a configuration descriptor from the start of the given buffer.
+    /// Only the 9 byte header is read.
+    pub fn parse(bytes: &[u8]) -> Result<ConfigurationDescriptor, USBParseError> {
+        Self::parse_as(bytes, Self::TYPE)
+    }
+
+    /// Parses a configuration descriptor header of the given type.
+    /// Other speed configurations share the layout of configurations.
+    fn parse_as(bytes: &[u8], kind: u8) -> Result<ConfigurationDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != kind {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        Ok(ConfigurationDescriptor {
+            wTotalLength: read_u16(bytes, 2),
+            bNumInterfaces: bytes[4],
+            bConfigurationValue: bytes[5],
+            iConfiguration: bytes[6],
+            bmAttributes: bytes[7],
+            bMaxPower: bytes[8],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let total = self.wTotalLength.to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            total[0], total[1],
+            self.bNumInterfaces,
+            self.bConfigurationValue,
+            self.iConfiguration,
+            self.bmAttributes,
+            self.bMaxPower,
+        ]
+    }
+
+    /// Total length of data returned for this configuration.
+    pub const fn total_length(&self) -> u16 {
+        self.wTotalLength
+    }
+
+    /// Number of interfaces supported by this configuration.
+    pub const fn num_interfaces(&self) -> u8 {
+        self.bNumInterfaces
+    }
+
+    /// Value to use as an argument to `SET_CONFIGURATION`.
+    pub const fn configuration_value(&self) -> u8 {
+        self.bConfigurationValue
+    }
+
+    /// Index of string descriptor describing this configuration.
+    pub const fn string_index(&self) -> u8 {
+        self.iConfiguration
+    }
+
+    /// Raw configuration characteristics.
+    pub const fn attributes(&self) -> u8 {
+        self.bmAttributes
+    }
+
+    /// Returns `true` if the configuration is self powered.
+    pub const fn self_powered(&self) -> bool {
+        (self.bmAttributes & (1 << 6)) != 0
+    }
+
+    /// Returns `true` if the configuration supports remote wakeup.
+    pub const fn remote_wakeup(&self) -> bool {
+        (self.bmAttributes & (1 << 5)) != 0
+    }
+
+    /// Raw maximum power consumption.
+    /// Expressed in 2 mA units when operating at high speed or lower, and
+    /// in 8 mA units when operating at SuperSpeed or higher.
+    pub const fn max_power(&self) -> u8 {
+        self.bMaxPower
+    }
+}
+
+impl ToBytes for ConfigurationDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// A full configuration: the configuration descriptor followed by all its
+/// interface, endpoint and class specific descriptors.
+#[derive(Clone, Copy, Debug)]
+pub struct Configuration<'a> {
+    /// The configuration descriptor header.
+    descriptor: ConfigurationDescriptor,
+
+    /// Descriptors following the header, up to `wTotalLength`.
+    body: &'a [u8],
+}
+
+impl<'a> Configuration<'a> {
+    /// Parses a full configuration from the given buffer.
+    /// The buffer must contain at least `wTotalLength` bytes. Every
+    /// descriptor in the configuration is checked to be well formed.
+    pub fn parse(bytes: &'a [u8]) -> Result<Configuration<'a>, USBParseError> {
+        Self::parse_as(bytes, ConfigurationDescriptor::TYPE)
+    }
+
+    /// Parses a full configuration whose header has the given type.
+    fn parse_as(bytes: &'a [u8], kind: u8) -> Result<Configuration<'a>, USBParseError> {
+        let descriptor = ConfigurationDescriptor::parse_as(bytes, kind)?;
+
+        let total = descriptor.total_length() as usize;
+
+        if total < ConfigurationDescriptor::LENGTH as usize {
+            return Err( USBParseError::InvalidTotalLength(descriptor.total_length()) );
+        }
+
+        if bytes.len() < total {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let body = &bytes[ConfigurationDescriptor::LENGTH as usize..total];
+
+        let mut rest = body;
+
+        while !rest.is_empty() {
+            let (raw, tail) = RawDescriptor::split(rest)?;
+            tree::check(&raw)?;
+            rest = tail;
+        }
+
+        Ok( Configuration { descriptor, body } )
+    }
+
+    /// The configuration descriptor header.
+    pub const fn descriptor(&self) -> &ConfigurationDescriptor {
+        &self.descriptor
+    }
+
+    /// All descriptors following the configuration descriptor.
+    pub const fn descriptors(&self) -> Descriptors<'a> {
+        Descriptors::new(self.body)
+    }
+
+    /// Class specific descriptors placed before the first interface or
+    /// interface association.
+    pub fn class_specific(&self) -> Descriptors<'a> {
+        Descriptors::new( tree::head(self.body, tree::is_function_boundary) )
+    }
+
+    /// All interface association descriptors in the configuration.
+    pub const fn associations(&self) -> Associations<'a> {
+        Associations::new(self.body)
+    }
+
+    /// All interfaces and alternate settings in the configuration.
+    pub const fn interfaces(&self) -> Interfaces<'a> {
+        Interfaces::new(self.body)
+    }
+
+    /// Interfaces and alternate settings not claimed by any interface
+    /// association.
+    pub const fn unassociated_interfaces(&self) -> Interfaces<'a> {
+        Interfaces::unassociated(self.body)
+    }
+
+    /// Functions of the configuration, in descriptor order: every interface
+    /// association, and every interface not claimed by any association.
+    pub const fn functions(&self) -> Functions<'a> {
+        Functions::new(self.body)
+    }
+
+    /// Checks that no interface is claimed by two interface associations,
+    /// and that every association lies within `bNumInterfaces`.
+    pub fn validate_associations(&self) -> Result<(), USBParseError> {
+        let interfaces = self.descriptor.num_interfaces() as u16;
+
+        for (i, a) in self.associations().enumerate() {
+            if a.first_interface() as u16 + a.interface_count() as u16 > interfaces {
+                return Err( USBParseError::AssociationOutOfRange(a.first_interface()) );
+            }
+
+            for b in self.associations().skip(i + 1) {
+                let first = a.first_interface().max( b.first_interface() );
+
+                if a.contains(first) && b.contains(first) {
+                    return Err( USBParseError::InterfaceClaimedTwice(first) );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> ToBytes for Configuration<'a> {
+    fn size(&self) -> usize {
+        ConfigurationDescriptor::LENGTH as usize + self.body.len()
+    }
+
+    /// Writes the configuration with its total length and number of
+    /// interfaces recomputed from its descriptors.
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let mut writer = ConfigurationWriter::new(buffer, &self.descriptor)?;
+
+        writer.push(self.body)?;
+
+        writer.finish()
+    }
+}
+
+
+
+/// A full configuration as it would be at the other operating speed of a
+/// high speed capable device.
+/// It shares the layout of a configuration and only differs by the type of
+/// its header.
+#[derive(Clone, Copy, Debug)]
+pub struct OtherSpeedConfiguration<'a> {
+    /// The configuration at the other speed.
+    configuration: Configuration<'a>,
+}
+
+impl<'a> OtherSpeedConfiguration<'a> {
+    /// Descriptor type of the other speed configuration descriptor.
+    pub const TYPE: u8 = DescriptorType::OtherSpeedConfiguration.code();
+
+    /// Parses a full other speed configuration from the given buffer.
+    /// The buffer must contain at least `wTotalLength` bytes. Every
+    /// descriptor in the configuration is checked to be well formed.
+    pub fn parse(bytes: &'a [u8]) -> Result<OtherSpeedConfiguration<'a>, USBParseError> {
+        Ok( OtherSpeedConfiguration { configuration: Configuration::parse_as(bytes, Self::TYPE)? } )
+    }
+
+    /// The configuration tree at the other speed.
+    pub const fn configuration(&self) -> &Configuration<'a> {
+        &self.configuration
+    }
+}
+
+impl<'a> ToBytes for OtherSpeedConfiguration<'a> {
+    fn size(&self) -> usize {
+        self.configuration.size()
+    }
+
+    /// Writes the configuration with its total length and number of
+    /// interfaces recomputed from its descriptors.
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let mut writer = ConfigurationWriter::other_speed(buffer, &self.configuration.descriptor)?;
+
+        writer.push(self.configuration.body)?;
+
+        writer.finish()
+    }
+}
+
+
+
+/// Compile-time builder of a full configuration of `N` bytes.
+/// `wTotalLength` and `bNumInterfaces` are computed from the appended
+/// descriptors. Invalid descriptors make the builder panic, which fails
+/// compilation when evaluated in a `const` or `static` initializer.
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigurationBuilder<const N: usize> {
+    /// Configuration being built.
+    bytes: [u8; N],
+
+    /// Number of bytes written so far.
+    size: usize,
+
+    /// Number of interfaces appended so far.
+    interfaces: u8,
+}
+
+impl<const N: usize> ConfigurationBuilder<N> {
+    /// Starts a configuration with the given header.
+    /// Its total length and number of interfaces are ignored.
+    pub const fn new(descriptor: ConfigurationDescriptor) -> ConfigurationBuilder<N> {
+        ConfigurationBuilder { bytes: [0u8; N], size: 0, interfaces: 0 }
+            .append(&descriptor.to_array())
+    }
+
+    /// Starts an other speed configuration with the given header.
+    /// Its total length and number of interfaces are ignored.
+    pub const fn other_speed(descriptor: ConfigurationDescriptor) -> ConfigurationBuilder<N> {
+        let mut builder = Self::new(descriptor);
+        builder.bytes[1] = OtherSpeedConfiguration::TYPE;
+        builder
+    }
+
+    /// Appends an interface association descriptor.
+    /// Its class must be valid in an interface descriptor.
+    pub const fn association(self, descriptor: InterfaceAssociationDescriptor) -> Self {
+        assert!(descriptor.class().ifdesc(), "the class is not valid in an interface association descriptor");
+        assert!(descriptor.interface_count() != 0, "an interface association must group at least one interface");
+
+        self.append(&descriptor.to_array())
+    }
+
+    /// Appends an interface descriptor.
+    /// Its class must be valid in an interface descriptor.
+    pub const fn interface(mut self, descriptor: InterfaceDescriptor) -> Self {
+        assert!(descriptor.class().ifdesc(), "the class is not valid in an interface descriptor");
+
+        if descriptor.alternate_setting() == 0 {
+            self.interfaces += 1;
+        }
+
+        self.append(&descriptor.to_array())
+    }
+
+    /// Appends an endpoint descriptor, with its Audio 1.0 fields if any.
+    /// The default control endpoint cannot be described.
+    pub const fn endpoint(self, descriptor: EndpointDescriptor) -> Self {
+        assert!(descriptor.address().number() != 0, "endpoint 0 has no endpoint descriptor");
+
+        let bytes = descriptor.to_array();
+        let (bytes, _) = bytes.split_at(descriptor.length() as usize);
+
+        self.append(bytes)
+    }
+
+    /// Appends a class specific or any other descriptor.
+    pub const fn descriptor(self, bytes: &[u8]) -> Self {
+        assert!(bytes.len() >= 2 && bytes[0] as usize == bytes.len(), "the descriptor length does not match bLength");
+        assert!(bytes[1] != InterfaceDescriptor::TYPE, "interfaces must be appended with `interface`");
+
+        self.append(bytes)
+    }
+
+    /// Copies the given bytes at the end of the configuration.
+    const fn append(mut self, bytes: &[u8]) -> Self {
+        assert!(self.size + bytes.len() <= N, "the configuration does not fit in the array");
+
+        let end = self.size + bytes.len();
+        let (_, tail) = self.bytes.split_at_mut(self.size);
+        let (slot, _) = tail.split_at_mut(bytes.len());
+        slot.copy_from_slice(bytes);
+
+        self.size = end;
+        self
+    }
+
+    /// Encodes the configuration.
+    /// Panics if the descriptors do not fill the array exactly.
+    pub const fn build(mut self) -> [u8; N] {
+        assert!(self.size == N, "the configuration does not fill the array");
+        assert!(N <= u16::MAX as usize, "the configuration is longer than wTotalLength allows");
+
+        let total = (N as u16).to_le_bytes();
+
+        self.bytes[2] = total[0];
+        self.bytes[3] = total[1];
+        self.bytes[4] = self.interfaces;
+
+        self.bytes
+    }
+}
@@ -0,0 +1,376 @@
+//! USB Device descriptor.
+//! A structure representing the USB device descriptor.
+//! Documentation: Section 9.6.1 of the USB 3.0 specification.
+
+
+
+use crate::class::class::Class;
+use crate::error::*;
+use super::{ BCDVersion, DescriptorType, ToBytes, read_u16 };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeviceDescriptor {
+    /// USB Specification release number in binary-coded decimal.
+    bcdUSB: BCDVersion,
+
+    /// USB-IF class code, subclass code and protocol code.
+    /// Its type is enumerated in `Class`.
+    class: u8,
+    subclass: u8,
+    protocol: u8,
+
+    /// Maximum packet size for endpoint 0.
+    bMaxPacketSize0: MaxPacketSize0,
+
+    /// Vendor ID.
+    idVendor: u16,
+
+    /// Product ID.
+    idProduct: u16,
+
+    /// Device release number in binary coded decimal.
+    bcdDevice: BCDVersion,
+
+    /// Index of string descriptor describing manufacturer.
+    iManufacturer: u8,
+
+    /// Index of string descriptor describing product.
+    iProduct: u8,
+
+    /// Index of string descriptor containing device serial number.
+    iSerialNumber: u8,
+
+    /// number of possible configuration.
+    bNumConfigurations: u8,
+}
+
+impl DeviceDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 18;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::Device.code();
+
+    /// Parses a device descriptor from the start of the given buffer.
+    pub fn parse(bytes: &[u8]) -> Result<DeviceDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        let bcd_usb = BCDVersion::from_raw( read_u16(bytes, 2) );
+
+        Ok(DeviceDescriptor {
+            bcdUSB: bcd_usb,
+            class: bytes[4],
+            subclass: bytes[5],
+            protocol: bytes[6],
+            bMaxPacketSize0: MaxPacketSize0::parse(bytes[7], bcd_usb)?,
+            idVendor: read_u16(bytes, 8),
+            idProduct: read_u16(bytes, 10),
+            bcdDevice: BCDVersion::from_raw( read_u16(bytes, 12) ),
+            iManufacturer: bytes[14],
+            iProduct: bytes[15],
+            iSerialNumber: bytes[16],
+            bNumConfigurations: bytes[17],
+        })
+    }
+
+    /// Starts building a device descriptor at compile time.
+    pub const fn builder() -> DeviceDescriptorBuilder {
+        DeviceDescriptorBuilder::new()
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let usb = self.bcdUSB.raw().to_le_bytes();
+        let vendor = self.idVendor.to_le_bytes();
+        let product = self.idProduct.to_le_bytes();
+        let device = self.bcdDevice.raw().to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            usb[0], usb[1],
+            self.class, self.subclass, self.protocol,
+            self.bMaxPacketSize0.raw(),
+            vendor[0], vendor[1],
+            product[0], product[1],
+            device[0], device[1],
+            self.iManufacturer, self.iProduct, self.iSerialNumber,
+            self.bNumConfigurations,
+        ]
+    }
+
+    /// USB Specification release number.
+    pub const fn usb_version(&self) -> BCDVersion {
+        self.bcdUSB
+    }
+
+    /// Device class.
+    /// Unknown codes are kept as is; use `Class::try_from` on `class_code`
+    /// to reject them.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// Raw class, subclass and protocol codes.
+    pub const fn class_code(&self) -> (u8, u8, u8) {
+        (self.class, self.subclass, self.protocol)
+    }
+
+    /// Maximum packet size for endpoint 0.
+    pub const fn max_packet_size0(&self) -> MaxPacketSize0 {
+        self.bMaxPacketSize0
+    }
+
+    /// Vendor ID.
+    pub const fn vendor_id(&self) -> u16 {
+        self.idVendor
+    }
+
+    /// Product ID.
+    pub const fn product_id(&self) -> u16 {
+        self.idProduct
+    }
+
+    /// Device release number.
+    pub const fn device_version(&self) -> BCDVersion {
+        self.bcdDevice
+    }
+
+    /// Index of string descriptor describing manufacturer.
+    pub const fn manufacturer_index(&self) -> u8 {
+        self.iManufacturer
+    }
+
+    /// Index of string descriptor describing product.
+    pub const fn product_index(&self) -> u8 {
+        self.iProduct
+    }
+
+    /// Index of string descriptor containing device serial number.
+    pub const fn serial_number_index(&self) -> u8 {
+        self.iSerialNumber
+    }
+
+    /// Number of possible configurations.
+    pub const fn num_configurations(&self) -> u8 {
+        self.bNumConfigurations
+    }
+}
+
+impl ToBytes for DeviceDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// Compile-time builder of a device descriptor.
+/// Invalid combinations make `build` panic, which fails compilation when
+/// evaluated in a `const` or `static` initializer.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceDescriptorBuilder {
+    /// Descriptor being built.
+    descriptor: DeviceDescriptor,
+
+    /// Device class.
+    class: Class,
+}
+
+impl DeviceDescriptorBuilder {
+    /// Starts a USB 2.0 device with class `Device`, a 64 byte control
+    /// endpoint, no strings and one configuration.
+    pub const fn new() -> DeviceDescriptorBuilder {
+        DeviceDescriptorBuilder {
+            descriptor: DeviceDescriptor {
+                bcdUSB: BCDVersion::USB20,
+                class: 0x00,
+                subclass: 0x00,
+                protocol: 0x00,
+                bMaxPacketSize0: MaxPacketSize0::Bytes64,
+                idVendor: 0x0000,
+                idProduct: 0x0000,
+                bcdDevice: BCDVersion::from_raw(0x0000),
+                iManufacturer: 0,
+                iProduct: 0,
+                iSerialNumber: 0,
+                bNumConfigurations: 1,
+            },
+            class: Class::Device,
+        }
+    }
+
+    /// USB Specification release number.
+    pub const fn usb_version(mut self, version: BCDVersion) -> Self {
+        self.descriptor.bcdUSB = version;
+        self
+    }
+
+    /// Device class. It must be valid in a device descriptor.
+    pub const fn class(mut self, class: Class) -> Self {
+        let (class_code, subclass, protocol) = class.code();
+
+        self.descriptor.class = class_code;
+        self.descriptor.subclass = subclass;
+        self.descriptor.protocol = protocol;
+        self.class = class;
+        self
+    }
+
+    /// Maximum packet size for endpoint 0.
+    pub const fn max_packet_size0(mut self, size: MaxPacketSize0) -> Self {
+        self.descriptor.bMaxPacketSize0 = size;
+        self
+    }
+
+    /// Vendor ID.
+    pub const fn vendor(mut self, id: u16) -> Self {
+        self.descriptor.idVendor = id;
+        self
+    }
+
+    /// Product ID.
+    pub const fn product(mut self, id: u16) -> Self {
+        self.descriptor.idProduct = id;
+        self
+    }
+
+    /// Device release number.
+    pub const fn device_version(mut self, version: BCDVersion) -> Self {
+        self.descriptor.bcdDevice = version;
+        self
+    }
+
+    /// Index of string descriptor describing manufacturer.
+    pub const fn manufacturer_index(mut self, index: u8) -> Self {
+        self.descriptor.iManufacturer = index;
+        self
+    }
+
+    /// Index of string descriptor describing product.
+    pub const fn product_index(mut self, index: u8) -> Self {
+        self.descriptor.iProduct = index;
+        self
+    }
+
+    /// Index of string descriptor containing device serial number.
+    pub const fn serial_number_index(mut self, index: u8) -> Self {
+        self.descriptor.iSerialNumber = index;
+        self
+    }
+
+    /// Number of possible configurations.
+    pub const fn num_configurations(mut self, count: u8) -> Self {
+        self.descriptor.bNumConfigurations = count;
+        self
+    }
+
+    /// Returns the device descriptor.
+    /// Panics if the descriptor is invalid.
+    pub const fn descriptor(&self) -> DeviceDescriptor {
+        assert!(self.class.devdesc(), "the class is not valid in a device descriptor");
+
+        let superspeed = self.descriptor.bcdUSB.major() >= 3;
+        let bytes512 = matches!(self.descriptor.bMaxPacketSize0, MaxPacketSize0::Bytes512);
+
+        assert!(!superspeed || bytes512, "USB 3.x devices require a 512 byte control endpoint");
+        assert!(superspeed || !bytes512, "a 512 byte control endpoint requires USB 3.x");
+        assert!(self.descriptor.bNumConfigurations != 0, "a device requires at least one configuration");
+
+        self.descriptor
+    }
+
+    /// Encodes the device descriptor.
+    /// Panics if the descriptor is invalid.
+    pub const fn build(&self) -> [u8; DeviceDescriptor::LENGTH as usize] {
+        self.descriptor().to_array()
+    }
+}
+
+impl Default for DeviceDescriptorBuilder {
+    fn default() -> DeviceDescriptorBuilder {
+        DeviceDescriptorBuilder::new()
+    }
+}
+
+
+
+/// Maximum packet size of the default control endpoint.
+/// USB 2.0 devices may use 8, 16, 32 or 64 bytes. USB 3.x devices encode a
+/// 512 byte packet size as the exponent 9.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaxPacketSize0 {
+    /// 8 bytes.
+    Bytes8,
+
+    /// 16 bytes.
+    Bytes16,
+
+    /// 32 bytes.
+    Bytes32,
+
+    /// 64 bytes.
+    Bytes64,
+
+    /// 512 bytes (SuperSpeed and above).
+    Bytes512,
+}
+
+impl MaxPacketSize0 {
+    /// Validates the raw `bMaxPacketSize0` field against the USB version.
+    pub fn parse(byte: u8, usb: BCDVersion) -> Result<MaxPacketSize0, USBParseError> {
+        if usb.major() >= 3 {
+            return match byte {
+                0x09 => Ok( MaxPacketSize0::Bytes512 ),
+                _ => Err( USBParseError::InvalidMaxPacketSize(byte) ),
+            };
+        }
+
+        match byte {
+            8  => Ok( MaxPacketSize0::Bytes8 ),
+            16 => Ok( MaxPacketSize0::Bytes16 ),
+            32 => Ok( MaxPacketSize0::Bytes32 ),
+            64 => Ok( MaxPacketSize0::Bytes64 ),
+
+            _ => Err( USBParseError::InvalidMaxPacketSize(byte) ),
+        }
+    }
+
+    /// Returns the packet size in bytes.
+    pub const fn size(&self) -> u16 {
+        match *self {
+            MaxPacketSize0::Bytes8   => 8,
+            MaxPacketSize0::Bytes16  => 16,
+            MaxPacketSize0::Bytes32  => 32,
+            MaxPacketSize0::Bytes64  => 64,
+            MaxPacketSize0::Bytes512 => 512,
+        }
+    }
+
+    /// Returns the raw `bMaxPacketSize0` encoding.
+    pub const fn raw(&self) -> u8 {
+        match *self {
+            MaxPacketSize0::Bytes8   => 8,
+            MaxPacketSize0::Bytes16  => 16,
+            MaxPacketSize0::Bytes32  => 32,
+            MaxPacketSize0::Bytes64  => 64,
+            MaxPacketSize0::Bytes512 => 0x09,
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! USB Endpoint descriptor.
+//! A structure representing the USB endpoint descriptor.
+//! Documentation: Section 9.6.6 of the USB 3.0 specification.
+
+
+
+use crate::error::*;
+use super::{ DescriptorType, ToBytes, read_u16 };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EndpointDescriptor {
+    /// Size of the descriptor, 7 or 9 for Audio 1.0 endpoints.
+    bLength: u8,
+
+    /// Address of the endpoint.
+    bEndpointAddress: EndpointAddress,
+
+    /// Endpoint attributes.
+    bmAttributes: u8,
+
+    /// Maximum packet size and additional transactions per microframe.
+    wMaxPacketSize: u16,
+
+    /// Interval for servicing the endpoint.
+    bInterval: u8,
+
+    /// Rate of the feedback of an Audio 1.0 synchronization endpoint.
+    bRefresh: u8,
+
+    /// Address of the synchronization endpoint of an Audio 1.0 endpoint.
+    bSynchAddress: u8,
+}
+
+impl EndpointDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 7;
+
+    /// Size of an Audio 1.0 endpoint descriptor in bytes, which appends
+    /// `bRefresh` and `bSynchAddress` to the standard descriptor.
+    pub const AUDIO_LENGTH: u8 = 9;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::Endpoint.code();
+
+    /// Creates an endpoint descriptor.
+    /// The raw `wMaxPacketSize` holds the packet size in bits 0..10 and the
+    /// additional transactions per microframe in bits 11..12.
+    pub const fn new(address: EndpointAddress, attributes: u8, max_packet_size: u16, interval: u8) -> EndpointDescriptor {
+        EndpointDescriptor {
+            bLength: Self::LENGTH,
+            bEndpointAddress: address,
+            bmAttributes: attributes,
+            wMaxPacketSize: max_packet_size,
+            bInterval: interval,
+            bRefresh: 0,
+            bSynchAddress: 0,
+        }
+    }
+
+    /// Turns the descriptor into an Audio 1.0 endpoint descriptor with the
+    /// given feedback rate and synchronization endpoint address.
+    pub const fn audio(mut self, refresh: u8, synch_address: u8) -> EndpointDescriptor {
+        self.bLength = Self::AUDIO_LENGTH;
+        self.bRefresh = refresh;
+        self.bSynchAddress = synch_address;
+        self
+    }
+
+    /// Parses an endpoint descriptor from the start of the given buffer.
+    /// Only standard and Audio 1.0 endpoint descriptors are accepted.
+    pub fn parse(bytes: &[u8]) -> Result<EndpointDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if (bytes[0] != Self::LENGTH) && (bytes[0] != Self::AUDIO_LENGTH) {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes.len() < bytes[0] as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        Ok( Self::from_bytes(bytes) )
+    }
+
+    /// Decodes an endpoint descriptor that has already been validated.
+    /// The Audio 1.0 fields are kept if the descriptor is an Audio 1.0
+    /// endpoint descriptor.
+    pub(crate) const fn from_bytes(bytes: &[u8]) -> EndpointDescriptor {
+        let descriptor = EndpointDescriptor::new(EndpointAddress::from_raw(bytes[2]), bytes[3], read_u16(bytes, 4), bytes[6]);
+
+        match bytes[0] == Self::AUDIO_LENGTH {
+            true => descriptor.audio(bytes[7], bytes[8]),
+            _ => descriptor,
+        }
+    }
+
+    /// Encodes the descriptor.
+    /// Only the first `length()` bytes belong to the descriptor, the Audio
+    /// 1.0 fields of a standard endpoint are left zeroed.
+    pub const fn to_array(&self) -> [u8; Self::AUDIO_LENGTH as usize] {
+        let size = self.wMaxPacketSize.to_le_bytes();
+
+        [
+            self.bLength, Self::TYPE,
+            self.bEndpointAddress.raw(),
+            self.bmAttributes,
+            size[0], size[1],
+            self.bInterval,
+            self.bRefresh,
+            self.bSynchAddress,
+        ]
+    }
+
+    /// Size of the descriptor in bytes.
+    pub const fn length(&self) -> u8 {
+        self.bLength
+    }
+
+    /// Returns `true` if this is an Audio 1.0 endpoint descriptor.
+    pub const fn is_audio(&self) -> bool {
+        self.bLength == Self::AUDIO_LENGTH
+    }
+
+    /// Feedback rate of an Audio 1.0 synchronization endpoint.
+    pub const fn refresh(&self) -> u8 {
+        self.bRefresh
+    }
+
+    /// Address of the synchronization endpoint of an Audio 1.0 endpoint.
+    pub const fn synch_address(&self) -> u8 {
+        self.bSynchAddress
+    }
+
+    /// Address of the endpoint.
+    pub const fn address(&self) -> EndpointAddress {
+        self.bEndpointAddress
+    }
+
+    /// Raw endpoint attributes.
+    pub const fn attributes(&self) -> u8 {
+        self.bmAttributes
+    }
+
+    /// Transfer type of the endpoint.
+    pub const fn transfer_type(&self) -> TransferType {
+        match self.bmAttributes & 0x03 {
+            0 => TransferType::Control,
+            1 => TransferType::Isochronous,
+            2 => TransferType::Bulk,
+            _ => TransferType::Interrupt,
+        }
+    }
+
+    /// Synchronization type of an isochronous endpoint.
+    pub const fn sync_type(&self) -> SyncType {
+        match (self.bmAttributes >> 2) & 0x03 {
+            0 => SyncType::NoSync,
+            1 => SyncType::Asynchronous,
+            2 => SyncType::Adaptive,
+            _ => SyncType::Synchronous,
+        }
+    }
+
+    /// Usage type of an isochronous or interrupt endpoint.
+    pub const fn usage_type(&self) -> UsageType {
+        match (self.bmAttributes >> 4) & 0x03 {
+            0 => UsageType::Data,
+            1 => UsageType::Feedback,
+            2 => UsageType::ImplicitFeedback,
+            _ => UsageType::Reserved,
+        }
+    }
+
+    /// Raw `wMaxPacketSize` field.
+    pub const fn raw_max_packet_size(&self) -> u16 {
+        self.wMaxPacketSize
+    }
+
+    /// Maximum packet size in bytes.
+    pub const fn max_packet_size(&self) -> u16 {
+        self.wMaxPacketSize & 0x07FF
+    }
+
+    /// Additional transactions per microframe of a high speed, high
+    /// bandwidth isochronous or interrupt endpoint (0 to 2).
+    pub const fn additional_transactions(&self) -> u8 {
+        ((self.wMaxPacketSize >> 11) & 0x03) as u8
+    }
+
+    /// Raw `bInterval` field.
+    pub const fn interval(&self) -> u8 {
+        self.bInterval
+    }
+
+    /// Service interval of a periodic endpoint in microseconds.
+    /// Full and low speed interrupt endpoints are polled every `bInterval`
+    /// frames. Every other periodic endpoint is polled every
+    /// `2^(bInterval-1)` frames (full speed) or microframes (high speed and
+    /// above). Returns `None` for control and bulk endpoints and for out of
+    /// range values.
+    pub const fn interval_duration(&self, speed: Speed) -> Option<u32> {
+        let interval = self.bInterval as u32;
+
+        let periodic = matches!(self.transfer_type(), TransferType::Isochronous | TransferType::Interrupt);
+
+        if !periodic || interval == 0 {
+            return None;
+        }
+
+        match (speed, self.transfer_type()) {
+            (Speed::Low, TransferType::Interrupt) | (Speed::Full, TransferType::Interrupt) => Some(interval * 1000),
+
+            (Speed::Low, _) => None,
+
+            _ if interval > 16 => None,
+
+            (Speed::Full, _) => Some((1 << (interval - 1)) * 1000),
+
+            _ => Some((1 << (interval - 1)) * 125),
+        }
+    }
+}
+
+impl ToBytes for EndpointDescriptor {
+    fn size(&self) -> usize {
+        self.bLength as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..self.size()].to_bytes(buffer)
+    }
+}
+
+
+
+/// Endpoint number and direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EndpointAddress(u8);
+
+impl EndpointAddress {
+    /// Creates an endpoint address from its raw encoding.
+    pub const fn from_raw(raw: u8) -> EndpointAddress {
+        EndpointAddress(raw)
+    }
+
+    /// Creates an endpoint address from its number and direction.
+    pub const fn new(number: u8, direction: Direction) -> EndpointAddress {
+        match direction {
+            Direction::Out => EndpointAddress(number & 0x0F),
+            Direction::In => EndpointAddress((number & 0x0F) | 0x80),
+        }
+    }
+
+    /// Returns the raw `bEndpointAddress` encoding.
+    pub const fn raw(&self) -> u8 {
+        self.0
+    }
+
+    /// Endpoint number.
+    pub const fn number(&self) -> u8 {
+        self.0 & 0x0F
+    }
+
+    /// Endpoint direction.
+    pub const fn direction(&self) -> Direction {
+        match self.0 & 0x80 {
+            0 => Direction::Out,
+            _ => Direction::In,
+        }
+    }
+}
+
+
+
+/// Direction of an endpoint, relative to the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Host to device.
+    Out,
+
+    /// Device to host.
+    In,
+}
+
+
+
+/// Transfer type of an endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransferType {
+    /// Control endpoint.
+    Control,
+
+    /// Isochronous endpoint.
+    Isochronous,
+
+    /// Bulk endpoint.
+    Bulk,
+
+    /// Interrupt endpoint.
+    Interrupt,
+}
+
+
+
+/// Synchronization type of an isochronous endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SyncType {
+    /// No synchronization.
+    NoSync,
+
+    /// Asynchronous.
+    Asynchronous,
+
+    /// Adaptive.
+    Adaptive,
+
+    /// Synchronous.
+    Synchronous,
+}
+
+
+
+/// Usage type of an endpoint.
+/// Interrupt endpoints of SuperSpeed devices use `Data` for periodic and
+/// `Feedback` for notification usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UsageType {
+    /// Data endpoint (periodic for interrupt endpoints).
+    Data,
+
+    /// Feedback endpoint (notification for interrupt endpoints).
+    Feedback,
+
+    /// Implicit feedback data endpoint.
+    ImplicitFeedback,
+
+    /// Reserved.
+    Reserved,
+}
+
+
+
+/// Operating speed of a device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Speed {
+    /// Low speed (1.5 Mb/s).
+    Low,
+
+    /// Full speed (12 Mb/s).
+    Full,
+
+    /// High speed (480 Mb/s).
+    High,
+
+    /// SuperSpeed (5 Gb/s).
+    Super,
+
+    /// SuperSpeedPlus (10 Gb/s and above).
+    SuperPlus,
+}
@@ -0,0 +1,129 @@
+//! USB Interface descriptor.
+//! A structure representing the USB interface descriptor.
+//! Documentation: Section 9.6.5 of the USB 3.0 specification.
+
+
+
+use crate::class::class::Class;
+use crate::error::*;
+use super::{ DescriptorType, ToBytes };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterfaceDescriptor {
+    /// Number of this interface.
+    bInterfaceNumber: u8,
+
+    /// Value used to select this alternate setting.
+    bAlternateSetting: u8,
+
+    /// Number of endpoints used by this interface, excluding endpoint 0.
+    bNumEndpoints: u8,
+
+    /// USB-IF class code, subclass code and protocol code.
+    class: Class,
+
+    /// Index of string descriptor describing this interface.
+    iInterface: u8,
+}
+
+impl InterfaceDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 9;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::Interface.code();
+
+    /// Creates an interface descriptor.
+    pub const fn new(number: u8, alternate_setting: u8, num_endpoints: u8, class: Class, string_index: u8) -> InterfaceDescriptor {
+        InterfaceDescriptor {
+            bInterfaceNumber: number,
+            bAlternateSetting: alternate_setting,
+            bNumEndpoints: num_endpoints,
+            class,
+            iInterface: string_index,
+        }
+    }
+
+    /// Parses an interface descriptor from the start of the given buffer.
+    /// Unknown class codes are kept as is, but classes that are only valid
+    /// in a device descriptor are rejected.
+    pub fn parse(bytes: &[u8]) -> Result<InterfaceDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        let class = Class::decode( (bytes[5], bytes[6], bytes[7]) );
+
+        if !class.ifdesc() {
+            return Err( USBParseError::InvalidClassLevel(bytes[5]) );
+        }
+
+        Ok(InterfaceDescriptor {
+            bInterfaceNumber: bytes[2],
+            bAlternateSetting: bytes[3],
+            bNumEndpoints: bytes[4],
+            class,
+            iInterface: bytes[8],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let (class, subclass, protocol) = self.class.code();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            self.bInterfaceNumber,
+            self.bAlternateSetting,
+            self.bNumEndpoints,
+            class, subclass, protocol,
+            self.iInterface,
+        ]
+    }
+
+    /// Number of this interface.
+    pub const fn number(&self) -> u8 {
+        self.bInterfaceNumber
+    }
+
+    /// Value used to select this alternate setting.
+    pub const fn alternate_setting(&self) -> u8 {
+        self.bAlternateSetting
+    }
+
+    /// Number of endpoints used by this interface, excluding endpoint 0.
+    pub const fn num_endpoints(&self) -> u8 {
+        self.bNumEndpoints
+    }
+
+    /// Interface class.
+    pub const fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Index of string descriptor describing this interface.
+    pub const fn string_index(&self) -> u8 {
+        self.iInterface
+    }
+}
+
+impl ToBytes for InterfaceDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
@@ -0,0 +1,943 @@
+//! USB Language identifiers.
+//! Languages listed in the USB-IF LANGID table, used by string descriptors.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageId {
+    /// Afrikaans.
+    Afrikaans,
+
+    /// Albanian.
+    Albanian,
+
+    /// Arabic (Saudi Arabia).
+    ArabicSaudiArabia,
+
+    /// Arabic (Iraq).
+    ArabicIraq,
+
+    /// Arabic (Egypt).
+    ArabicEgypt,
+
+    /// Arabic (Libya).
+    ArabicLibya,
+
+    /// Arabic (Algeria).
+    ArabicAlgeria,
+
+    /// Arabic (Morocco).
+    ArabicMorocco,
+
+    /// Arabic (Tunisia).
+    ArabicTunisia,
+
+    /// Arabic (Oman).
+    ArabicOman,
+
+    /// Arabic (Yemen).
+    ArabicYemen,
+
+    /// Arabic (Syria).
+    ArabicSyria,
+
+    /// Arabic (Jordan).
+    ArabicJordan,
+
+    /// Arabic (Lebanon).
+    ArabicLebanon,
+
+    /// Arabic (Kuwait).
+    ArabicKuwait,
+
+    /// Arabic (U.A.E.).
+    ArabicUAE,
+
+    /// Arabic (Bahrain).
+    ArabicBahrain,
+
+    /// Arabic (Qatar).
+    ArabicQatar,
+
+    /// Armenian.
+    Armenian,
+
+    /// Assamese.
+    Assamese,
+
+    /// Azeri (Latin).
+    AzeriLatin,
+
+    /// Azeri (Cyrillic).
+    AzeriCyrillic,
+
+    /// Basque.
+    Basque,
+
+    /// Belarussian.
+    Belarussian,
+
+    /// Bengali.
+    Bengali,
+
+    /// Bulgarian.
+    Bulgarian,
+
+    /// Burmese.
+    Burmese,
+
+    /// Catalan.
+    Catalan,
+
+    /// Chinese (Taiwan).
+    ChineseTaiwan,
+
+    /// Chinese (PRC).
+    ChinesePRC,
+
+    /// Chinese (Hong Kong SAR, PRC).
+    ChineseHongKong,
+
+    /// Chinese (Singapore).
+    ChineseSingapore,
+
+    /// Chinese (Macau SAR).
+    ChineseMacau,
+
+    /// Croatian.
+    Croatian,
+
+    /// Czech.
+    Czech,
+
+    /// Danish.
+    Danish,
+
+    /// Dutch (Netherlands).
+    DutchNetherlands,
+
+    /// Dutch (Belgium).
+    DutchBelgium,
+
+    /// English (United States).
+    EnglishUnitedStates,
+
+    /// English (United Kingdom).
+    EnglishUnitedKingdom,
+
+    /// English (Australian).
+    EnglishAustralian,
+
+    /// English (Canadian).
+    EnglishCanadian,
+
+    /// English (New Zealand).
+    EnglishNewZealand,
+
+    /// English (Ireland).
+    EnglishIreland,
+
+    /// English (South Africa).
+    EnglishSouthAfrica,
+
+    /// English (Jamaica).
+    EnglishJamaica,
+
+    /// English (Caribbean).
+    EnglishCaribbean,
+
+    /// English (Belize).
+    EnglishBelize,
+
+    /// English (Trinidad).
+    EnglishTrinidad,
+
+    /// English (Zimbabwe).
+    EnglishZimbabwe,
+
+    /// English (Philippines).
+    EnglishPhilippines,
+
+    /// Estonian.
+    Estonian,
+
+    /// Faeroese.
+    Faeroese,
+
+    /// Farsi.
+    Farsi,
+
+    /// Finnish.
+    Finnish,
+
+    /// French (Standard).
+    FrenchStandard,
+
+    /// French (Belgian).
+    FrenchBelgian,
+
+    /// French (Canadian).
+    FrenchCanadian,
+
+    /// French (Switzerland).
+    FrenchSwitzerland,
+
+    /// French (Luxembourg).
+    FrenchLuxembourg,
+
+    /// French (Monaco).
+    FrenchMonaco,
+
+    /// Georgian.
+    Georgian,
+
+    /// German (Standard).
+    GermanStandard,
+
+    /// German (Switzerland).
+    GermanSwitzerland,
+
+    /// German (Austria).
+    GermanAustria,
+
+    /// German (Luxembourg).
+    GermanLuxembourg,
+
+    /// German (Liechtenstein).
+    GermanLiechtenstein,
+
+    /// Greek.
+    Greek,
+
+    /// Gujarati.
+    Gujarati,
+
+    /// Hebrew.
+    Hebrew,
+
+    /// Hindi.
+    Hindi,
+
+    /// Hungarian.
+    Hungarian,
+
+    /// Icelandic.
+    Icelandic,
+
+    /// Indonesian.
+    Indonesian,
+
+    /// Italian (Standard).
+    ItalianStandard,
+
+    /// Italian (Switzerland).
+    ItalianSwitzerland,
+
+    /// Japanese.
+    Japanese,
+
+    /// Kannada.
+    Kannada,
+
+    /// Kashmiri (India).
+    KashmiriIndia,
+
+    /// Kazakh.
+    Kazakh,
+
+    /// Konkani.
+    Konkani,
+
+    /// Korean.
+    Korean,
+
+    /// Korean (Johab).
+    KoreanJohab,
+
+    /// Latvian.
+    Latvian,
+
+    /// Lithuanian.
+    Lithuanian,
+
+    /// Lithuanian (Classic).
+    LithuanianClassic,
+
+    /// Macedonian.
+    Macedonian,
+
+    /// Malay (Malaysian).
+    MalayMalaysian,
+
+    /// Malay (Brunei Darussalam).
+    MalayBrunei,
+
+    /// Malayalam.
+    Malayalam,
+
+    /// Manipuri.
+    Manipuri,
+
+    /// Marathi.
+    Marathi,
+
+    /// Nepali (India).
+    NepaliIndia,
+
+    /// Norwegian (Bokmal).
+    NorwegianBokmal,
+
+    /// Norwegian (Nynorsk).
+    NorwegianNynorsk,
+
+    /// Oriya.
+    Oriya,
+
+    /// Polish.
+    Polish,
+
+    /// Portuguese (Brazil).
+    PortugueseBrazil,
+
+    /// Portuguese (Standard).
+    PortugueseStandard,
+
+    /// Punjabi.
+    Punjabi,
+
+    /// Romanian.
+    Romanian,
+
+    /// Russian.
+    Russian,
+
+    /// Sanskrit.
+    Sanskrit,
+
+    /// Serbian (Cyrillic).
+    SerbianCyrillic,
+
+    /// Serbian (Latin).
+    SerbianLatin,
+
+    /// Sindhi.
+    Sindhi,
+
+    /// Slovak.
+    Slovak,
+
+    /// Slovenian.
+    Slovenian,
+
+    /// Spanish (Traditional Sort).
+    SpanishTraditional,
+
+    /// Spanish (Mexican).
+    SpanishMexican,
+
+    /// Spanish (Modern Sort).
+    SpanishModern,
+
+    /// Spanish (Guatemala).
+    SpanishGuatemala,
+
+    /// Spanish (Costa Rica).
+    SpanishCostaRica,
+
+    /// Spanish (Panama).
+    SpanishPanama,
+
+    /// Spanish (Dominican Republic).
+    SpanishDominicanRepublic,
+
+    /// Spanish (Venezuela).
+    SpanishVenezuela,
+
+    /// Spanish (Colombia).
+    SpanishColombia,
+
+    /// Spanish (Peru).
+    SpanishPeru,
+
+    /// Spanish (Argentina).
+    SpanishArgentina,
+
+    /// Spanish (Ecuador).
+    SpanishEcuador,
+
+    /// Spanish (Chile).
+    SpanishChile,
+
+    /// Spanish (Uruguay).
+    SpanishUruguay,
+
+    /// Spanish (Paraguay).
+    SpanishParaguay,
+
+    /// Spanish (Bolivia).
+    SpanishBolivia,
+
+    /// Spanish (El Salvador).
+    SpanishElSalvador,
+
+    /// Spanish (Honduras).
+    SpanishHonduras,
+
+    /// Spanish (Nicaragua).
+    SpanishNicaragua,
+
+    /// Spanish (Puerto Rico).
+    SpanishPuertoRico,
+
+    /// Sutu.
+    Sutu,
+
+    /// Swahili (Kenya).
+    SwahiliKenya,
+
+    /// Swedish.
+    Swedish,
+
+    /// Swedish (Finland).
+    SwedishFinland,
+
+    /// Tamil.
+    Tamil,
+
+    /// Tatar (Tatarstan).
+    TatarTatarstan,
+
+    /// Telugu.
+    Telugu,
+
+    /// Thai.
+    Thai,
+
+    /// Turkish.
+    Turkish,
+
+    /// Ukrainian.
+    Ukrainian,
+
+    /// Urdu (Pakistan).
+    UrduPakistan,
+
+    /// Urdu (India).
+    UrduIndia,
+
+    /// Uzbek (Latin).
+    UzbekLatin,
+
+    /// Uzbek (Cyrillic).
+    UzbekCyrillic,
+
+    /// Vietnamese.
+    Vietnamese,
+
+    /// HID (Usage Data Descriptor).
+    HIDUsageData,
+
+    /// HID (Vendor Defined 1).
+    HIDVendor1,
+
+    /// HID (Vendor Defined 2).
+    HIDVendor2,
+
+    /// HID (Vendor Defined 3).
+    HIDVendor3,
+
+    /// HID (Vendor Defined 4).
+    HIDVendor4,
+
+    /// Language not listed in the USB-IF table, kept as is.
+    Other(u16),
+}
+
+impl LanguageId {
+    /// Decodes a LANGID without failing.
+    /// Unknown identifiers are kept in `LanguageId::Other`.
+    pub fn decode(code: u16) -> LanguageId {
+        LanguageId::try_from(code).unwrap_or(LanguageId::Other(code))
+    }
+
+    /// Raw LANGID code.
+    pub const fn code(&self) -> u16 {
+        match *self {
+            LanguageId::Afrikaans                => 0x0436,
+            LanguageId::Albanian                 => 0x041C,
+            LanguageId::ArabicSaudiArabia        => 0x0401,
+            LanguageId::ArabicIraq               => 0x0801,
+            LanguageId::ArabicEgypt              => 0x0C01,
+            LanguageId::ArabicLibya              => 0x1001,
+            LanguageId::ArabicAlgeria            => 0x1401,
+            LanguageId::ArabicMorocco            => 0x1801,
+            LanguageId::ArabicTunisia            => 0x1C01,
+            LanguageId::ArabicOman               => 0x2001,
+            LanguageId::ArabicYemen              => 0x2401,
+            LanguageId::ArabicSyria              => 0x2801,
+            LanguageId::ArabicJordan             => 0x2C01,
+            LanguageId::ArabicLebanon            => 0x3001,
+            LanguageId::ArabicKuwait             => 0x3401,
+            LanguageId::ArabicUAE                => 0x3801,
+            LanguageId::ArabicBahrain            => 0x3C01,
+            LanguageId::ArabicQatar              => 0x4001,
+            LanguageId::Armenian                 => 0x042B,
+            LanguageId::Assamese                 => 0x044D,
+            LanguageId::AzeriLatin               => 0x042C,
+            LanguageId::AzeriCyrillic            => 0x082C,
+            LanguageId::Basque                   => 0x042D,
+            LanguageId::Belarussian              => 0x0423,
+            LanguageId::Bengali                  => 0x0445,
+            LanguageId::Bulgarian                => 0x0402,
+            LanguageId::Burmese                  => 0x0455,
+            LanguageId::Catalan                  => 0x0403,
+            LanguageId::ChineseTaiwan            => 0x0404,
+            LanguageId::ChinesePRC               => 0x0804,
+            LanguageId::ChineseHongKong          => 0x0C04,
+            LanguageId::ChineseSingapore         => 0x1004,
+            LanguageId::ChineseMacau             => 0x1404,
+            LanguageId::Croatian                 => 0x041A,
+            LanguageId::Czech                    => 0x0405,
+            LanguageId::Danish                   => 0x0406,
+            LanguageId::DutchNetherlands         => 0x0413,
+            LanguageId::DutchBelgium             => 0x0813,
+            LanguageId::EnglishUnitedStates      => 0x0409,
+            LanguageId::EnglishUnitedKingdom     => 0x0809,
+            LanguageId::EnglishAustralian        => 0x0C09,
+            LanguageId::EnglishCanadian          => 0x1009,
+            LanguageId::EnglishNewZealand        => 0x1409,
+            LanguageId::EnglishIreland           => 0x1809,
+            LanguageId::EnglishSouthAfrica       => 0x1C09,
+            LanguageId::EnglishJamaica           => 0x2009,
+            LanguageId::EnglishCaribbean         => 0x2409,
+            LanguageId::EnglishBelize            => 0x2809,
+            LanguageId::EnglishTrinidad          => 0x2C09,
+            LanguageId::EnglishZimbabwe          => 0x3009,
+            LanguageId::EnglishPhilippines       => 0x3409,
+            LanguageId::Estonian                 => 0x0425,
+            LanguageId::Faeroese                 => 0x0438,
+            LanguageId::Farsi                    => 0x0429,
+            LanguageId::Finnish                  => 0x040B,
+            LanguageId::FrenchStandard           => 0x040C,
+            LanguageId::FrenchBelgian            => 0x080C,
+            LanguageId::FrenchCanadian           => 0x0C0C,
+            LanguageId::FrenchSwitzerland        => 0x100C,
+            LanguageId::FrenchLuxembourg         => 0x140C,
+            LanguageId::FrenchMonaco             => 0x180C,
+            LanguageId::Georgian                 => 0x0437,
+            LanguageId::GermanStandard           => 0x0407,
+            LanguageId::GermanSwitzerland        => 0x0807,
+            LanguageId::GermanAustria            => 0x0C07,
+            LanguageId::GermanLuxembourg         => 0x1007,
+            LanguageId::GermanLiechtenstein      => 0x1407,
+            LanguageId::Greek                    => 0x0408,
+            LanguageId::Gujarati                 => 0x0447,
+            LanguageId::Hebrew                   => 0x040D,
+            LanguageId::Hindi                    => 0x0439,
+            LanguageId::Hungarian                => 0x040E,
+            LanguageId::Icelandic                => 0x040F,
+            LanguageId::Indonesian               => 0x0421,
+            LanguageId::ItalianStandard          => 0x0410,
+            LanguageId::ItalianSwitzerland       => 0x0810,
+            LanguageId::Japanese                 => 0x0411,
+            LanguageId::Kannada                  => 0x044B,
+            LanguageId::KashmiriIndia            => 0x0860,
+            LanguageId::Kazakh                   => 0x043F,
+            LanguageId::Konkani                  => 0x0457,
+            LanguageId::Korean                   => 0x0412,
+            LanguageId::KoreanJohab              => 0x0812,
+            LanguageId::Latvian                  => 0x0426,
+            LanguageId::Lithuanian               => 0x0427,
+            LanguageId::LithuanianClassic        => 0x0827,
+            LanguageId::Macedonian               => 0x042F,
+            LanguageId::MalayMalaysian           => 0x043E,
+            LanguageId::MalayBrunei              => 0x083E,
+            LanguageId::Malayalam                => 0x044C,
+            LanguageId::Manipuri                 => 0x0458,
+            LanguageId::Marathi                  => 0x044E,
+            LanguageId::NepaliIndia              => 0x0861,
+            LanguageId::NorwegianBokmal          => 0x0414,
+            LanguageId::NorwegianNynorsk         => 0x0814,
+            LanguageId::Oriya                    => 0x0448,
+            LanguageId::Polish                   => 0x0415,
+            LanguageId::PortugueseBrazil         => 0x0416,
+            LanguageId::PortugueseStandard       => 0x0816,
+            LanguageId::Punjabi                  => 0x0446,
+            LanguageId::Romanian                 => 0x0418,
+            LanguageId::Russian                  => 0x0419,
+            LanguageId::Sanskrit                 => 0x044F,
+            LanguageId::SerbianCyrillic          => 0x0C1A,
+            LanguageId::SerbianLatin             => 0x081A,
+            LanguageId::Sindhi                   => 0x0459,
+            LanguageId::Slovak                   => 0x041B,
+            LanguageId::Slovenian                => 0x0424,
+            LanguageId::SpanishTraditional       => 0x040A,
+            LanguageId::SpanishMexican           => 0x080A,
+            LanguageId::SpanishModern            => 0x0C0A,
+            LanguageId::SpanishGuatemala         => 0x100A,
+            LanguageId::SpanishCostaRica         => 0x140A,
+            LanguageId::SpanishPanama            => 0x180A,
+            LanguageId::SpanishDominicanRepublic => 0x1C0A,
+            LanguageId::SpanishVenezuela         => 0x200A,
+            LanguageId::SpanishColombia          => 0x240A,
+            LanguageId::SpanishPeru              => 0x280A,
+            LanguageId::SpanishArgentina         => 0x2C0A,
+            LanguageId::SpanishEcuador           => 0x300A,
+            LanguageId::SpanishChile             => 0x340A,
+            LanguageId::SpanishUruguay           => 0x380A,
+            LanguageId::SpanishParaguay          => 0x3C0A,
+            LanguageId::SpanishBolivia           => 0x400A,
+            LanguageId::SpanishElSalvador        => 0x440A,
+            LanguageId::SpanishHonduras          => 0x480A,
+            LanguageId::SpanishNicaragua         => 0x4C0A,
+            LanguageId::SpanishPuertoRico        => 0x500A,
+            LanguageId::Sutu                     => 0x0430,
+            LanguageId::SwahiliKenya             => 0x0441,
+            LanguageId::Swedish                  => 0x041D,
+            LanguageId::SwedishFinland           => 0x081D,
+            LanguageId::Tamil                    => 0x0449,
+            LanguageId::TatarTatarstan           => 0x0444,
+            LanguageId::Telugu                   => 0x044A,
+            LanguageId::Thai                     => 0x041E,
+            LanguageId::Turkish                  => 0x041F,
+            LanguageId::Ukrainian                => 0x0422,
+            LanguageId::UrduPakistan             => 0x0420,
+            LanguageId::UrduIndia                => 0x0820,
+            LanguageId::UzbekLatin               => 0x0443,
+            LanguageId::UzbekCyrillic            => 0x0843,
+            LanguageId::Vietnamese               => 0x042A,
+            LanguageId::HIDUsageData             => 0x04FF,
+            LanguageId::HIDVendor1               => 0xF0FF,
+            LanguageId::HIDVendor2               => 0xF4FF,
+            LanguageId::HIDVendor3               => 0xF8FF,
+            LanguageId::HIDVendor4               => 0xFCFF,
+
+            LanguageId::Other(code) => code,
+        }
+    }
+
+    /// Name of the language as listed in the USB-IF table.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            LanguageId::Afrikaans                => "Afrikaans",
+            LanguageId::Albanian                 => "Albanian",
+            LanguageId::ArabicSaudiArabia        => "Arabic (Saudi Arabia)",
+            LanguageId::ArabicIraq               => "Arabic (Iraq)",
+            LanguageId::ArabicEgypt              => "Arabic (Egypt)",
+            LanguageId::ArabicLibya              => "Arabic (Libya)",
+            LanguageId::ArabicAlgeria            => "Arabic (Algeria)",
+            LanguageId::ArabicMorocco            => "Arabic (Morocco)",
+            LanguageId::ArabicTunisia            => "Arabic (Tunisia)",
+            LanguageId::ArabicOman               => "Arabic (Oman)",
+            LanguageId::ArabicYemen              => "Arabic (Yemen)",
+            LanguageId::ArabicSyria              => "Arabic (Syria)",
+            LanguageId::ArabicJordan             => "Arabic (Jordan)",
+            LanguageId::ArabicLebanon            => "Arabic (Lebanon)",
+            LanguageId::ArabicKuwait             => "Arabic (Kuwait)",
+            LanguageId::ArabicUAE                => "Arabic (U.A.E.)",
+            LanguageId::ArabicBahrain            => "Arabic (Bahrain)",
+            LanguageId::ArabicQatar              => "Arabic (Qatar)",
+            LanguageId::Armenian                 => "Armenian",
+            LanguageId::Assamese                 => "Assamese",
+            LanguageId::AzeriLatin               => "Azeri (Latin)",
+            LanguageId::AzeriCyrillic            => "Azeri (Cyrillic)",
+            LanguageId::Basque                   => "Basque",
+            LanguageId::Belarussian              => "Belarussian",
+            LanguageId::Bengali                  => "Bengali",
+            LanguageId::Bulgarian                => "Bulgarian",
+            LanguageId::Burmese                  => "Burmese",
+            LanguageId::Catalan                  => "Catalan",
+            LanguageId::ChineseTaiwan            => "Chinese (Taiwan)",
+            LanguageId::ChinesePRC               => "Chinese (PRC)",
+            LanguageId::ChineseHongKong          => "Chinese (Hong Kong SAR, PRC)",
+            LanguageId::ChineseSingapore         => "Chinese (Singapore)",
+            LanguageId::ChineseMacau             => "Chinese (Macau SAR)",
+            LanguageId::Croatian                 => "Croatian",
+            LanguageId::Czech                    => "Czech",
+            LanguageId::Danish                   => "Danish",
+            LanguageId::DutchNetherlands         => "Dutch (Netherlands)",
+            LanguageId::DutchBelgium             => "Dutch (Belgium)",
+            LanguageId::EnglishUnitedStates      => "English (United States)",
+            LanguageId::EnglishUnitedKingdom     => "English (United Kingdom)",
+            LanguageId::EnglishAustralian        => "English (Australian)",
+            LanguageId::EnglishCanadian          => "English (Canadian)",
+            LanguageId::EnglishNewZealand        => "English (New Zealand)",
+            LanguageId::EnglishIreland           => "English (Ireland)",
+            LanguageId::EnglishSouthAfrica       => "English (South Africa)",
+            LanguageId::EnglishJamaica           => "English (Jamaica)",
+            LanguageId::EnglishCaribbean         => "English (Caribbean)",
+            LanguageId::EnglishBelize            => "English (Belize)",
+            LanguageId::EnglishTrinidad          => "English (Trinidad)",
+            LanguageId::EnglishZimbabwe          => "English (Zimbabwe)",
+            LanguageId::EnglishPhilippines       => "English (Philippines)",
+            LanguageId::Estonian                 => "Estonian",
+            LanguageId::Faeroese                 => "Faeroese",
+            LanguageId::Farsi                    => "Farsi",
+            LanguageId::Finnish                  => "Finnish",
+            LanguageId::FrenchStandard           => "French (Standard)",
+            LanguageId::FrenchBelgian            => "French (Belgian)",
+            LanguageId::FrenchCanadian           => "French (Canadian)",
+            LanguageId::FrenchSwitzerland        => "French (Switzerland)",
+            LanguageId::FrenchLuxembourg         => "French (Luxembourg)",
+            LanguageId::FrenchMonaco             => "French (Monaco)",
+            LanguageId::Georgian                 => "Georgian",
+            LanguageId::GermanStandard           => "German (Standard)",
+            LanguageId::GermanSwitzerland        => "German (Switzerland)",
+            LanguageId::GermanAustria            => "German (Austria)",
+            LanguageId::GermanLuxembourg         => "German (Luxembourg)",
+            LanguageId::GermanLiechtenstein      => "German (Liechtenstein)",
+            LanguageId::Greek                    => "Greek",
+            LanguageId::Gujarati                 => "Gujarati",
+            LanguageId::Hebrew                   => "Hebrew",
+            LanguageId::Hindi                    => "Hindi",
+            LanguageId::Hungarian                => "Hungarian",
+            LanguageId::Icelandic                => "Icelandic",
+            LanguageId::Indonesian               => "Indonesian",
+            LanguageId::ItalianStandard          => "Italian (Standard)",
+            LanguageId::ItalianSwitzerland       => "Italian (Switzerland)",
+            LanguageId::Japanese                 => "Japanese",
+            LanguageId::Kannada                  => "Kannada",
+            LanguageId::KashmiriIndia            => "Kashmiri (India)",
+            LanguageId::Kazakh                   => "Kazakh",
+            LanguageId::Konkani                  => "Konkani",
+            LanguageId::Korean                   => "Korean",
+            LanguageId::KoreanJohab              => "Korean (Johab)",
+            LanguageId::Latvian                  => "Latvian",
+            LanguageId::Lithuanian               => "Lithuanian",
+            LanguageId::LithuanianClassic        => "Lithuanian (Classic)",
+            LanguageId::Macedonian               => "Macedonian",
+            LanguageId::MalayMalaysian           => "Malay (Malaysian)",
+            LanguageId::MalayBrunei              => "Malay (Brunei Darussalam)",
+            LanguageId::Malayalam                => "Malayalam",
+            LanguageId::Manipuri                 => "Manipuri",
+            LanguageId::Marathi                  => "Marathi",
+            LanguageId::NepaliIndia              => "Nepali (India)",
+            LanguageId::NorwegianBokmal          => "Norwegian (Bokmal)",
+            LanguageId::NorwegianNynorsk         => "Norwegian (Nynorsk)",
+            LanguageId::Oriya                    => "Oriya",
+            LanguageId::Polish                   => "Polish",
+            LanguageId::PortugueseBrazil         => "Portuguese (Brazil)",
+            LanguageId::PortugueseStandard       => "Portuguese (Standard)",
+            LanguageId::Punjabi                  => "Punjabi",
+            LanguageId::Romanian                 => "Romanian",
+            LanguageId::Russian                  => "Russian",
+            LanguageId::Sanskrit                 => "Sanskrit",
+            LanguageId::SerbianCyrillic          => "Serbian (Cyrillic)",
+            LanguageId::SerbianLatin             => "Serbian (Latin)",
+            LanguageId::Sindhi                   => "Sindhi",
+            LanguageId::Slovak                   => "Slovak",
+            LanguageId::Slovenian                => "Slovenian",
+            LanguageId::SpanishTraditional       => "Spanish (Traditional Sort)",
+            LanguageId::SpanishMexican           => "Spanish (Mexican)",
+            LanguageId::SpanishModern            => "Spanish (Modern Sort)",
+            LanguageId::SpanishGuatemala         => "Spanish (Guatemala)",
+            LanguageId::SpanishCostaRica         => "Spanish (Costa Rica)",
+            LanguageId::SpanishPanama            => "Spanish (Panama)",
+            LanguageId::SpanishDominicanRepublic => "Spanish (Dominican Republic)",
+            LanguageId::SpanishVenezuela         => "Spanish (Venezuela)",
+            LanguageId::SpanishColombia          => "Spanish (Colombia)",
+            LanguageId::SpanishPeru              => "Spanish (Peru)",
+            LanguageId::SpanishArgentina         => "Spanish (Argentina)",
+            LanguageId::SpanishEcuador           => "Spanish (Ecuador)",
+            LanguageId::SpanishChile             => "Spanish (Chile)",
+            LanguageId::SpanishUruguay           => "Spanish (Uruguay)",
+            LanguageId::SpanishParaguay          => "Spanish (Paraguay)",
+            LanguageId::SpanishBolivia           => "Spanish (Bolivia)",
+            LanguageId::SpanishElSalvador        => "Spanish (El Salvador)",
+            LanguageId::SpanishHonduras          => "Spanish (Honduras)",
+            LanguageId::SpanishNicaragua         => "Spanish (Nicaragua)",
+            LanguageId::SpanishPuertoRico        => "Spanish (Puerto Rico)",
+            LanguageId::Sutu                     => "Sutu",
+            LanguageId::SwahiliKenya             => "Swahili (Kenya)",
+            LanguageId::Swedish                  => "Swedish",
+            LanguageId::SwedishFinland           => "Swedish (Finland)",
+            LanguageId::Tamil                    => "Tamil",
+            LanguageId::TatarTatarstan           => "Tatar (Tatarstan)",
+            LanguageId::Telugu                   => "Telugu",
+            LanguageId::Thai                     => "Thai",
+            LanguageId::Turkish                  => "Turkish",
+            LanguageId::Ukrainian                => "Ukrainian",
+            LanguageId::UrduPakistan             => "Urdu (Pakistan)",
+            LanguageId::UrduIndia                => "Urdu (India)",
+            LanguageId::UzbekLatin               => "Uzbek (Latin)",
+            LanguageId::UzbekCyrillic            => "Uzbek (Cyrillic)",
+            LanguageId::Vietnamese               => "Vietnamese",
+            LanguageId::HIDUsageData             => "HID (Usage Data Descriptor)",
+            LanguageId::HIDVendor1               => "HID (Vendor Defined 1)",
+            LanguageId::HIDVendor2               => "HID (Vendor Defined 2)",
+            LanguageId::HIDVendor3               => "HID (Vendor Defined 3)",
+            LanguageId::HIDVendor4               => "HID (Vendor Defined 4)",
+
+            LanguageId::Other(_) => "Unknown",
+        }
+    }
+}
+
+impl core::convert::TryFrom<u16> for LanguageId {
+    type Error = USBParseError;
+
+    fn try_from(code: u16) -> Result<LanguageId, USBParseError> {
+        match code {
+            0x0436 => Ok( LanguageId::Afrikaans ),
+            0x041C => Ok( LanguageId::Albanian ),
+            0x0401 => Ok( LanguageId::ArabicSaudiArabia ),
+            0x0801 => Ok( LanguageId::ArabicIraq ),
+            0x0C01 => Ok( LanguageId::ArabicEgypt ),
+            0x1001 => Ok( LanguageId::ArabicLibya ),
+            0x1401 => Ok( LanguageId::ArabicAlgeria ),
+            0x1801 => Ok( LanguageId::ArabicMorocco ),
+            0x1C01 => Ok( LanguageId::ArabicTunisia ),
+            0x2001 => Ok( LanguageId::ArabicOman ),
+            0x2401 => Ok( LanguageId::ArabicYemen ),
+            0x2801 => Ok( LanguageId::ArabicSyria ),
+            0x2C01 => Ok( LanguageId::ArabicJordan ),
+            0x3001 => Ok( LanguageId::ArabicLebanon ),
+            0x3401 => Ok( LanguageId::ArabicKuwait ),
+            0x3801 => Ok( LanguageId::ArabicUAE ),
+            0x3C01 => Ok( LanguageId::ArabicBahrain ),
+            0x4001 => Ok( LanguageId::ArabicQatar ),
+            0x042B => Ok( LanguageId::Armenian ),
+            0x044D => Ok( LanguageId::Assamese ),
+            0x042C => Ok( LanguageId::AzeriLatin ),
+            0x082C => Ok( LanguageId::AzeriCyrillic ),
+            0x042D => Ok( LanguageId::Basque ),
+            0x0423 => Ok( LanguageId::Belarussian ),
+            0x0445 => Ok( LanguageId::Bengali ),
+            0x0402 => Ok( LanguageId::Bulgarian ),
+            0x0455 => Ok( LanguageId::Burmese ),
+            0x0403 => Ok( LanguageId::Catalan ),
+            0x0404 => Ok( LanguageId::ChineseTaiwan ),
+            0x0804 => Ok( LanguageId::ChinesePRC ),
+            0x0C04 => Ok( LanguageId::ChineseHongKong ),
+            0x1004 => Ok( LanguageId::ChineseSingapore ),
+            0x1404 => Ok( LanguageId::ChineseMacau ),
+            0x041A => Ok( LanguageId::Croatian ),
+            0x0405 => Ok( LanguageId::Czech ),
+            0x0406 => Ok( LanguageId::Danish ),
+            0x0413 => Ok( LanguageId::DutchNetherlands ),
+            0x0813 => Ok( LanguageId::DutchBelgium ),
+            0x0409 => Ok( LanguageId::EnglishUnitedStates ),
+            0x0809 => Ok( LanguageId::EnglishUnitedKingdom ),
+            0x0C09 => Ok( LanguageId::EnglishAustralian ),
+            0x1009 => Ok( LanguageId::EnglishCanadian ),
+            0x1409 => Ok( LanguageId::EnglishNewZealand ),
+            0x1809 => Ok( LanguageId::EnglishIreland ),
+            0x1C09 => Ok( LanguageId::EnglishSouthAfrica ),
+            0x2009 => Ok( LanguageId::EnglishJamaica ),
+            0x2409 => Ok( LanguageId::EnglishCaribbean ),
+            0x2809 => Ok( LanguageId::EnglishBelize ),
+            0x2C09 => Ok( LanguageId::EnglishTrinidad ),
+            0x3009 => Ok( LanguageId::EnglishZimbabwe ),
+            0x3409 => Ok( LanguageId::EnglishPhilippines ),
+            0x0425 => Ok( LanguageId::Estonian ),
+            0x0438 => Ok( LanguageId::Faeroese ),
+            0x0429 => Ok( LanguageId::Farsi ),
+            0x040B => Ok( LanguageId::Finnish ),
+            0x040C => Ok( LanguageId::FrenchStandard ),
+            0x080C => Ok( LanguageId::FrenchBelgian ),
+            0x0C0C => Ok( LanguageId::FrenchCanadian ),
+            0x100C => Ok( LanguageId::FrenchSwitzerland ),
+            0x140C => Ok( LanguageId::FrenchLuxembourg ),
+            0x180C => Ok( LanguageId::FrenchMonaco ),
+            0x0437 => Ok( LanguageId::Georgian ),
+            0x0407 => Ok( LanguageId::GermanStandard ),
+            0x0807 => Ok( LanguageId::GermanSwitzerland ),
+            0x0C07 => Ok( LanguageId::GermanAustria ),
+            0x1007 => Ok( LanguageId::GermanLuxembourg ),
+            0x1407 => Ok( LanguageId::GermanLiechtenstein ),
+            0x0408 => Ok( LanguageId::Greek ),
+            0x0447 => Ok( LanguageId::Gujarati ),
+            0x040D => Ok( LanguageId::Hebrew ),
+            0x0439 => Ok( LanguageId::Hindi ),
+            0x040E => Ok( LanguageId::Hungarian ),
+            0x040F => Ok( LanguageId::Icelandic ),
+            0x0421 => Ok( LanguageId::Indonesian ),
+            0x0410 => Ok( LanguageId::ItalianStandard ),
+            0x0810 => Ok( LanguageId::ItalianSwitzerland ),
+            0x0411 => Ok( LanguageId::Japanese ),
+            0x044B => Ok( LanguageId::Kannada ),
+            0x0860 => Ok( LanguageId::KashmiriIndia ),
+            0x043F => Ok( LanguageId::Kazakh ),
+            0x0457 => Ok( LanguageId::Konkani ),
+            0x0412 => Ok( LanguageId::Korean ),
+            0x0812 => Ok( LanguageId::KoreanJohab ),
+            0x0426 => Ok( LanguageId::Latvian ),
+            0x0427 => Ok( LanguageId::Lithuanian ),
+            0x0827 => Ok( LanguageId::LithuanianClassic ),
+            0x042F => Ok( LanguageId::Macedonian ),
+            0x043E => Ok( LanguageId::MalayMalaysian ),
+            0x083E => Ok( LanguageId::MalayBrunei ),
+            0x044C => Ok( LanguageId::Malayalam ),
+            0x0458 => Ok( LanguageId::Manipuri ),
+            0x044E => Ok( LanguageId::Marathi ),
+            0x0861 => Ok( LanguageId::NepaliIndia ),
+            0x0414 => Ok( LanguageId::NorwegianBokmal ),
+            0x0814 => Ok( LanguageId::NorwegianNynorsk ),
+            0x0448 => Ok( LanguageId::Oriya ),
+            0x0415 => Ok( LanguageId::Polish ),
+            0x0416 => Ok( LanguageId::PortugueseBrazil ),
+            0x0816 => Ok( LanguageId::PortugueseStandard ),
+            0x0446 => Ok( LanguageId::Punjabi ),
+            0x0418 => Ok( LanguageId::Romanian ),
+            0x0419 => Ok( LanguageId::Russian ),
+            0x044F => Ok( LanguageId::Sanskrit ),
+            0x0C1A => Ok( LanguageId::SerbianCyrillic ),
+            0x081A => Ok( LanguageId::SerbianLatin ),
+            0x0459 => Ok( LanguageId::Sindhi ),
+            0x041B => Ok( LanguageId::Slovak ),
+            0x0424 => Ok( LanguageId::Slovenian ),
+            0x040A => Ok( LanguageId::SpanishTraditional ),
+            0x080A => Ok( LanguageId::SpanishMexican ),
+            0x0C0A => Ok( LanguageId::SpanishModern ),
+            0x100A => Ok( LanguageId::SpanishGuatemala ),
+            0x140A => Ok( LanguageId::SpanishCostaRica ),
+            0x180A => Ok( LanguageId::SpanishPanama ),
+            0x1C0A => Ok( LanguageId::SpanishDominicanRepublic ),
+            0x200A => Ok( LanguageId::SpanishVenezuela ),
+            0x240A => Ok( LanguageId::SpanishColombia ),
+            0x280A => Ok( LanguageId::SpanishPeru ),
+            0x2C0A => Ok( LanguageId::SpanishArgentina ),
+            0x300A => Ok( LanguageId::SpanishEcuador ),
+            0x340A => Ok( LanguageId::SpanishChile ),
+            0x380A => Ok( LanguageId::SpanishUruguay ),
+            0x3C0A => Ok( LanguageId::SpanishParaguay ),
+            0x400A => Ok( LanguageId::SpanishBolivia ),
+            0x440A => Ok( LanguageId::SpanishElSalvador ),
+            0x480A => Ok( LanguageId::SpanishHonduras ),
+            0x4C0A => Ok( LanguageId::SpanishNicaragua ),
+            0x500A => Ok( LanguageId::SpanishPuertoRico ),
+            0x0430 => Ok( LanguageId::Sutu ),
+            0x0441 => Ok( LanguageId::SwahiliKenya ),
+            0x041D => Ok( LanguageId::Swedish ),
+            0x081D => Ok( LanguageId::SwedishFinland ),
+            0x0449 => Ok( LanguageId::Tamil ),
+            0x0444 => Ok( LanguageId::TatarTatarstan ),
+            0x044A => Ok( LanguageId::Telugu ),
+            0x041E => Ok( LanguageId::Thai ),
+            0x041F => Ok( LanguageId::Turkish ),
+            0x0422 => Ok( LanguageId::Ukrainian ),
+            0x0420 => Ok( LanguageId::UrduPakistan ),
+            0x0820 => Ok( LanguageId::UrduIndia ),
+            0x0443 => Ok( LanguageId::UzbekLatin ),
+            0x0843 => Ok( LanguageId::UzbekCyrillic ),
+            0x042A => Ok( LanguageId::Vietnamese ),
+            0x04FF => Ok( LanguageId::HIDUsageData ),
+            0xF0FF => Ok( LanguageId::HIDVendor1 ),
+            0xF4FF => Ok( LanguageId::HIDVendor2 ),
+            0xF8FF => Ok( LanguageId::HIDVendor3 ),
+            0xFCFF => Ok( LanguageId::HIDVendor4 ),
+
+            _ => Err( USBParseError::UnknownLanguage(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u16> for LanguageId {
+    fn into(self) -> u16 {
+        self.code()
+    }
+}
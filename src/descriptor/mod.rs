@@ -1,51 +1,73 @@
-//! USB Device descriptor.
-//! A structure representing the USB device descriptor.
-//! Documentation: Section 9.6.1 of the USB 3.0 specification.
-
-
-
-
-
-pub struct DeviceDescriptor {
-    /// Size of this descriptor in bytes.
-    bLength: u8,
-
-    /// Descriptor type.
-    /// Its type is enumerated in `DescriptorType`.
-    bDescriptorType: u8,
-
-    /// USB Specification release number in binary-coded decimal.
-    /// Its type is abstracted in `USBVersion`.
-    bcdUSB: u16,
-
-    /// USB-IF class code, subclass code and protocol code.
-    /// Its type is enumerated in `ClassCode`.
-    class: u8,
-    subclass: u8,
-    protocol: u8,
-
-    /// Maximum packet size for endpoint 0.
-    bMaxPacketSize0: u8,
-
-    /// Vendor ID.
-    idVendor: u16,
-
-    /// Product ID.
-    idProduct: u16,
-
-    /// Device release number in binary coded decimal.
-    bcdDevice: u16,
-
-    /// Index of string descriptor describing manufacturer.
-    iManufacturer: u8,
-
-    /// Index of string descriptor describing product.
-    iProduct: u8,
+//! USB descriptors.
+//! Structures representing the standard USB descriptors and the ability to
+//! parse them from raw data.
+
+
+
+mod association;
+mod bcd;
+mod bos;
+mod capability;
+mod companion;
+mod configuration;
+mod device;
+mod endpoint;
+mod interface;
+mod langid;
+mod qualifier;
+mod raw;
+mod serialize;
+mod string;
+mod tree;
+mod types;
+
+
+
+pub use association::*;
+pub use bcd::*;
+pub use bos::*;
+pub use capability::*;
+pub use companion::*;
+pub use configuration::*;
+pub use device::*;
+pub use endpoint::*;
+pub use interface::*;
+pub use langid::*;
+pub use qualifier::*;
+pub use raw::*;
+pub use serialize::*;
+pub use string::*;
+pub use types::*;
+pub use tree::{
+    Association, Associations,
+    Endpoint, Endpoints,
+    Function, Functions,
+    Interface, Interfaces,
+    InterfaceGroup, InterfaceGroups,
+};
+
+
+
+/// Reads a little-endian `u16` at the given offset.
+/// The caller must ensure that `offset + 1` is in bounds.
+pub(crate) const fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
 
-    /// Index of string descriptor containing device serial number.
-    iSerialNumber: u8,
+/// Reads a little-endian `u32` at the given offset.
+/// The caller must ensure that `offset + 3` is in bounds.
+pub(crate) const fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
 
-    /// number of possible configuration.
-    bNumcConfigurations: u8,
+/// Writes a little-endian `u16` at the given offset.
+/// The caller must ensure that `offset + 1` is in bounds.
+pub(crate) fn write_u16(bytes: &mut [u8], offset: usize, value: u16) {
+    bytes[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
 }
 
+/// Writes a little-endian `u32` at the given offset.
+/// The caller must ensure that `offset + 3` is in bounds.
+pub(crate) fn write_u32(bytes: &mut [u8], offset: usize, value: u32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
@@ -0,0 +1,157 @@
+//! USB Device Qualifier descriptor.
+//! A structure representing the device qualifier descriptor, which describes
+//! a high speed capable device as it would be at its other operating speed.
+//! Documentation: Section 9.6.2 of the USB 2.0 specification.
+
+
+
+use crate::class::class::Class;
+use crate::error::*;
+use super::{ BCDVersion, DescriptorType, DeviceDescriptor, MaxPacketSize0, Speed, ToBytes, read_u16 };
+
+
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeviceQualifierDescriptor {
+    /// USB Specification release number in binary-coded decimal.
+    bcdUSB: BCDVersion,
+
+    /// USB-IF class code, subclass code and protocol code.
+    /// Its type is enumerated in `Class`.
+    class: u8,
+    subclass: u8,
+    protocol: u8,
+
+    /// Maximum packet size for endpoint 0 at the other speed.
+    bMaxPacketSize0: MaxPacketSize0,
+
+    /// Number of configurations at the other speed.
+    bNumConfigurations: u8,
+}
+
+impl DeviceQualifierDescriptor {
+    /// Size of this descriptor in bytes.
+    pub const LENGTH: u8 = 10;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::DeviceQualifier.code();
+
+    /// Creates the device qualifier of the given device.
+    /// Everything but the maximum packet size of endpoint 0 is copied from
+    /// the device descriptor.
+    pub const fn new(device: &DeviceDescriptor, max_packet_size0: MaxPacketSize0) -> DeviceQualifierDescriptor {
+        let (class, subclass, protocol) = device.class_code();
+
+        DeviceQualifierDescriptor {
+            bcdUSB: device.usb_version(),
+            class,
+            subclass,
+            protocol,
+            bMaxPacketSize0: max_packet_size0,
+            bNumConfigurations: device.num_configurations(),
+        }
+    }
+
+    /// Parses a device qualifier descriptor from the start of the given
+    /// buffer.
+    /// The other speed is always full or high speed, so the maximum packet
+    /// size of endpoint 0 follows the USB 2.0 rules.
+    pub fn parse(bytes: &[u8]) -> Result<DeviceQualifierDescriptor, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[0] != Self::LENGTH {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        Ok(DeviceQualifierDescriptor {
+            bcdUSB: BCDVersion::from_raw( read_u16(bytes, 2) ),
+            class: bytes[4],
+            subclass: bytes[5],
+            protocol: bytes[6],
+            bMaxPacketSize0: MaxPacketSize0::parse(bytes[7], BCDVersion::USB20)?,
+            bNumConfigurations: bytes[8],
+        })
+    }
+
+    /// Encodes the descriptor.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH as usize] {
+        let usb = self.bcdUSB.raw().to_le_bytes();
+
+        [
+            Self::LENGTH, Self::TYPE,
+            usb[0], usb[1],
+            self.class, self.subclass, self.protocol,
+            self.bMaxPacketSize0.raw(),
+            self.bNumConfigurations,
+            0,
+        ]
+    }
+
+    /// USB Specification release number.
+    pub const fn usb_version(&self) -> BCDVersion {
+        self.bcdUSB
+    }
+
+    /// Device class.
+    /// Unknown codes are kept as is.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// Raw class, subclass and protocol codes.
+    pub const fn class_code(&self) -> (u8, u8, u8) {
+        (self.class, self.subclass, self.protocol)
+    }
+
+    /// Maximum packet size for endpoint 0 at the other speed.
+    pub const fn max_packet_size0(&self) -> MaxPacketSize0 {
+        self.bMaxPacketSize0
+    }
+
+    /// Number of configurations at the other speed.
+    pub const fn num_configurations(&self) -> u8 {
+        self.bNumConfigurations
+    }
+
+    /// Checks the qualifier against the device descriptor read at the given
+    /// speed.
+    /// Both must report the same class codes and number of configurations.
+    /// Endpoint 0 must use 64 byte packets at high speed, which is the
+    /// device descriptor at high speed and the qualifier otherwise.
+    pub fn validate(&self, device: &DeviceDescriptor, speed: Speed) -> Result<(), USBParseError> {
+        if self.class_code() != device.class_code() {
+            return Err( USBParseError::ClassMismatch(self.class) );
+        }
+
+        if self.bNumConfigurations != device.num_configurations() {
+            return Err( USBParseError::ConfigurationCountMismatch(self.bNumConfigurations) );
+        }
+
+        let high = match speed {
+            Speed::High => device.max_packet_size0(),
+            _ => self.bMaxPacketSize0,
+        };
+
+        match high {
+            MaxPacketSize0::Bytes64 => Ok(()),
+            _ => Err( USBParseError::InvalidMaxPacketSize( high.raw() ) ),
+        }
+    }
+}
+
+impl ToBytes for DeviceQualifierDescriptor {
+    fn size(&self) -> usize {
+        Self::LENGTH as usize
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
@@ -0,0 +1,130 @@
+//! Raw descriptors.
+//! Untyped view over a single descriptor and iteration over a chain of
+//! concatenated descriptors.
+
+
+
+use crate::error::*;
+use super::{ DescriptorType, ToBytes };
+
+
+
+/// A single descriptor borrowed from a larger buffer.
+/// The slice always holds exactly `bLength` bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawDescriptor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> RawDescriptor<'a> {
+    /// Splits the first descriptor from the given buffer.
+    /// Returns the descriptor and the remaining bytes.
+    pub fn split(bytes: &'a [u8]) -> Result<(RawDescriptor<'a>, &'a [u8]), USBParseError> {
+        if bytes.len() < 2 {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let length = bytes[0] as usize;
+
+        if length < 2 {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if length > bytes.len() {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let (head, tail) = bytes.split_at(length);
+
+        Ok( (RawDescriptor { bytes: head }, tail) )
+    }
+
+    /// Size of this descriptor in bytes.
+    pub const fn length(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Raw descriptor type code.
+    /// Its type is enumerated in `DescriptorType`.
+    pub const fn descriptor_type(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// Descriptor type.
+    /// Class specific codes take their HID meaning; use
+    /// `DescriptorType::decode_in` to resolve them with the interface class.
+    pub fn kind(&self) -> DescriptorType {
+        DescriptorType::decode( self.descriptor_type() )
+    }
+
+    /// All bytes of the descriptor, including the header.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Bytes of the descriptor following the two byte header.
+    pub fn data(&self) -> &'a [u8] {
+        &self.bytes[2..]
+    }
+}
+
+impl<'a> ToBytes for RawDescriptor<'a> {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.bytes.to_bytes(buffer)
+    }
+}
+
+
+
+/// Iterator over a chain of concatenated descriptors.
+/// Iteration stops at the first malformed descriptor. Use `validate` to
+/// check the whole chain beforehand.
+#[derive(Clone, Copy, Debug)]
+pub struct Descriptors<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Descriptors<'a> {
+    /// Creates an iterator over the given chain.
+    pub const fn new(bytes: &'a [u8]) -> Descriptors<'a> {
+        Descriptors { bytes }
+    }
+
+    /// Checks that every descriptor in the chain is well formed.
+    pub fn validate(bytes: &'a [u8]) -> Result<(), USBParseError> {
+        let mut rest = bytes;
+
+        while !rest.is_empty() {
+            rest = RawDescriptor::split(rest)?.1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the bytes that have not been iterated yet.
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for Descriptors<'a> {
+    type Item = RawDescriptor<'a>;
+
+    fn next(&mut self) -> Option<RawDescriptor<'a>> {
+        match RawDescriptor::split(self.bytes) {
+            Ok((descriptor, rest)) => {
+                self.bytes = rest;
+                Some(descriptor)
+            },
+
+            Err(_) => {
+                self.bytes = &[];
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Descriptor serialization.
+//! Writing descriptors back to bytes into caller provided buffers, and
+//! emitting descriptor sets whose header fields are computed on the fly.
+
+
+
+use crate::error::*;
+use super::{ BosDescriptor, ConfigurationDescriptor, Descriptors, InterfaceDescriptor, OtherSpeedConfiguration, write_u16 };
+
+
+
+/// A descriptor that can be written back to bytes.
+pub trait ToBytes {
+    /// Number of bytes needed to serialize the descriptor.
+    fn size(&self) -> usize;
+
+    /// Writes the descriptor at the start of the given buffer.
+    /// Returns the number of bytes written.
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError>;
+}
+
+/// Returns the first `size` bytes of the buffer.
+pub(crate) fn reserve(buffer: &mut [u8], size: usize) -> Result<&mut [u8], USBSerializeError> {
+    match buffer.get_mut(..size) {
+        Some(bytes) => Ok(bytes),
+        _ => Err( USBSerializeError::BufferTooShort(size) ),
+    }
+}
+
+/// Returns the `bLength` of a descriptor of the given size.
+pub(crate) fn length(size: usize) -> Result<u8, USBSerializeError> {
+    match u8::try_from(size) {
+        Ok(length) => Ok(length),
+        _ => Err( USBSerializeError::TooLong(size) ),
+    }
+}
+
+
+
+/// A header followed by a chain of descriptors.
+/// The total length at offset 2 and the count at offset 4 of the header are
+/// written when the set is finished.
+#[derive(Debug)]
+struct DescriptorSet<'b> {
+    /// Output buffer, starting with the header.
+    buffer: &'b mut [u8],
+
+    /// Number of bytes written so far.
+    size: usize,
+
+    /// Number of counted descriptors.
+    count: usize,
+}
+
+impl<'b> DescriptorSet<'b> {
+    /// Starts a set with the given header.
+    fn new<T: ToBytes + ?Sized>(buffer: &'b mut [u8], header: &T) -> Result<DescriptorSet<'b>, USBSerializeError> {
+        let size = header.to_bytes(buffer)?;
+
+        Ok( DescriptorSet { buffer, size, count: 0 } )
+    }
+
+    /// Appends a descriptor and returns the bytes written for it.
+    fn push<T: ToBytes + ?Sized>(&mut self, descriptor: &T) -> Result<&[u8], USBSerializeError> {
+        let start = self.size;
+
+        let written = match descriptor.to_bytes(&mut self.buffer[start..]) {
+            Err( USBSerializeError::BufferTooShort(n) ) => return Err( USBSerializeError::BufferTooShort(start + n) ),
+            other => other?,
+        };
+
+        self.size += written;
+
+        Ok( &self.buffer[start..self.size] )
+    }
+
+    /// Writes the total length and count into the header.
+    fn finish(self) -> Result<usize, USBSerializeError> {
+        let total = match u16::try_from(self.size) {
+            Ok(total) => total,
+            _ => return Err( USBSerializeError::TooLong(self.size) ),
+        };
+
+        let count = match u8::try_from(self.count) {
+            Ok(count) => count,
+            _ => return Err( USBSerializeError::TooLong(self.count) ),
+        };
+
+        write_u16(self.buffer, 2, total);
+        self.buffer[4] = count;
+
+        Ok(self.size)
+    }
+}
+
+
+
+/// Emits a full configuration: the configuration descriptor followed by its
+/// interface, endpoint and class specific descriptors.
+/// `wTotalLength` and `bNumInterfaces` are computed when finishing.
+#[derive(Debug)]
+pub struct ConfigurationWriter<'b> {
+    set: DescriptorSet<'b>,
+}
+
+impl<'b> ConfigurationWriter<'b> {
+    /// Starts a configuration with the given header.
+    /// Its total length and number of interfaces are ignored.
+    pub fn new(buffer: &'b mut [u8], descriptor: &ConfigurationDescriptor) -> Result<ConfigurationWriter<'b>, USBSerializeError> {
+        Ok( ConfigurationWriter { set: DescriptorSet::new(buffer, descriptor)? } )
+    }
+
+    /// Starts an other speed configuration with the given header.
+    /// Its total length and number of interfaces are ignored.
+    pub fn other_speed(buffer: &'b mut [u8], descriptor: &ConfigurationDescriptor) -> Result<ConfigurationWriter<'b>, USBSerializeError> {
+        let writer = Self::new(buffer, descriptor)?;
+        writer.set.buffer[1] = OtherSpeedConfiguration::TYPE;
+        Ok(writer)
+    }
+
+    /// Appends one or more descriptors to the configuration.
+    /// Every interface descriptor with alternate setting 0 counts as an
+    /// interface.
+    pub fn push<T: ToBytes + ?Sized>(&mut self, descriptor: &T) -> Result<&mut Self, USBSerializeError> {
+        let written = self.set.push(descriptor)?;
+
+        let interfaces = Descriptors::new(written)
+            .filter(|raw| raw.descriptor_type() == InterfaceDescriptor::TYPE)
+            .filter(|raw| raw.bytes().get(3) == Some(&0))
+            .count();
+
+        self.set.count += interfaces;
+
+        Ok(self)
+    }
+
+    /// Number of bytes written so far.
+    pub const fn size(&self) -> usize {
+        self.set.size
+    }
+
+    /// Completes the configuration header.
+    /// Returns the total length of the configuration.
+    pub fn finish(self) -> Result<usize, USBSerializeError> {
+        self.set.finish()
+    }
+}
+
+
+
+/// Emits a Binary Object Store: the BOS descriptor followed by its device
+/// capabilities.
+/// `wTotalLength` and `bNumDeviceCaps` are computed when finishing.
+#[derive(Debug)]
+pub struct BosWriter<'b> {
+    set: DescriptorSet<'b>,
+}
+
+impl<'b> BosWriter<'b> {
+    /// Starts an empty Binary Object Store.
+    pub fn new(buffer: &'b mut [u8]) -> Result<BosWriter<'b>, USBSerializeError> {
+        let header = [BosDescriptor::LENGTH, BosDescriptor::TYPE, 0, 0, 0];
+
+        Ok( BosWriter { set: DescriptorSet::new(buffer, &header[..])? } )
+    }
+
+    /// Appends one or more device capabilities to the store.
+    pub fn push<T: ToBytes + ?Sized>(&mut self, capability: &T) -> Result<&mut Self, USBSerializeError> {
+        let written = self.set.push(capability)?;
+
+        self.set.count += Descriptors::new(written).count();
+
+        Ok(self)
+    }
+
+    /// Number of bytes written so far.
+    pub const fn size(&self) -> usize {
+        self.set.size
+    }
+
+    /// Completes the BOS descriptor.
+    /// Returns the total length of the store.
+    pub fn finish(self) -> Result<usize, USBSerializeError> {
+        self.set.finish()
+    }
+}
+
+
+
+impl ToBytes for [u8] {
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        reserve(buffer, self.len())?.copy_from_slice(self);
+
+        Ok( self.len() )
+    }
+}
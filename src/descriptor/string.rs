@@ -0,0 +1,348 @@
+//! USB String descriptors.
+//! String descriptors hold UTF-16LE text. String index 0 instead holds the
+//! table of languages supported by the device.
+//! Documentation: Section 9.6.9 of the USB 3.0 specification.
+
+
+
+use crate::error::*;
+use super::{ DescriptorType, LanguageId, ToBytes, length, read_u16, reserve, write_u16 };
+
+
+
+/// Descriptor type of a string descriptor.
+const STRING: u8 = DescriptorType::String.code();
+
+/// Validates the header of a string descriptor and returns its payload.
+fn payload(bytes: &[u8]) -> Result<&[u8], USBParseError> {
+    if bytes.len() < 2 {
+        return Err( USBParseError::BufferTooShort(bytes.len()) );
+    }
+
+    let length = bytes[0];
+
+    if (length < 2) || ((length & 1) != 0) {
+        return Err( USBParseError::InvalidLength(length) );
+    }
+
+    if bytes[1] != STRING {
+        return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+    }
+
+    if bytes.len() < length as usize {
+        return Err( USBParseError::BufferTooShort(bytes.len()) );
+    }
+
+    Ok( &bytes[2..length as usize] )
+}
+
+
+
+/// A string descriptor borrowed from a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StringDescriptor<'a> {
+    /// UTF-16LE encoded text.
+    payload: &'a [u8],
+}
+
+impl<'a> StringDescriptor<'a> {
+    /// Parses a string descriptor from the start of the given buffer.
+    /// The text itself is only validated while iterating it.
+    pub fn parse(bytes: &'a [u8]) -> Result<StringDescriptor<'a>, USBParseError> {
+        Ok( StringDescriptor { payload: payload(bytes)? } )
+    }
+
+    /// Raw UTF-16LE text.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Number of UTF-16 code units in the text.
+    pub const fn len_utf16(&self) -> usize {
+        self.payload.len() / 2
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.payload.is_empty()
+    }
+
+    /// UTF-16 code units of the text.
+    pub fn units(&self) -> Units<'a> {
+        Units { bytes: self.payload }
+    }
+
+    /// Characters of the text.
+    /// Unpaired surrogates are reported as `USBParseError::InvalidUTF16`.
+    pub fn chars(&self) -> Chars<'a> {
+        Chars { inner: core::char::decode_utf16( self.units() ) }
+    }
+
+    /// Returns `true` if the text is valid and equal to the given string.
+    pub fn matches(&self, text: &str) -> bool {
+        let mut expected = text.chars();
+
+        for c in self.chars() {
+            match (c, expected.next()) {
+                (Ok(a), Some(b)) if a == b => continue,
+                _ => return false,
+            }
+        }
+
+        expected.next().is_none()
+    }
+}
+
+
+
+impl<'a> ToBytes for StringDescriptor<'a> {
+    fn size(&self) -> usize {
+        2 + self.payload.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        header(buffer, self.payload.len())?[2..].copy_from_slice(self.payload);
+
+        Ok( self.size() )
+    }
+}
+
+/// Writes the header of a string descriptor with the given payload size.
+/// Returns the whole descriptor to fill in.
+fn header(buffer: &mut [u8], payload: usize) -> Result<&mut [u8], USBSerializeError> {
+    let bytes = reserve(buffer, 2 + payload)?;
+
+    bytes[0] = length(2 + payload)?;
+    bytes[1] = STRING;
+
+    Ok(bytes)
+}
+
+
+
+/// A string descriptor to be encoded from text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextDescriptor<'a>(pub &'a str);
+
+impl<'a> TextDescriptor<'a> {
+    /// Encodes the string descriptor at compile time.
+    /// Panics if the descriptor does not fill the array exactly.
+    pub const fn to_array<const N: usize>(&self) -> [u8; N] {
+        assert!(N <= u8::MAX as usize, "the string is longer than bLength allows");
+
+        let text = self.0.as_bytes();
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+        let mut o = 2;
+
+        while i < text.len() {
+            // Decode one UTF-8 sequence. `str` is always valid UTF-8.
+            let (c, width) = match text[i] {
+                b if b < 0x80 => (b as u32, 1),
+                b if b < 0xE0 => (((b & 0x1F) as u32) << 6 | (text[i + 1] & 0x3F) as u32, 2),
+                b if b < 0xF0 => (((b & 0x0F) as u32) << 12 | ((text[i + 1] & 0x3F) as u32) << 6 | (text[i + 2] & 0x3F) as u32, 3),
+                b => (((b & 0x07) as u32) << 18 | ((text[i + 1] & 0x3F) as u32) << 12 | ((text[i + 2] & 0x3F) as u32) << 6 | (text[i + 3] & 0x3F) as u32, 4),
+            };
+
+            i += width;
+
+            let (first, second) = match c >= 0x10000 {
+                true => (0xD800 | ((c - 0x10000) >> 10), Some( 0xDC00 | ((c - 0x10000) & 0x3FF) )),
+                _ => (c, None),
+            };
+
+            assert!(o + 2 <= N, "the string does not fit in the array");
+            bytes[o] = first as u8;
+            bytes[o + 1] = (first >> 8) as u8;
+            o += 2;
+
+            if let Some(second) = second {
+                assert!(o + 2 <= N, "the string does not fit in the array");
+                bytes[o] = second as u8;
+                bytes[o + 1] = (second >> 8) as u8;
+                o += 2;
+            }
+        }
+
+        assert!(o == N, "the string does not fill the array");
+
+        bytes[0] = N as u8;
+        bytes[1] = STRING;
+
+        bytes
+    }
+}
+
+impl<'a> ToBytes for TextDescriptor<'a> {
+    fn size(&self) -> usize {
+        2 + 2 * self.0.encode_utf16().count()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let size = self.size();
+        let bytes = header(buffer, size - 2)?;
+
+        for (i, unit) in self.0.encode_utf16().enumerate() {
+            write_u16(bytes, 2 + 2 * i, unit);
+        }
+
+        Ok(size)
+    }
+}
+
+
+
+/// Iterator over the UTF-16 code units of a string descriptor.
+#[derive(Clone, Debug)]
+pub struct Units<'a> {
+    /// Code units not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for Units<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+
+        let unit = read_u16(self.bytes, 0);
+        self.bytes = &self.bytes[2..];
+
+        Some(unit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.bytes.len() / 2;
+        (n, Some(n))
+    }
+}
+
+/// Iterator over the characters of a string descriptor.
+#[derive(Clone, Debug)]
+pub struct Chars<'a> {
+    /// UTF-16 decoder.
+    inner: core::char::DecodeUtf16<Units<'a>>,
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = Result<char, USBParseError>;
+
+    fn next(&mut self) -> Option<Result<char, USBParseError>> {
+        self.inner.next().map(|c| c.map_err(|e| USBParseError::InvalidUTF16( e.unpaired_surrogate() )))
+    }
+}
+
+
+
+/// The string descriptor at index 0: the languages supported by the device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LanguageTable<'a> {
+    /// Little-endian LANGID codes.
+    payload: &'a [u8],
+}
+
+impl<'a> LanguageTable<'a> {
+    /// Parses the language table from the start of the given buffer.
+    pub fn parse(bytes: &'a [u8]) -> Result<LanguageTable<'a>, USBParseError> {
+        Ok( LanguageTable { payload: payload(bytes)? } )
+    }
+
+    /// Number of languages in the table.
+    pub const fn len(&self) -> usize {
+        self.payload.len() / 2
+    }
+
+    /// Returns `true` if the device lists no languages.
+    pub const fn is_empty(&self) -> bool {
+        self.payload.is_empty()
+    }
+
+    /// Languages supported by the device.
+    /// Identifiers not listed by the USB-IF are kept in `LanguageId::Other`.
+    pub fn languages(&self) -> Languages<'a> {
+        Languages { units: Units { bytes: self.payload } }
+    }
+
+    /// Returns `true` if the device supports the given language.
+    pub fn supports(&self, language: LanguageId) -> bool {
+        self.languages().any(|l| l == language)
+    }
+}
+
+impl<'a> ToBytes for LanguageTable<'a> {
+    fn size(&self) -> usize {
+        2 + self.payload.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        header(buffer, self.payload.len())?[2..].copy_from_slice(self.payload);
+
+        Ok( self.size() )
+    }
+}
+
+/// A language table to be encoded from a list of languages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LanguageList<'a>(pub &'a [LanguageId]);
+
+impl<'a> LanguageList<'a> {
+    /// Encodes the language table at compile time.
+    /// Panics if the table does not fill the array exactly.
+    pub const fn to_array<const N: usize>(&self) -> [u8; N] {
+        assert!(N == 2 + 2 * self.0.len(), "the table does not fill the array");
+        assert!(N <= u8::MAX as usize, "the table is longer than bLength allows");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < self.0.len() {
+            let code = self.0[i].code().to_le_bytes();
+
+            bytes[2 + 2 * i] = code[0];
+            bytes[3 + 2 * i] = code[1];
+            i += 1;
+        }
+
+        bytes[0] = N as u8;
+        bytes[1] = STRING;
+
+        bytes
+    }
+}
+
+impl<'a> ToBytes for LanguageList<'a> {
+    fn size(&self) -> usize {
+        2 + 2 * self.0.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        let bytes = header(buffer, 2 * self.0.len())?;
+
+        for (i, language) in self.0.iter().enumerate() {
+            write_u16(bytes, 2 + 2 * i, language.code());
+        }
+
+        Ok( self.size() )
+    }
+}
+
+/// Iterator over the languages of a language table.
+#[derive(Clone, Debug)]
+pub struct Languages<'a> {
+    /// LANGID codes not yet visited.
+    units: Units<'a>,
+}
+
+impl<'a> Iterator for Languages<'a> {
+    type Item = LanguageId;
+
+    fn next(&mut self) -> Option<LanguageId> {
+        self.units.next().map(LanguageId::decode)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.units.size_hint()
+    }
+}
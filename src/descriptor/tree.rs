@@ -0,0 +1,673 @@
+//! Configuration tree.
+//! Borrowed views over the interface associations, interfaces and endpoints
+//! of a configuration. Class specific descriptors are attached to the node
+//! they follow.
+
+
+
+use crate::cdc::{ FunctionalDescriptor, FunctionalDescriptors, UnionDescriptor };
+use crate::class::class::Class;
+use crate::error::*;
+use crate::hid::{ BootDevice, HIDDescriptor };
+use super::{
+    DescriptorType, Descriptors, EndpointDescriptor, InterfaceAssociationDescriptor, InterfaceDescriptor, RawDescriptor,
+    SuperSpeedEndpointCompanion, SuperSpeedPlusIsochronousCompanion, TransferType,
+};
+
+
+
+/// Descriptor type of an interface descriptor.
+const INTERFACE: u8 = DescriptorType::Interface.code();
+
+/// Descriptor type of an endpoint descriptor.
+const ENDPOINT: u8 = DescriptorType::Endpoint.code();
+
+/// Descriptor type of an interface association descriptor.
+const INTERFACE_ASSOCIATION: u8 = DescriptorType::InterfaceAssociation.code();
+
+/// Descriptor type of a SuperSpeed endpoint companion descriptor.
+const SS_ENDPOINT_COMPANION: u8 = DescriptorType::SuperSpeedEndpointCompanion.code();
+
+/// Descriptor type of a SuperSpeedPlus isochronous endpoint companion
+/// descriptor.
+const SSP_ISOCH_ENDPOINT_COMPANION: u8 = DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion.code();
+
+
+
+/// Checks that a standard descriptor inside a configuration is long enough
+/// to hold all its fields.
+/// Endpoint descriptors must be standard or Audio 1.0 ones, so that their
+/// typed form holds all their bytes.
+pub(crate) fn check(raw: &RawDescriptor) -> Result<(), USBParseError> {
+    let endpoint = matches!(raw.length(), EndpointDescriptor::LENGTH | EndpointDescriptor::AUDIO_LENGTH);
+
+    if (raw.descriptor_type() == ENDPOINT) && !endpoint {
+        return Err( USBParseError::InvalidLength(raw.length()) );
+    }
+
+    let minimum = match raw.descriptor_type() {
+        INTERFACE => InterfaceDescriptor::LENGTH,
+        ENDPOINT => EndpointDescriptor::LENGTH,
+        INTERFACE_ASSOCIATION => InterfaceAssociationDescriptor::LENGTH,
+        SS_ENDPOINT_COMPANION => SuperSpeedEndpointCompanion::LENGTH,
+        SSP_ISOCH_ENDPOINT_COMPANION => SuperSpeedPlusIsochronousCompanion::LENGTH,
+        _ => 2,
+    };
+
+    match raw.length() < minimum {
+        true => Err( USBParseError::InvalidLength(raw.length()) ),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if the descriptor starts a new function or interface.
+pub(crate) fn is_function_boundary(raw: &RawDescriptor) -> bool {
+    matches!(raw.descriptor_type(), INTERFACE | INTERFACE_ASSOCIATION)
+}
+
+/// Returns `true` if the descriptor starts a new endpoint.
+fn is_endpoint(raw: &RawDescriptor) -> bool {
+    raw.descriptor_type() == ENDPOINT
+}
+
+/// Returns the leading descriptors of the chain up to the first descriptor
+/// matching the predicate.
+pub(crate) fn head(bytes: &[u8], stop: fn(&RawDescriptor) -> bool) -> &[u8] {
+    &bytes[..offset(bytes, stop)]
+}
+
+/// Returns the offset of the first descriptor matching the predicate, or the
+/// length of the chain if none does.
+fn offset(bytes: &[u8], stop: fn(&RawDescriptor) -> bool) -> usize {
+    let mut descriptors = Descriptors::new(bytes);
+
+    loop {
+        let position = bytes.len() - descriptors.remaining().len();
+
+        match descriptors.next() {
+            Some(raw) if !stop(&raw) => continue,
+            _ => return position,
+        }
+    }
+}
+
+/// Splits the next descriptor of the given type from the chain, along with
+/// the descriptors that follow it up to the next one matching `stop`.
+fn next_node<'a>(bytes: &mut &'a [u8], kind: u8, stop: fn(&RawDescriptor) -> bool) -> Option<(RawDescriptor<'a>, &'a [u8])> {
+    let mut descriptors = Descriptors::new(bytes);
+
+    let header = descriptors.find(|raw| raw.descriptor_type() == kind)?;
+
+    let rest = descriptors.remaining();
+    let body = head(rest, stop);
+
+    *bytes = &rest[body.len()..];
+
+    Some( (header, body) )
+}
+
+
+
+/// An interface association descriptor and the interfaces it groups.
+#[derive(Clone, Copy, Debug)]
+pub struct Association<'a> {
+    /// The interface association descriptor.
+    header: RawDescriptor<'a>,
+
+    /// Body of the whole configuration.
+    configuration: &'a [u8],
+}
+
+impl<'a> Association<'a> {
+    /// The raw interface association descriptor.
+    pub const fn raw(&self) -> RawDescriptor<'a> {
+        self.header
+    }
+
+    /// Number of the first interface of the function.
+    pub const fn first_interface(&self) -> u8 {
+        self.header.bytes()[2]
+    }
+
+    /// Number of contiguous interfaces of the function.
+    pub const fn interface_count(&self) -> u8 {
+        self.header.bytes()[3]
+    }
+
+    /// Raw function class, subclass and protocol codes.
+    pub const fn class_code(&self) -> (u8, u8, u8) {
+        let bytes = self.header.bytes();
+        (bytes[4], bytes[5], bytes[6])
+    }
+
+    /// Function class.
+    /// Unknown codes are kept as is.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// The typed interface association descriptor.
+    pub fn descriptor(&self) -> Result<InterfaceAssociationDescriptor, USBParseError> {
+        InterfaceAssociationDescriptor::parse( self.header.bytes() )
+    }
+
+    /// Index of string descriptor describing this function.
+    pub const fn string_index(&self) -> u8 {
+        self.header.bytes()[7]
+    }
+
+    /// Returns `true` if the interface number belongs to this function.
+    pub const fn contains(&self, number: u8) -> bool {
+        let first = self.first_interface() as u16;
+        let number = number as u16;
+
+        (number >= first) && (number < first + self.interface_count() as u16)
+    }
+
+    /// Interfaces and alternate settings grouped by this function.
+    pub const fn interfaces(&self) -> Interfaces<'a> {
+        Interfaces {
+            bytes: self.configuration,
+            filter: Filter::Association(self.header),
+            configuration: self.configuration,
+        }
+    }
+}
+
+/// Iterator over the interface associations of a configuration.
+#[derive(Clone, Copy, Debug)]
+pub struct Associations<'a> {
+    /// Descriptors not yet visited.
+    bytes: &'a [u8],
+
+    /// Body of the whole configuration.
+    configuration: &'a [u8],
+}
+
+impl<'a> Associations<'a> {
+    /// Creates an iterator over the associations of a configuration body.
+    pub(crate) const fn new(configuration: &'a [u8]) -> Associations<'a> {
+        Associations { bytes: configuration, configuration }
+    }
+}
+
+impl<'a> Iterator for Associations<'a> {
+    type Item = Association<'a>;
+
+    fn next(&mut self) -> Option<Association<'a>> {
+        let (header, _) = next_node(&mut self.bytes, INTERFACE_ASSOCIATION, |_| true)?;
+
+        Some( Association { header, configuration: self.configuration } )
+    }
+}
+
+
+
+/// An interface descriptor (one alternate setting) with its class specific
+/// descriptors and endpoints.
+#[derive(Clone, Copy, Debug)]
+pub struct Interface<'a> {
+    /// The interface descriptor.
+    header: RawDescriptor<'a>,
+
+    /// Descriptors following the interface descriptor, up to the next
+    /// interface or interface association.
+    body: &'a [u8],
+}
+
+impl<'a> Interface<'a> {
+    /// The raw interface descriptor.
+    pub const fn raw(&self) -> RawDescriptor<'a> {
+        self.header
+    }
+
+    /// Number of this interface.
+    pub const fn number(&self) -> u8 {
+        self.header.bytes()[2]
+    }
+
+    /// Value used to select this alternate setting.
+    pub const fn alternate_setting(&self) -> u8 {
+        self.header.bytes()[3]
+    }
+
+    /// Raw interface class, subclass and protocol codes.
+    pub const fn class_code(&self) -> (u8, u8, u8) {
+        let bytes = self.header.bytes();
+        (bytes[5], bytes[6], bytes[7])
+    }
+
+    /// Interface class.
+    /// Unknown codes are kept as is.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// Index of string descriptor describing this interface.
+    pub const fn string_index(&self) -> u8 {
+        // Interface descriptors are checked to hold all their fields when
+        // parsing the configuration.
+        self.header.bytes()[8]
+    }
+
+    /// The typed interface descriptor.
+    pub fn descriptor(&self) -> Result<InterfaceDescriptor, USBParseError> {
+        InterfaceDescriptor::parse( self.header.bytes() )
+    }
+
+    /// Class specific descriptors placed before the first endpoint.
+    pub fn class_specific(&self) -> Descriptors<'a> {
+        Descriptors::new( head(self.body, is_endpoint) )
+    }
+
+    /// HID descriptor of a HID interface.
+    /// Returns `None` for other interfaces, and if the descriptor is missing
+    /// or malformed.
+    pub fn hid(&self) -> Option<HIDDescriptor<'a>> {
+        if !matches!(self.class(), Class::HumanInterfaceDevice(_, _)) {
+            return None;
+        }
+
+        self.class_specific()
+            .find(|raw| raw.descriptor_type() == HIDDescriptor::TYPE)
+            .and_then(|raw| HIDDescriptor::parse( raw.bytes() ).ok())
+    }
+
+    /// Functional descriptors of a CDC communications interface.
+    /// The iterator is empty for other interfaces.
+    pub fn cdc_functional(&self) -> FunctionalDescriptors<'a> {
+        let descriptors = match self.class() {
+            Class::CDCControl(_, _) => self.class_specific(),
+            _ => Descriptors::new(&[]),
+        };
+
+        FunctionalDescriptors::new(descriptors)
+    }
+
+    /// Union functional descriptor of a CDC communications interface.
+    /// Returns `None` for other interfaces, and if the descriptor is missing
+    /// or malformed.
+    pub fn cdc_union(&self) -> Option<UnionDescriptor<'a>> {
+        self.cdc_functional().find_map(|descriptor| match descriptor {
+            FunctionalDescriptor::Union(union) => Some(union),
+            _ => None,
+        })
+    }
+
+    /// Boot protocol implemented by a HID interface of the boot subclass.
+    pub fn boot_device(&self) -> Option<BootDevice> {
+        BootDevice::of( self.class() )
+    }
+
+    /// Endpoints of this alternate setting.
+    pub const fn endpoints(&self) -> Endpoints<'a> {
+        Endpoints { bytes: self.body }
+    }
+}
+
+/// Which interfaces an `Interfaces` iterator yields.
+#[derive(Clone, Copy, Debug)]
+enum Filter<'a> {
+    /// All interfaces.
+    All,
+
+    /// Interfaces grouped by the given interface association.
+    Association(RawDescriptor<'a>),
+
+    /// Interfaces not grouped by any interface association.
+    Unassociated,
+
+    /// Alternate settings of the given interface.
+    Number(u8),
+}
+
+/// Iterator over interfaces and their alternate settings.
+#[derive(Clone, Copy, Debug)]
+pub struct Interfaces<'a> {
+    /// Descriptors not yet visited.
+    bytes: &'a [u8],
+
+    /// Which interfaces to yield.
+    filter: Filter<'a>,
+
+    /// Body of the whole configuration.
+    configuration: &'a [u8],
+}
+
+impl<'a> Interfaces<'a> {
+    /// Creates an iterator over all interfaces of a configuration body.
+    pub(crate) const fn new(configuration: &'a [u8]) -> Interfaces<'a> {
+        Interfaces { bytes: configuration, filter: Filter::All, configuration }
+    }
+
+    /// Creates an iterator over the interfaces of a configuration body not
+    /// grouped by any interface association.
+    pub(crate) const fn unassociated(configuration: &'a [u8]) -> Interfaces<'a> {
+        Interfaces { bytes: configuration, filter: Filter::Unassociated, configuration }
+    }
+
+    /// Groups consecutive alternate settings of the same interface.
+    pub const fn grouped(self) -> InterfaceGroups<'a> {
+        InterfaceGroups { interfaces: self }
+    }
+
+    /// Returns `true` if the interface passes the filter.
+    fn accepts(&self, interface: &Interface) -> bool {
+        let number = interface.number();
+
+        match self.filter {
+            Filter::All => true,
+
+            Filter::Association(header) => Association { header, configuration: self.configuration }.contains(number),
+
+            Filter::Unassociated => !Associations::new(self.configuration).any(|a| a.contains(number)),
+
+            Filter::Number(n) => n == number,
+        }
+    }
+}
+
+impl<'a> Iterator for Interfaces<'a> {
+    type Item = Interface<'a>;
+
+    fn next(&mut self) -> Option<Interface<'a>> {
+        loop {
+            let (header, body) = next_node(&mut self.bytes, INTERFACE, is_function_boundary)?;
+
+            let interface = Interface { header, body };
+
+            if self.accepts(&interface) {
+                return Some(interface);
+            }
+        }
+    }
+}
+
+
+
+/// Consecutive alternate settings of the same interface.
+#[derive(Clone, Copy, Debug)]
+pub struct InterfaceGroup<'a> {
+    /// Number of the interface.
+    number: u8,
+
+    /// Number of alternate settings in the group.
+    count: usize,
+
+    /// Iterator positioned at the first alternate setting of the group.
+    start: Interfaces<'a>,
+}
+
+impl<'a> InterfaceGroup<'a> {
+    /// Number of the interface.
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Number of alternate settings in the group.
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if the group has no alternate settings.
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Alternate settings of the interface, in descriptor order.
+    pub fn alternates(&self) -> core::iter::Take<Interfaces<'a>> {
+        self.start.take(self.count)
+    }
+
+    /// Returns the alternate setting with the given `bAlternateSetting`.
+    pub fn alternate(&self, setting: u8) -> Option<Interface<'a>> {
+        self.alternates().find(|i| i.alternate_setting() == setting)
+    }
+}
+
+/// Iterator over interfaces grouped by interface number.
+#[derive(Clone, Copy, Debug)]
+pub struct InterfaceGroups<'a> {
+    /// Interfaces not yet grouped.
+    interfaces: Interfaces<'a>,
+}
+
+impl<'a> Iterator for InterfaceGroups<'a> {
+    type Item = InterfaceGroup<'a>;
+
+    fn next(&mut self) -> Option<InterfaceGroup<'a>> {
+        let start = self.interfaces;
+        let number = self.interfaces.next()?.number();
+
+        let mut count = 1;
+        let mut ahead = self.interfaces;
+
+        while let Some(interface) = ahead.next() {
+            if interface.number() != number {
+                break;
+            }
+
+            self.interfaces = ahead;
+            count += 1;
+        }
+
+        Some( InterfaceGroup { number, count, start } )
+    }
+}
+
+
+
+/// A function of a configuration: the interfaces grouped by an interface
+/// association, or a single interface outside of any association.
+#[derive(Clone, Copy, Debug)]
+pub enum Function<'a> {
+    /// Interfaces grouped by an interface association.
+    Association(Association<'a>),
+
+    /// A single interface and its alternate settings.
+    Interface(InterfaceGroup<'a>),
+}
+
+impl<'a> Function<'a> {
+    /// Number of the first interface of the function.
+    pub const fn first_interface(&self) -> u8 {
+        match self {
+            Function::Association(a) => a.first_interface(),
+            Function::Interface(g) => g.number(),
+        }
+    }
+
+    /// Number of contiguous interfaces of the function.
+    pub const fn interface_count(&self) -> u8 {
+        match self {
+            Function::Association(a) => a.interface_count(),
+            Function::Interface(_) => 1,
+        }
+    }
+
+    /// Raw function class, subclass and protocol codes.
+    /// A single interface reports the codes of its first alternate setting.
+    pub fn class_code(&self) -> (u8, u8, u8) {
+        match self {
+            Function::Association(a) => a.class_code(),
+            Function::Interface(g) => g.alternates().next().map_or((0, 0, 0), |i| i.class_code()),
+        }
+    }
+
+    /// Function class.
+    /// Unknown codes are kept as is.
+    pub fn class(&self) -> Class {
+        Class::decode( self.class_code() )
+    }
+
+    /// Index of string descriptor describing this function.
+    pub fn string_index(&self) -> u8 {
+        match self {
+            Function::Association(a) => a.string_index(),
+            Function::Interface(g) => g.alternates().next().map_or(0, |i| i.string_index()),
+        }
+    }
+
+    /// Returns `true` if the interface number belongs to this function.
+    pub const fn contains(&self, number: u8) -> bool {
+        match self {
+            Function::Association(a) => a.contains(number),
+            Function::Interface(g) => g.number() == number,
+        }
+    }
+
+    /// Interfaces and alternate settings of the function.
+    pub const fn interfaces(&self) -> Interfaces<'a> {
+        match self {
+            Function::Association(a) => a.interfaces(),
+            Function::Interface(g) => Interfaces {
+                bytes: g.start.bytes,
+                filter: Filter::Number(g.number),
+                configuration: g.start.configuration,
+            },
+        }
+    }
+}
+
+/// Iterator over the functions of a configuration, in descriptor order.
+#[derive(Clone, Copy, Debug)]
+pub struct Functions<'a> {
+    /// Descriptors not yet visited.
+    bytes: &'a [u8],
+
+    /// Number of the last interface yielded outside of any association.
+    last: Option<u8>,
+
+    /// Body of the whole configuration.
+    configuration: &'a [u8],
+}
+
+impl<'a> Functions<'a> {
+    /// Creates an iterator over the functions of a configuration body.
+    pub(crate) const fn new(configuration: &'a [u8]) -> Functions<'a> {
+        Functions { bytes: configuration, last: None, configuration }
+    }
+}
+
+impl<'a> Iterator for Functions<'a> {
+    type Item = Function<'a>;
+
+    fn next(&mut self) -> Option<Function<'a>> {
+        loop {
+            let position = self.bytes;
+
+            let mut descriptors = Descriptors::new(self.bytes);
+            let header = descriptors.find(is_function_boundary)?;
+            self.bytes = descriptors.remaining();
+
+            if header.descriptor_type() == INTERFACE_ASSOCIATION {
+                return Some( Function::Association( Association { header, configuration: self.configuration } ) );
+            }
+
+            let number = header.bytes()[2];
+
+            if self.last == Some(number) {
+                continue;
+            }
+
+            let interfaces = Interfaces { bytes: position, filter: Filter::Unassociated, configuration: self.configuration };
+
+            if let Some(group) = interfaces.grouped().next() {
+                if group.number() == number {
+                    self.last = Some(number);
+                    return Some( Function::Interface(group) );
+                }
+            }
+        }
+    }
+}
+
+
+
+/// An endpoint descriptor with the class specific and companion descriptors
+/// that follow it.
+#[derive(Clone, Copy, Debug)]
+pub struct Endpoint<'a> {
+    /// The endpoint descriptor.
+    header: RawDescriptor<'a>,
+
+    /// Descriptors following the endpoint descriptor, up to the next
+    /// endpoint.
+    body: &'a [u8],
+}
+
+impl<'a> Endpoint<'a> {
+    /// The raw endpoint descriptor.
+    pub const fn raw(&self) -> RawDescriptor<'a> {
+        self.header
+    }
+
+    /// Raw endpoint address.
+    pub const fn address(&self) -> u8 {
+        self.header.bytes()[2]
+    }
+
+    /// The typed endpoint descriptor.
+    pub const fn descriptor(&self) -> EndpointDescriptor {
+        EndpointDescriptor::from_bytes( self.header.bytes() )
+    }
+
+    /// Descriptors placed after this endpoint, including its companion
+    /// descriptors.
+    pub const fn class_specific(&self) -> Descriptors<'a> {
+        Descriptors::new(self.body)
+    }
+
+    /// SuperSpeed endpoint companion of a USB 3.x endpoint.
+    pub fn companion(&self) -> Option<SuperSpeedEndpointCompanion> {
+        self.class_specific()
+            .find(|raw| raw.descriptor_type() == SS_ENDPOINT_COMPANION)
+            .map(|raw| SuperSpeedEndpointCompanion::from_bytes( raw.bytes() ))
+    }
+
+    /// SuperSpeedPlus isochronous endpoint companion of a USB 3.x
+    /// isochronous endpoint.
+    pub fn isochronous_companion(&self) -> Option<SuperSpeedPlusIsochronousCompanion> {
+        self.class_specific()
+            .find(|raw| raw.descriptor_type() == SSP_ISOCH_ENDPOINT_COMPANION)
+            .map(|raw| SuperSpeedPlusIsochronousCompanion::from_bytes( raw.bytes() ))
+    }
+
+    /// Maximum number of bytes moved in one service interval by a periodic
+    /// endpoint, or in one burst by a bulk or control endpoint.
+    /// USB 3.x endpoints use their companion descriptors. Other endpoints
+    /// use their maximum packet size and additional transactions.
+    pub fn max_bytes_per_interval(&self) -> u32 {
+        let descriptor = self.descriptor();
+        let packet = descriptor.max_packet_size() as u32;
+
+        let periodic = matches!(descriptor.transfer_type(), TransferType::Isochronous | TransferType::Interrupt);
+
+        match (self.companion(), self.isochronous_companion()) {
+            (Some(c), Some(i)) if periodic && c.ssp_iso_companion() => i.bytes_per_interval(),
+
+            (Some(c), _) if periodic => c.bytes_per_interval() as u32,
+
+            (Some(c), _) => packet * c.burst_size() as u32,
+
+            (None, _) => packet * (descriptor.additional_transactions() as u32 + 1),
+        }
+    }
+}
+
+/// Iterator over the endpoints of an interface.
+#[derive(Clone, Copy, Debug)]
+pub struct Endpoints<'a> {
+    /// Descriptors not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for Endpoints<'a> {
+    type Item = Endpoint<'a>;
+
+    fn next(&mut self) -> Option<Endpoint<'a>> {
+        let (header, body) = next_node(&mut self.bytes, ENDPOINT, is_endpoint)?;
+
+        Some( Endpoint { header, body } )
+    }
+}
@@ -0,0 +1,234 @@
+//! USB Descriptor types.
+//! The `bDescriptorType` codes of the standard, class specific and vendor
+//! specific descriptors.
+//! Documentation: Table 9-6 of the USB 3.2 specification.
+
+
+
+use crate::class::class::Class;
+use crate::class::subclass::ApplicationSpecificSubClass;
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DescriptorType {
+    /// Device descriptor (01h).
+    Device,
+
+    /// Configuration descriptor (02h).
+    Configuration,
+
+    /// String descriptor (03h).
+    String,
+
+    /// Interface descriptor (04h).
+    Interface,
+
+    /// Endpoint descriptor (05h).
+    Endpoint,
+
+    /// Device qualifier descriptor (06h).
+    DeviceQualifier,
+
+    /// Other speed configuration descriptor (07h).
+    OtherSpeedConfiguration,
+
+    /// Interface power descriptor (08h).
+    InterfacePower,
+
+    /// On-The-Go descriptor (09h).
+    OTG,
+
+    /// Debug descriptor (0Ah).
+    Debug,
+
+    /// Interface association descriptor (0Bh).
+    InterfaceAssociation,
+
+    /// Security descriptor (0Ch).
+    Security,
+
+    /// Key descriptor (0Dh).
+    Key,
+
+    /// Encryption type descriptor (0Eh).
+    EncryptionType,
+
+    /// Binary Object Store descriptor (0Fh).
+    BOS,
+
+    /// Device capability descriptor (10h).
+    DeviceCapability,
+
+    /// Wireless endpoint companion descriptor (11h).
+    WirelessEndpointCompanion,
+
+    /// SuperSpeed endpoint companion descriptor (30h).
+    SuperSpeedEndpointCompanion,
+
+    /// SuperSpeedPlus isochronous endpoint companion descriptor (31h).
+    SuperSpeedPlusIsochronousEndpointCompanion,
+
+    /// HID descriptor (21h in a HID interface).
+    HID,
+
+    /// HID report descriptor (22h in a HID interface).
+    Report,
+
+    /// HID physical descriptor (23h in a HID interface).
+    Physical,
+
+    /// Class specific device descriptor (21h outside of HID interfaces).
+    ClassDevice,
+
+    /// Class specific configuration descriptor (22h outside of HID
+    /// interfaces).
+    ClassConfiguration,
+
+    /// Class specific string descriptor (23h outside of HID interfaces).
+    ClassString,
+
+    /// DFU functional descriptor (21h in a firmware upgrade interface).
+    DFUFunctional,
+
+    /// Smart card device class descriptor (21h in a smart card interface).
+    SmartCardFunctional,
+
+    /// Class specific interface descriptor (24h).
+    ClassInterface,
+
+    /// Class specific endpoint descriptor (25h).
+    ClassEndpoint,
+
+    /// Hub descriptor (29h).
+    Hub,
+
+    /// SuperSpeed hub descriptor (2Ah).
+    SuperSpeedHub,
+
+    /// Vendor specific descriptor (40h to 5Fh), kept as is.
+    VendorSpecific(u8),
+
+    /// Unknown descriptor type, kept as is. This includes the class specific
+    /// codes 20h and 26h to 28h, which no class assigns a meaning.
+    Other(u8),
+}
+
+impl DescriptorType {
+    /// Decodes a descriptor type without failing.
+    /// Class specific codes take their HID meaning. Unknown codes are kept
+    /// in the `Other` variant so they convert back to the same byte. Use
+    /// `TryFrom<u8>` to reject them instead.
+    /// The reserved class specific codes 20h and 26h to 28h are unknown.
+    pub fn decode(code: u8) -> DescriptorType {
+        DescriptorType::try_from(code).unwrap_or(DescriptorType::Other(code))
+    }
+
+    /// Decodes a descriptor type found in an interface of the given class.
+    /// Class specific codes 21h to 23h are shared by several classes, and
+    /// are resolved with the class of the interface.
+    pub fn decode_in(code: u8, class: Class) -> DescriptorType {
+        match (code, class) {
+            (0x21..=0x23, Class::HumanInterfaceDevice(_, _)) => DescriptorType::decode(code),
+
+            (0x21, Class::ApplicationSpecific(ApplicationSpecificSubClass::FirmwareUpgrade(_))) => DescriptorType::DFUFunctional,
+            (0x21, Class::SmartCard(_)) => DescriptorType::SmartCardFunctional,
+
+            (0x21, _) => DescriptorType::ClassDevice,
+            (0x22, _) => DescriptorType::ClassConfiguration,
+            (0x23, _) => DescriptorType::ClassString,
+
+            _ => DescriptorType::decode(code),
+        }
+    }
+
+    /// Raw descriptor type code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            DescriptorType::Device                  => 0x01,
+            DescriptorType::Configuration           => 0x02,
+            DescriptorType::String                  => 0x03,
+            DescriptorType::Interface               => 0x04,
+            DescriptorType::Endpoint                => 0x05,
+            DescriptorType::DeviceQualifier         => 0x06,
+            DescriptorType::OtherSpeedConfiguration => 0x07,
+            DescriptorType::InterfacePower          => 0x08,
+            DescriptorType::OTG                     => 0x09,
+            DescriptorType::Debug                   => 0x0A,
+            DescriptorType::InterfaceAssociation    => 0x0B,
+            DescriptorType::Security                => 0x0C,
+            DescriptorType::Key                     => 0x0D,
+            DescriptorType::EncryptionType          => 0x0E,
+            DescriptorType::BOS                     => 0x0F,
+            DescriptorType::DeviceCapability        => 0x10,
+            DescriptorType::WirelessEndpointCompanion => 0x11,
+
+            DescriptorType::SuperSpeedEndpointCompanion => 0x30,
+            DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion => 0x31,
+
+            DescriptorType::HID                 => 0x21,
+            DescriptorType::Report              => 0x22,
+            DescriptorType::Physical            => 0x23,
+            DescriptorType::ClassDevice         => 0x21,
+            DescriptorType::ClassConfiguration  => 0x22,
+            DescriptorType::ClassString         => 0x23,
+            DescriptorType::DFUFunctional       => 0x21,
+            DescriptorType::SmartCardFunctional => 0x21,
+            DescriptorType::ClassInterface      => 0x24,
+            DescriptorType::ClassEndpoint       => 0x25,
+            DescriptorType::Hub                 => 0x29,
+            DescriptorType::SuperSpeedHub       => 0x2A,
+
+            DescriptorType::VendorSpecific(code) => code,
+            DescriptorType::Other(code) => code,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for DescriptorType {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<DescriptorType, USBParseError> {
+        match code {
+            0x01 => Ok( DescriptorType::Device ),
+            0x02 => Ok( DescriptorType::Configuration ),
+            0x03 => Ok( DescriptorType::String ),
+            0x04 => Ok( DescriptorType::Interface ),
+            0x05 => Ok( DescriptorType::Endpoint ),
+            0x06 => Ok( DescriptorType::DeviceQualifier ),
+            0x07 => Ok( DescriptorType::OtherSpeedConfiguration ),
+            0x08 => Ok( DescriptorType::InterfacePower ),
+            0x09 => Ok( DescriptorType::OTG ),
+            0x0A => Ok( DescriptorType::Debug ),
+            0x0B => Ok( DescriptorType::InterfaceAssociation ),
+            0x0C => Ok( DescriptorType::Security ),
+            0x0D => Ok( DescriptorType::Key ),
+            0x0E => Ok( DescriptorType::EncryptionType ),
+            0x0F => Ok( DescriptorType::BOS ),
+            0x10 => Ok( DescriptorType::DeviceCapability ),
+            0x11 => Ok( DescriptorType::WirelessEndpointCompanion ),
+
+            0x30 => Ok( DescriptorType::SuperSpeedEndpointCompanion ),
+            0x31 => Ok( DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion ),
+
+            0x21 => Ok( DescriptorType::HID ),
+            0x22 => Ok( DescriptorType::Report ),
+            0x23 => Ok( DescriptorType::Physical ),
+            0x24 => Ok( DescriptorType::ClassInterface ),
+            0x25 => Ok( DescriptorType::ClassEndpoint ),
+            0x29 => Ok( DescriptorType::Hub ),
+            0x2A => Ok( DescriptorType::SuperSpeedHub ),
+
+            0x40..=0x5F => Ok( DescriptorType::VendorSpecific(code) ),
+
+            _ => Err( USBParseError::UnknownDescriptorType(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for DescriptorType {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
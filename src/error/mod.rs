@@ -3,7 +3,9 @@
 
 
 mod parse;
+mod serialize;
 
 
 
 pub use parse::*;
+pub use serialize::*;
@@ -13,4 +13,127 @@ pub enum USBParseError {
 
     /// Unknown class code.
     UnknownClass(u8),
+
+    /// The buffer is shorter than the descriptor requires.
+    /// Contains the number of bytes available.
+    BufferTooShort(usize),
+
+    /// The `bLength` field does not match the expected size.
+    InvalidLength(u8),
+
+    /// The `wTotalLength` field cannot hold the descriptor it belongs to.
+    InvalidTotalLength(u16),
+
+    /// The `bDescriptorType` field does not match the expected type.
+    InvalidDescriptorType(u8),
+
+    /// Unknown descriptor type code.
+    UnknownDescriptorType(u8),
+
+    /// The class code is not valid at this descriptor level.
+    /// Contains the base class code.
+    InvalidClassLevel(u8),
+
+    /// Unknown language identifier.
+    UnknownLanguage(u16),
+
+    /// Unknown HID country code.
+    UnknownCountry(u8),
+
+    /// A string descriptor contains an unpaired UTF-16 surrogate.
+    InvalidUTF16(u16),
+
+    /// The `bMaxPacketSize0` field is not valid for the USB version.
+    InvalidMaxPacketSize(u8),
+
+    /// An interface is claimed by more than one interface association.
+    /// Contains the interface number.
+    InterfaceClaimedTwice(u8),
+
+    /// An interface association extends past `bNumInterfaces`.
+    /// Contains the number of its first interface.
+    AssociationOutOfRange(u8),
+
+    /// The class codes differ from those of the device descriptor.
+    /// Contains the base class code.
+    ClassMismatch(u8),
+
+    /// The number of configurations differs from that of the device
+    /// descriptor.
+    /// Contains the number of configurations.
+    ConfigurationCountMismatch(u8),
+
+    /// Unknown HID collection type.
+    UnknownCollectionType(u8),
+
+    /// A report descriptor item is truncated.
+    /// Contains the offset of the item.
+    ReportItemTruncated(usize),
+
+    /// A Push item overflows the global stack, or a Pop item finds it empty.
+    /// Contains the offset of the item.
+    ReportStackMismatch(usize),
+
+    /// An End Collection item has no matching Collection item, or a
+    /// collection is not closed.
+    /// Contains the offset of the item, or the length of the descriptor.
+    CollectionMismatch(usize),
+
+    /// A Report ID item is 0 or above FFh, or a Usage Page item is above
+    /// FFFFh.
+    /// Contains the offset of the item.
+    ReportItemOutOfRange(usize),
+
+    /// A report field has no element at this index.
+    /// Contains the index.
+    FieldIndexOutOfRange(u32),
+
+    /// A report field is wider than 32 bits.
+    /// Contains the size of its elements in bits.
+    FieldTooWide(u32),
+
+    /// Unknown CDC functional descriptor subtype.
+    UnknownFunctionalSubtype(u8),
+
+    /// The `bDescriptorSubtype` field does not match the expected subtype.
+    InvalidDescriptorSubtype(u8),
+
+    /// A MAC address string holds a character that is not a hexadecimal
+    /// digit.
+    /// Contains the UTF-16 code unit.
+    InvalidMACAddress(u16),
+
+    /// Unknown NTB header or NDP signature.
+    /// Contains the signature.
+    InvalidNtbSignature(u32),
+
+    /// A length field of an NTB is not valid.
+    /// Contains the length.
+    InvalidNtbLength(u32),
+
+    /// An NDP or a datagram lies outside of its NTB.
+    /// Contains its offset.
+    NtbIndexOutOfRange(u32),
+
+    /// Unknown stop bits code of a line coding.
+    UnknownStopBits(u8),
+
+    /// Unknown parity code of a line coding.
+    UnknownParity(u8),
+
+    /// Unsupported number of data bits of a line coding.
+    UnknownDataBits(u8),
+
+    /// Unknown class request code.
+    UnknownRequest(u8),
+
+    /// Unknown class notification code.
+    UnknownNotification(u8),
+
+    /// The `bmRequestType` field does not match the request or notification.
+    InvalidRequestType(u8),
+
+    /// The `wLength` field does not match the request or notification.
+    /// Contains the length.
+    InvalidRequestLength(u16),
 }
@@ -0,0 +1,33 @@
+//! USB Serialize errors.
+
+
+
+use super::USBParseError;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum USBSerializeError {
+    /// The buffer is shorter than the serialized data.
+    /// Contains the number of bytes needed.
+    BufferTooShort(usize),
+
+    /// The data does not fit in its length field.
+    /// Contains the length of the data.
+    TooLong(usize),
+
+    /// The report field cannot be accessed at this index.
+    /// Contains the error returned when reading the same element.
+    InvalidField(USBParseError),
+
+    /// The value does not fit in the report field.
+    /// Contains the value.
+    ValueOutOfRange(i64),
+
+    /// The transfer block holds as many datagrams as it can.
+    /// Contains the maximum number of datagrams.
+    TooManyDatagrams(usize),
+
+    /// An empty datagram cannot be sent in a transfer block.
+    EmptyDatagram,
+}
@@ -0,0 +1,435 @@
+//! HID boot protocol reports.
+//! Fixed report formats of boot keyboards and boot mice, which hosts without
+//! a report descriptor parser can use, and their canonical report
+//! descriptors.
+//! Documentation: Appendix B and E of the HID 1.11 specification.
+
+
+
+use crate::class::class::Class;
+use crate::class::protocol::HIDProtocol;
+use crate::class::subclass::HIDSubClass;
+use crate::descriptor::ToBytes;
+use crate::error::*;
+
+
+
+/// Usage ID reported in all key slots when too many keys are pressed.
+const ERROR_ROLL_OVER: u8 = 0x01;
+
+
+
+/// A device implementing a boot protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BootDevice {
+    /// Boot keyboard: `BootKeyboardReport` and `BootKeyboardLeds`.
+    Keyboard,
+
+    /// Boot mouse: `BootMouseReport`.
+    Mouse,
+}
+
+impl BootDevice {
+    /// Boot device of an interface of the given class.
+    /// Only interfaces of the boot subclass implement a boot protocol.
+    pub const fn of(class: Class) -> Option<BootDevice> {
+        match class {
+            Class::HumanInterfaceDevice(HIDSubClass::Boot, HIDProtocol::Keyboard) => Some(BootDevice::Keyboard),
+            Class::HumanInterfaceDevice(HIDSubClass::Boot, HIDProtocol::Mouse) => Some(BootDevice::Mouse),
+            _ => None,
+        }
+    }
+
+    /// Canonical report descriptor of the boot reports.
+    pub const fn report_descriptor(&self) -> &'static [u8] {
+        match self {
+            BootDevice::Keyboard => &BootKeyboardReport::DESCRIPTOR,
+            BootDevice::Mouse => &BootMouseReport::DESCRIPTOR,
+        }
+    }
+}
+
+
+
+/// Modifier keys of a boot keyboard report.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KeyModifiers(pub u8);
+
+impl KeyModifiers {
+    /// Left control key.
+    pub const LEFT_CONTROL: KeyModifiers = KeyModifiers(1 << 0);
+
+    /// Left shift key.
+    pub const LEFT_SHIFT: KeyModifiers = KeyModifiers(1 << 1);
+
+    /// Left alt key.
+    pub const LEFT_ALT: KeyModifiers = KeyModifiers(1 << 2);
+
+    /// Left GUI key.
+    pub const LEFT_GUI: KeyModifiers = KeyModifiers(1 << 3);
+
+    /// Right control key.
+    pub const RIGHT_CONTROL: KeyModifiers = KeyModifiers(1 << 4);
+
+    /// Right shift key.
+    pub const RIGHT_SHIFT: KeyModifiers = KeyModifiers(1 << 5);
+
+    /// Right alt key.
+    pub const RIGHT_ALT: KeyModifiers = KeyModifiers(1 << 6);
+
+    /// Right GUI key.
+    pub const RIGHT_GUI: KeyModifiers = KeyModifiers(1 << 7);
+
+    /// Returns `true` if all the given modifiers are pressed.
+    pub const fn contains(&self, other: KeyModifiers) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the given modifiers.
+    pub const fn with(self, other: KeyModifiers) -> KeyModifiers {
+        KeyModifiers(self.0 | other.0)
+    }
+
+    /// Returns `true` if either control key is pressed.
+    pub const fn control(&self) -> bool {
+        (self.0 & (Self::LEFT_CONTROL.0 | Self::RIGHT_CONTROL.0)) != 0
+    }
+
+    /// Returns `true` if either shift key is pressed.
+    pub const fn shift(&self) -> bool {
+        (self.0 & (Self::LEFT_SHIFT.0 | Self::RIGHT_SHIFT.0)) != 0
+    }
+
+    /// Returns `true` if either alt key is pressed.
+    pub const fn alt(&self) -> bool {
+        (self.0 & (Self::LEFT_ALT.0 | Self::RIGHT_ALT.0)) != 0
+    }
+
+    /// Returns `true` if either GUI key is pressed.
+    pub const fn gui(&self) -> bool {
+        (self.0 & (Self::LEFT_GUI.0 | Self::RIGHT_GUI.0)) != 0
+    }
+}
+
+
+
+/// Input report of a boot keyboard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BootKeyboardReport {
+    /// Pressed modifier keys.
+    modifiers: KeyModifiers,
+
+    /// Usage IDs of the pressed keys, 0 for empty slots.
+    keys: [u8; 6],
+}
+
+impl BootKeyboardReport {
+    /// Size of the report in bytes.
+    pub const LENGTH: usize = 8;
+
+    /// Canonical report descriptor of boot keyboards, with the input report
+    /// and the LED output report.
+    pub const DESCRIPTOR: [u8; 63] = [
+        0x05, 0x01,       // Usage Page (Generic Desktop)
+        0x09, 0x06,       // Usage (Keyboard)
+        0xA1, 0x01,       // Collection (Application)
+        0x05, 0x07,       //   Usage Page (Keyboard/Keypad)
+        0x19, 0xE0,       //   Usage Minimum (Left Control)
+        0x29, 0xE7,       //   Usage Maximum (Right GUI)
+        0x15, 0x00,       //   Logical Minimum (0)
+        0x25, 0x01,       //   Logical Maximum (1)
+        0x75, 0x01,       //   Report Size (1)
+        0x95, 0x08,       //   Report Count (8)
+        0x81, 0x02,       //   Input (Data, Variable, Absolute): modifiers
+        0x95, 0x01,       //   Report Count (1)
+        0x75, 0x08,       //   Report Size (8)
+        0x81, 0x01,       //   Input (Constant): reserved byte
+        0x95, 0x05,       //   Report Count (5)
+        0x75, 0x01,       //   Report Size (1)
+        0x05, 0x08,       //   Usage Page (LED)
+        0x19, 0x01,       //   Usage Minimum (Num Lock)
+        0x29, 0x05,       //   Usage Maximum (Kana)
+        0x91, 0x02,       //   Output (Data, Variable, Absolute): LEDs
+        0x95, 0x01,       //   Report Count (1)
+        0x75, 0x03,       //   Report Size (3)
+        0x91, 0x01,       //   Output (Constant): LED padding
+        0x95, 0x06,       //   Report Count (6)
+        0x75, 0x08,       //   Report Size (8)
+        0x15, 0x00,       //   Logical Minimum (0)
+        0x25, 0x65,       //   Logical Maximum (101)
+        0x05, 0x07,       //   Usage Page (Keyboard/Keypad)
+        0x19, 0x00,       //   Usage Minimum (0)
+        0x29, 0x65,       //   Usage Maximum (101)
+        0x81, 0x00,       //   Input (Data, Array): keys
+        0xC0,             // End Collection
+    ];
+
+    /// Creates a report with the given modifiers and keys.
+    /// Keys past the sixth are ignored.
+    pub const fn new(modifiers: KeyModifiers, keys: &[u8]) -> BootKeyboardReport {
+        let mut report = BootKeyboardReport { modifiers, keys: [0; 6] };
+        let mut i = 0;
+
+        while (i < keys.len()) && (i < 6) {
+            report.keys[i] = keys[i];
+            i += 1;
+        }
+
+        report
+    }
+
+    /// Creates the report sent when more keys are pressed than the report
+    /// can hold: all the key slots hold `ErrorRollOver`.
+    pub const fn rollover(modifiers: KeyModifiers) -> BootKeyboardReport {
+        BootKeyboardReport { modifiers, keys: [ERROR_ROLL_OVER; 6] }
+    }
+
+    /// Parses a report from the start of the given buffer.
+    /// Bytes past the boot report are ignored.
+    pub fn parse(bytes: &[u8]) -> Result<BootKeyboardReport, USBParseError> {
+        if bytes.len() < Self::LENGTH {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        Ok(BootKeyboardReport {
+            modifiers: KeyModifiers(bytes[0]),
+            keys: [bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]],
+        })
+    }
+
+    /// Encodes the report.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH] {
+        let k = self.keys;
+
+        [self.modifiers.0, 0, k[0], k[1], k[2], k[3], k[4], k[5]]
+    }
+
+    /// Pressed modifier keys.
+    pub const fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+
+    /// Raw key slots, 0 for empty slots.
+    pub const fn slots(&self) -> [u8; 6] {
+        self.keys
+    }
+
+    /// Returns `true` if the keyboard reports a rollover error instead of
+    /// its keys.
+    pub const fn is_rollover(&self) -> bool {
+        let mut i = 0;
+
+        while i < 6 {
+            if self.keys[i] != ERROR_ROLL_OVER {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Usage IDs of the pressed keys.
+    /// Empty during a rollover error.
+    pub fn keys(&self) -> impl Iterator<Item = u8> + '_ {
+        let rollover = self.is_rollover();
+
+        self.keys.iter()
+            .copied()
+            .filter(move |key| !rollover && (*key != 0))
+    }
+
+    /// Returns `true` if the key with the given usage ID is pressed.
+    pub fn pressed(&self, key: u8) -> bool {
+        self.keys().any(|pressed| pressed == key)
+    }
+}
+
+impl ToBytes for BootKeyboardReport {
+    fn size(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// Output report of a boot keyboard: the state of its LEDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BootKeyboardLeds(pub u8);
+
+impl BootKeyboardLeds {
+    /// Size of the report in bytes.
+    pub const LENGTH: usize = 1;
+
+    /// Num Lock LED.
+    pub const NUM_LOCK: BootKeyboardLeds = BootKeyboardLeds(1 << 0);
+
+    /// Caps Lock LED.
+    pub const CAPS_LOCK: BootKeyboardLeds = BootKeyboardLeds(1 << 1);
+
+    /// Scroll Lock LED.
+    pub const SCROLL_LOCK: BootKeyboardLeds = BootKeyboardLeds(1 << 2);
+
+    /// Compose LED.
+    pub const COMPOSE: BootKeyboardLeds = BootKeyboardLeds(1 << 3);
+
+    /// Kana LED.
+    pub const KANA: BootKeyboardLeds = BootKeyboardLeds(1 << 4);
+
+    /// Parses a report from the start of the given buffer.
+    /// The padding bits are ignored.
+    pub fn parse(bytes: &[u8]) -> Result<BootKeyboardLeds, USBParseError> {
+        match bytes.first() {
+            Some(byte) => Ok( BootKeyboardLeds(byte & 0x1F) ),
+            _ => Err( USBParseError::BufferTooShort(0) ),
+        }
+    }
+
+    /// Encodes the report.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH] {
+        [self.0 & 0x1F]
+    }
+
+    /// Returns `true` if all the given LEDs are on.
+    pub const fn contains(&self, other: BootKeyboardLeds) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Turns the given LEDs on.
+    pub const fn with(self, other: BootKeyboardLeds) -> BootKeyboardLeds {
+        BootKeyboardLeds(self.0 | other.0)
+    }
+}
+
+impl ToBytes for BootKeyboardLeds {
+    fn size(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
+
+
+
+/// Input report of a boot mouse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BootMouseReport {
+    /// Pressed buttons, button 1 in bit 0.
+    buttons: u8,
+
+    /// Relative movement along X.
+    x: i8,
+
+    /// Relative movement along Y.
+    y: i8,
+}
+
+impl BootMouseReport {
+    /// Size of the report in bytes.
+    pub const LENGTH: usize = 3;
+
+    /// Canonical report descriptor of boot mice.
+    pub const DESCRIPTOR: [u8; 50] = [
+        0x05, 0x01,       // Usage Page (Generic Desktop)
+        0x09, 0x02,       // Usage (Mouse)
+        0xA1, 0x01,       // Collection (Application)
+        0x09, 0x01,       //   Usage (Pointer)
+        0xA1, 0x00,       //   Collection (Physical)
+        0x05, 0x09,       //     Usage Page (Button)
+        0x19, 0x01,       //     Usage Minimum (1)
+        0x29, 0x03,       //     Usage Maximum (3)
+        0x15, 0x00,       //     Logical Minimum (0)
+        0x25, 0x01,       //     Logical Maximum (1)
+        0x95, 0x03,       //     Report Count (3)
+        0x75, 0x01,       //     Report Size (1)
+        0x81, 0x02,       //     Input (Data, Variable, Absolute): buttons
+        0x95, 0x01,       //     Report Count (1)
+        0x75, 0x05,       //     Report Size (5)
+        0x81, 0x01,       //     Input (Constant): padding
+        0x05, 0x01,       //     Usage Page (Generic Desktop)
+        0x09, 0x30,       //     Usage (X)
+        0x09, 0x31,       //     Usage (Y)
+        0x15, 0x81,       //     Logical Minimum (-127)
+        0x25, 0x7F,       //     Logical Maximum (127)
+        0x75, 0x08,       //     Report Size (8)
+        0x95, 0x02,       //     Report Count (2)
+        0x81, 0x06,       //     Input (Data, Variable, Relative): X, Y
+        0xC0,             //   End Collection
+        0xC0,             // End Collection
+    ];
+
+    /// Creates a report.
+    pub const fn new(buttons: u8, x: i8, y: i8) -> BootMouseReport {
+        BootMouseReport { buttons, x, y }
+    }
+
+    /// Parses a report from the start of the given buffer.
+    /// Bytes past the boot report, such as a wheel, are ignored.
+    pub fn parse(bytes: &[u8]) -> Result<BootMouseReport, USBParseError> {
+        if bytes.len() < Self::LENGTH {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        Ok( BootMouseReport::new(bytes[0], bytes[1] as i8, bytes[2] as i8) )
+    }
+
+    /// Encodes the report.
+    pub const fn to_array(&self) -> [u8; Self::LENGTH] {
+        [self.buttons, self.x as u8, self.y as u8]
+    }
+
+    /// Raw button bits, button 1 in bit 0.
+    pub const fn buttons(&self) -> u8 {
+        self.buttons
+    }
+
+    /// Returns `true` if the given button (1 to 8) is pressed.
+    pub const fn button(&self, number: u8) -> bool {
+        match number {
+            1..=8 => (self.buttons & (1 << (number - 1))) != 0,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the left button (button 1) is pressed.
+    pub const fn left(&self) -> bool {
+        self.button(1)
+    }
+
+    /// Returns `true` if the right button (button 2) is pressed.
+    pub const fn right(&self) -> bool {
+        self.button(2)
+    }
+
+    /// Returns `true` if the middle button (button 3) is pressed.
+    pub const fn middle(&self) -> bool {
+        self.button(3)
+    }
+
+    /// Relative movement along X.
+    pub const fn x(&self) -> i8 {
+        self.x
+    }
+
+    /// Relative movement along Y.
+    pub const fn y(&self) -> i8 {
+        self.y
+    }
+}
+
+impl ToBytes for BootMouseReport {
+    fn size(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.to_array()[..].to_bytes(buffer)
+    }
+}
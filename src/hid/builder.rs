@@ -0,0 +1,630 @@
+//! HID report descriptor builder.
+//! Compile-time encoding of report descriptors from a tree of items, and the
+//! `hid_report!` macro declaring that tree.
+//! Documentation: Sections 5.3 to 6.2.2.8 of the HID 1.11 specification.
+
+
+
+use super::{ CollectionType, GlobalItem, LocalItem, MainItem, TypedItem, STACK_DEPTH };
+
+
+
+/// Declares a report descriptor and encodes it at compile time into a
+/// `[u8; N]`.
+///
+/// Items are written as `name(value)` separated by commas, and collections
+/// as `collection(Type) { ... }`, which closes them with an End Collection
+/// item. Usage pages, usages, collection types and data flags may be given
+/// by their names in `hid::page`, `hid::usage`, `CollectionType` and
+/// `hid::flag`, or as expressions. Constants must then be written as paths
+/// or in braces, as a single identifier is looked up as a name.
+///
+/// The items supported are `usage_page`, `logical_minimum`,
+/// `logical_maximum`, `physical_minimum`, `physical_maximum`,
+/// `unit_exponent`, `unit`, `report_size`, `report_id`, `report_count`,
+/// `push`, `pop`, `usage`, `usage_minimum`, `usage_maximum`,
+/// `designator_index`, `designator_minimum`, `designator_maximum`,
+/// `string_index`, `string_minimum`, `string_maximum`, `delimiter`,
+/// `input`, `output`, `feature` and `collection`.
+///
+/// The descriptor is checked by `ReportBuilder`, and invalid descriptors
+/// fail compilation. `hid_report!(@nodes ...)` gives the tree of
+/// `ReportNode`s instead of its encoding.
+///
+/// Unknown items fail compilation with an error naming them:
+///
+/// ```compile_fail
+/// const REPORT: [u8; 2] = usbdescriptor::hid_report!(usage_pag(GenericDesktop));
+/// ```
+///
+/// Input starting with `@`, other than `@nodes`, is reserved for the
+/// internal rules of the macro and is rejected:
+///
+/// ```compile_fail
+/// const REPORT: [u8; 2] = usbdescriptor::hid_report!(@items usage_page(GenericDesktop));
+/// ```
+#[macro_export]
+macro_rules! hid_report {
+    (@nodes $( $name:ident ( $($args:tt)* ) $({ $($inner:tt)* })? ),* $(,)?) => {
+        [ $( $crate::hid_report!(@node $name ( $($args)* ) $({ $($inner)* })?) ),* ]
+    };
+
+    (@node collection ( $kind:ident ) { $($inner:tt)* }) => {
+        $crate::hid::ReportNode::Collection(
+            $crate::hid::CollectionType::$kind,
+            &$crate::hid_report!(@nodes $($inner)*),
+        )
+    };
+
+    (@node collection ( $kind:expr ) { $($inner:tt)* }) => {
+        $crate::hid::ReportNode::Collection(
+            $crate::hid::CollectionType::decode($kind),
+            &$crate::hid_report!(@nodes $($inner)*),
+        )
+    };
+
+    (@node input ( $($flags:tt)* )) => {
+        $crate::hid_report!(@main Input $($flags)*)
+    };
+
+    (@node output ( $($flags:tt)* )) => {
+        $crate::hid_report!(@main Output $($flags)*)
+    };
+
+    (@node feature ( $($flags:tt)* )) => {
+        $crate::hid_report!(@main Feature $($flags)*)
+    };
+
+    (@node usage_page ( $page:ident )) => {
+        $crate::hid_report!(@global UsagePage $crate::hid::page::$page)
+    };
+
+    (@node usage_page ( $page:expr )) => {
+        $crate::hid_report!(@global UsagePage $page)
+    };
+
+    (@node logical_minimum ( $value:expr )) => {
+        $crate::hid_report!(@global LogicalMinimum $value)
+    };
+
+    (@node logical_maximum ( $value:expr )) => {
+        $crate::hid_report!(@global LogicalMaximum $value)
+    };
+
+    (@node physical_minimum ( $value:expr )) => {
+        $crate::hid_report!(@global PhysicalMinimum $value)
+    };
+
+    (@node physical_maximum ( $value:expr )) => {
+        $crate::hid_report!(@global PhysicalMaximum $value)
+    };
+
+    (@node unit_exponent ( $value:expr )) => {
+        $crate::hid_report!(@global UnitExponent $value)
+    };
+
+    (@node unit ( $value:expr )) => {
+        $crate::hid_report!(@global Unit $value)
+    };
+
+    (@node report_size ( $value:expr )) => {
+        $crate::hid_report!(@global ReportSize $value)
+    };
+
+    (@node report_id ( $value:expr )) => {
+        $crate::hid_report!(@global ReportId $value)
+    };
+
+    (@node report_count ( $value:expr )) => {
+        $crate::hid_report!(@global ReportCount $value)
+    };
+
+    (@node push ()) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Global($crate::hid::GlobalItem::Push) )
+    };
+
+    (@node pop ()) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Global($crate::hid::GlobalItem::Pop) )
+    };
+
+    (@node usage ( $usage:ident )) => {
+        $crate::hid_report!(@local Usage $crate::hid::usage::$usage)
+    };
+
+    (@node usage ( $usage:expr )) => {
+        $crate::hid_report!(@local Usage $usage)
+    };
+
+    (@node usage_minimum ( $usage:ident )) => {
+        $crate::hid_report!(@local UsageMinimum $crate::hid::usage::$usage)
+    };
+
+    (@node usage_minimum ( $usage:expr )) => {
+        $crate::hid_report!(@local UsageMinimum $usage)
+    };
+
+    (@node usage_maximum ( $usage:ident )) => {
+        $crate::hid_report!(@local UsageMaximum $crate::hid::usage::$usage)
+    };
+
+    (@node usage_maximum ( $usage:expr )) => {
+        $crate::hid_report!(@local UsageMaximum $usage)
+    };
+
+    (@node designator_index ( $value:expr )) => {
+        $crate::hid_report!(@local DesignatorIndex $value)
+    };
+
+    (@node designator_minimum ( $value:expr )) => {
+        $crate::hid_report!(@local DesignatorMinimum $value)
+    };
+
+    (@node designator_maximum ( $value:expr )) => {
+        $crate::hid_report!(@local DesignatorMaximum $value)
+    };
+
+    (@node string_index ( $value:expr )) => {
+        $crate::hid_report!(@local StringIndex $value)
+    };
+
+    (@node string_minimum ( $value:expr )) => {
+        $crate::hid_report!(@local StringMinimum $value)
+    };
+
+    (@node string_maximum ( $value:expr )) => {
+        $crate::hid_report!(@local StringMaximum $value)
+    };
+
+    (@node delimiter ( $value:expr )) => {
+        $crate::hid_report!(@local Delimiter $value)
+    };
+
+    (@main $item:ident $($flag:ident),* $(,)?) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Main(
+            $crate::hid::MainItem::$item( $crate::hid::MainFlags(0 $(| $crate::hid::flag::$flag)*) )
+        ) )
+    };
+
+    (@main $item:ident $flags:expr) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Main(
+            $crate::hid::MainItem::$item( $crate::hid::MainFlags($flags) )
+        ) )
+    };
+
+    (@global $item:ident $value:expr) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Global($crate::hid::GlobalItem::$item($value)) )
+    };
+
+    (@local $item:ident $value:expr) => {
+        $crate::hid::ReportNode::Item( $crate::hid::TypedItem::Local($crate::hid::LocalItem::$item($value)) )
+    };
+
+    (@node $name:ident $($rest:tt)*) => {
+        compile_error!(concat!("unknown hid_report! item `", stringify!($name), "`"))
+    };
+
+    (@ $($rest:tt)*) => {
+        compile_error!("hid_report! items must be written as `name(value)` or `collection(Type) { ... }`")
+    };
+
+    ($($body:tt)*) => {{
+        const NODES: &[$crate::hid::ReportNode<'static>] = &$crate::hid_report!(@nodes $($body)*);
+        const SIZE: usize = $crate::hid::ReportBuilder::<0>::new().nodes(NODES).size();
+        const BYTES: [u8; SIZE] = $crate::hid::ReportBuilder::<SIZE>::new().nodes(NODES).build();
+
+        BYTES
+    }};
+}
+
+
+
+/// A node of a report descriptor tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportNode<'a> {
+    /// A single item.
+    Item(TypedItem),
+
+    /// A collection and its nodes, closed by an End Collection item.
+    Collection(CollectionType, &'a [ReportNode<'a>]),
+}
+
+impl<'a> ReportNode<'a> {
+    /// Items of the given nodes, in descriptor order.
+    /// These are the items decoded from the built descriptor.
+    pub const fn items(nodes: &'a [ReportNode<'a>]) -> NodeItems<'a> {
+        NodeItems { nodes, index: 0 }
+    }
+}
+
+/// Iterator over the items of a tree of nodes.
+#[derive(Clone, Copy, Debug)]
+pub struct NodeItems<'a> {
+    /// The tree of nodes.
+    nodes: &'a [ReportNode<'a>],
+
+    /// Index of the next item.
+    index: usize,
+}
+
+impl<'a> Iterator for NodeItems<'a> {
+    type Item = TypedItem;
+
+    fn next(&mut self) -> Option<TypedItem> {
+        let item = locate(self.nodes, self.index).ok()?;
+
+        self.index += 1;
+
+        Some(item)
+    }
+}
+
+/// Finds the item at the given index of a tree of nodes.
+/// Returns the index left after the nodes if they hold fewer items.
+fn locate(nodes: &[ReportNode], mut index: usize) -> Result<TypedItem, usize> {
+    for node in nodes {
+        match node {
+            ReportNode::Item(item) => match index {
+                0 => return Ok( *item ),
+                _ => index -= 1,
+            },
+
+            ReportNode::Collection(kind, children) => {
+                if index == 0 {
+                    return Ok( TypedItem::Main( MainItem::Collection(*kind) ) );
+                }
+
+                index = match locate(children, index - 1) {
+                    Ok(item) => return Ok(item),
+                    Err(index) => index,
+                };
+
+                match index {
+                    0 => return Ok( TypedItem::Main(MainItem::EndCollection) ),
+                    _ => index -= 1,
+                }
+            },
+        }
+    }
+
+    Err( index )
+}
+
+
+
+/// Compile-time builder of a report descriptor of `N` bytes.
+/// Items are encoded with the smallest data size holding their value. Data
+/// fields must have a report size and count and a valid logical range,
+/// usage ranges and delimiters must be complete, and collections and the
+/// global state stack must balance. Invalid items make the builder panic,
+/// which fails compilation when evaluated in a `const` or `static`
+/// initializer.
+#[derive(Clone, Copy, Debug)]
+pub struct ReportBuilder<const N: usize> {
+    /// Descriptor being built.
+    bytes: [u8; N],
+
+    /// Number of bytes encoded so far, including those past the array.
+    size: usize,
+
+    /// Current global state.
+    global: BuilderState,
+
+    /// Global states saved by Push items.
+    stack: [BuilderState; STACK_DEPTH],
+
+    /// Number of saved global states.
+    depth: usize,
+
+    /// Number of open collections.
+    collections: usize,
+
+    /// Number of Usage Minimum and Usage Maximum items of the next Main item.
+    ranges: (usize, usize),
+
+    /// Whether a set of alternative usages is open.
+    delimiter: bool,
+}
+
+/// Global state checked by the builder.
+#[derive(Clone, Copy, Debug)]
+struct BuilderState {
+    /// Logical minimum and maximum.
+    logical: (i32, i32),
+
+    /// Size of the fields in bits.
+    report_size: u32,
+
+    /// Number of fields.
+    report_count: u32,
+}
+
+impl BuilderState {
+    /// State at the start of a descriptor.
+    const fn new() -> BuilderState {
+        BuilderState { logical: (0, 0), report_size: 0, report_count: 0 }
+    }
+}
+
+impl<const N: usize> ReportBuilder<N> {
+    /// Starts an empty report descriptor.
+    /// Bytes past `N` are counted but dropped, so that a builder of 0 bytes
+    /// measures the descriptor.
+    pub const fn new() -> ReportBuilder<N> {
+        ReportBuilder {
+            bytes: [0u8; N],
+            size: 0,
+            global: BuilderState::new(),
+            stack: [BuilderState::new(); STACK_DEPTH],
+            depth: 0,
+            collections: 0,
+            ranges: (0, 0),
+            delimiter: false,
+        }
+    }
+
+    /// Appends the items of a tree of nodes.
+    pub const fn nodes(mut self, nodes: &[ReportNode]) -> Self {
+        let mut i = 0;
+
+        while i < nodes.len() {
+            self = match nodes[i] {
+                ReportNode::Item(item) => self.item(item),
+
+                ReportNode::Collection(kind, children) => self
+                    .main( MainItem::Collection(kind) )
+                    .nodes(children)
+                    .main( MainItem::EndCollection ),
+            };
+
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Appends an item.
+    pub const fn item(self, item: TypedItem) -> Self {
+        match item {
+            TypedItem::Main(item) => self.main(item),
+            TypedItem::Global(item) => self.global(item),
+            TypedItem::Local(item) => self.local(item),
+        }
+    }
+
+    /// Appends a Main item.
+    pub const fn main(mut self, item: MainItem) -> Self {
+        match item {
+            MainItem::Input(flags) => self.field(0x80, flags.0),
+            MainItem::Output(flags) => self.field(0x90, flags.0),
+            MainItem::Feature(flags) => self.field(0xB0, flags.0),
+
+            MainItem::Collection(kind) => {
+                self = self.close_locals();
+                self.collections += 1;
+                self.short(0xA0, kind.code() as u32, 1)
+            },
+
+            MainItem::EndCollection => {
+                assert!(self.collections != 0, "End Collection without an open collection");
+
+                self = self.close_locals();
+                self.collections -= 1;
+                self.short(0xC0, 0, 0)
+            },
+
+            MainItem::Other(tag) => {
+                assert!(tag <= 0x0F, "item tags are 4 bits long");
+
+                self.close_locals().short(tag << 4, 0, 0)
+            },
+        }
+    }
+
+    /// Appends a Global item.
+    pub const fn global(mut self, item: GlobalItem) -> Self {
+        match item {
+            GlobalItem::UsagePage(page) => self.unsigned(0x04, page as u32),
+
+            GlobalItem::LogicalMinimum(value) => {
+                self.global.logical.0 = value;
+                self.signed(0x14, value)
+            },
+
+            GlobalItem::LogicalMaximum(value) => {
+                self.global.logical.1 = value;
+                self.signed(0x24, value)
+            },
+
+            GlobalItem::PhysicalMinimum(value) => self.signed(0x34, value),
+            GlobalItem::PhysicalMaximum(value) => self.signed(0x44, value),
+
+            GlobalItem::UnitExponent(exponent) => {
+                assert!((exponent >= -8) && (exponent <= 7), "the unit exponent does not fit in 4 bits");
+
+                self.short(0x54, (exponent as u32) & 0x0F, 1)
+            },
+
+            GlobalItem::Unit(unit) => self.unsigned(0x64, unit),
+
+            GlobalItem::ReportSize(size) => {
+                self.global.report_size = size;
+                self.unsigned(0x74, size)
+            },
+
+            GlobalItem::ReportId(id) => {
+                assert!(id != 0, "report ID 0 is reserved");
+
+                self.short(0x84, id as u32, 1)
+            },
+
+            GlobalItem::ReportCount(count) => {
+                self.global.report_count = count;
+                self.unsigned(0x94, count)
+            },
+
+            GlobalItem::Push => {
+                assert!(self.depth < STACK_DEPTH, "Push exceeds the depth of the global state stack");
+
+                self.stack[self.depth] = self.global;
+                self.depth += 1;
+                self.short(0xA4, 0, 0)
+            },
+
+            GlobalItem::Pop => {
+                assert!(self.depth != 0, "Pop without a saved global state");
+
+                self.depth -= 1;
+                self.global = self.stack[self.depth];
+                self.short(0xB4, 0, 0)
+            },
+
+            GlobalItem::Other(tag) => {
+                assert!(tag <= 0x0F, "item tags are 4 bits long");
+
+                self.short(0x04 | (tag << 4), 0, 0)
+            },
+        }
+    }
+
+    /// Appends a Local item.
+    /// Usages above FFFFh are extended usages holding their usage page.
+    pub const fn local(mut self, item: LocalItem) -> Self {
+        match item {
+            LocalItem::Usage(usage) => self.unsigned(0x08, usage),
+
+            LocalItem::UsageMinimum(usage) => {
+                self.ranges.0 += 1;
+                self.unsigned(0x18, usage)
+            },
+
+            LocalItem::UsageMaximum(usage) => {
+                self.ranges.1 += 1;
+                self.unsigned(0x28, usage)
+            },
+
+            LocalItem::DesignatorIndex(index) => self.unsigned(0x38, index),
+            LocalItem::DesignatorMinimum(index) => self.unsigned(0x48, index),
+            LocalItem::DesignatorMaximum(index) => self.unsigned(0x58, index),
+            LocalItem::StringIndex(index) => self.unsigned(0x78, index),
+            LocalItem::StringMinimum(index) => self.unsigned(0x88, index),
+            LocalItem::StringMaximum(index) => self.unsigned(0x98, index),
+
+            LocalItem::Delimiter(1) => {
+                assert!(!self.delimiter, "delimiters cannot be nested");
+
+                self.delimiter = true;
+                self.short(0xA8, 1, 1)
+            },
+
+            LocalItem::Delimiter(0) => {
+                assert!(self.delimiter, "closing delimiter without an open delimiter");
+
+                self.delimiter = false;
+                self.short(0xA8, 0, 1)
+            },
+
+            LocalItem::Delimiter(_) => panic!("a delimiter is either open (1) or close (0)"),
+
+            LocalItem::Other(tag) => {
+                assert!(tag <= 0x0F, "item tags are 4 bits long");
+
+                self.short(0x08 | (tag << 4), 0, 0)
+            },
+        }
+    }
+
+    /// Number of bytes encoded so far.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Encodes the report descriptor.
+    /// Panics if collections or delimiters are left open, or if the items
+    /// do not fill the array exactly.
+    pub const fn build(self) -> [u8; N] {
+        assert!(self.collections == 0, "the report descriptor has unclosed collections");
+        assert!(!self.delimiter, "the report descriptor has an unclosed delimiter");
+        assert!(self.size == N, "the report descriptor does not fill the array");
+
+        self.bytes
+    }
+
+    /// Appends an Input, Output or Feature item.
+    const fn field(self, prefix: u8, flags: u32) -> Self {
+        let (minimum, maximum) = self.global.logical;
+
+        assert!(self.global.report_size != 0, "a data field requires a nonzero Report Size");
+        assert!(self.global.report_count != 0, "a data field requires a nonzero Report Count");
+        assert!((minimum <= maximum) || ((minimum >= 0) && (maximum < 0)), "the logical minimum is greater than the logical maximum");
+
+        self.close_locals().unsigned(prefix, flags)
+    }
+
+    /// Checks and clears the local state before a Main item.
+    const fn close_locals(mut self) -> Self {
+        assert!(self.ranges.0 == self.ranges.1, "Usage Minimum and Usage Maximum items must be paired");
+        assert!(!self.delimiter, "a Main item cannot follow an open delimiter");
+
+        self.ranges = (0, 0);
+        self
+    }
+
+    /// Appends an item with the smallest unsigned data holding the value.
+    const fn unsigned(self, prefix: u8, value: u32) -> Self {
+        let len = match value {
+            0..=0xFF => 1,
+            0x100..=0xFFFF => 2,
+            _ => 4,
+        };
+
+        self.short(prefix, value, len)
+    }
+
+    /// Appends an item with the smallest signed data holding the value.
+    const fn signed(self, prefix: u8, value: i32) -> Self {
+        let len = match value {
+            -0x80..=0x7F => 1,
+            -0x8000..=0x7FFF => 2,
+            _ => 4,
+        };
+
+        self.short(prefix, value as u32, len)
+    }
+
+    /// Appends a short item with the given prefix and data of 0, 1, 2 or 4
+    /// bytes.
+    const fn short(mut self, prefix: u8, value: u32, len: usize) -> Self {
+        let code = match len {
+            4 => 3,
+            n => n as u8,
+        };
+
+        self = self.append(prefix | code);
+
+        let data = value.to_le_bytes();
+        let mut i = 0;
+
+        while i < len {
+            self = self.append(data[i]);
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Appends a byte, dropping it past the end of the array.
+    const fn append(mut self, byte: u8) -> Self {
+        if self.size < N {
+            self.bytes[self.size] = byte;
+        }
+
+        self.size += 1;
+        self
+    }
+}
+
+impl<const N: usize> Default for ReportBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
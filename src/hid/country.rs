@@ -0,0 +1,234 @@
+//! HID country codes.
+//! The country of localized hardware reported in `bCountryCode`.
+//! Documentation: Section 6.2.1 of the HID 1.11 specification.
+
+
+
+use crate::error::*;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CountryCode {
+    /// Not supported.
+    NotSupported,
+
+    /// Arabic.
+    Arabic,
+
+    /// Belgian.
+    Belgian,
+
+    /// Canadian (bilingual).
+    CanadianBilingual,
+
+    /// Canadian (French).
+    CanadianFrench,
+
+    /// Czech Republic.
+    CzechRepublic,
+
+    /// Danish.
+    Danish,
+
+    /// Finnish.
+    Finnish,
+
+    /// French.
+    French,
+
+    /// German.
+    German,
+
+    /// Greek.
+    Greek,
+
+    /// Hebrew.
+    Hebrew,
+
+    /// Hungary.
+    Hungary,
+
+    /// International (ISO).
+    International,
+
+    /// Italian.
+    Italian,
+
+    /// Japan (Katakana).
+    JapanKatakana,
+
+    /// Korean.
+    Korean,
+
+    /// Latin American.
+    LatinAmerican,
+
+    /// Netherlands (Dutch).
+    Dutch,
+
+    /// Norwegian.
+    Norwegian,
+
+    /// Persian (Farsi).
+    Persian,
+
+    /// Poland.
+    Poland,
+
+    /// Portuguese.
+    Portuguese,
+
+    /// Russia.
+    Russia,
+
+    /// Slovakia.
+    Slovakia,
+
+    /// Spanish.
+    Spanish,
+
+    /// Swedish.
+    Swedish,
+
+    /// Swiss (French).
+    SwissFrench,
+
+    /// Swiss (German).
+    SwissGerman,
+
+    /// Switzerland.
+    Switzerland,
+
+    /// Taiwan.
+    Taiwan,
+
+    /// Turkish-Q.
+    TurkishQ,
+
+    /// UK.
+    UK,
+
+    /// US.
+    US,
+
+    /// Yugoslavia.
+    Yugoslavia,
+
+    /// Turkish-F.
+    TurkishF,
+
+    /// Reserved country code, kept as is.
+    Other(u8),
+}
+
+impl CountryCode {
+    /// Decodes a country code without failing.
+    /// Reserved codes are kept in the `Other` variant so they convert back
+    /// to the same byte. Use `TryFrom<u8>` to reject them instead.
+    pub fn decode(code: u8) -> CountryCode {
+        CountryCode::try_from(code).unwrap_or(CountryCode::Other(code))
+    }
+
+    /// Raw country code.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            CountryCode::NotSupported      => 0,
+            CountryCode::Arabic            => 1,
+            CountryCode::Belgian           => 2,
+            CountryCode::CanadianBilingual => 3,
+            CountryCode::CanadianFrench    => 4,
+            CountryCode::CzechRepublic     => 5,
+            CountryCode::Danish            => 6,
+            CountryCode::Finnish           => 7,
+            CountryCode::French            => 8,
+            CountryCode::German            => 9,
+            CountryCode::Greek             => 10,
+            CountryCode::Hebrew            => 11,
+            CountryCode::Hungary           => 12,
+            CountryCode::International     => 13,
+            CountryCode::Italian           => 14,
+            CountryCode::JapanKatakana     => 15,
+            CountryCode::Korean            => 16,
+            CountryCode::LatinAmerican     => 17,
+            CountryCode::Dutch             => 18,
+            CountryCode::Norwegian         => 19,
+            CountryCode::Persian           => 20,
+            CountryCode::Poland            => 21,
+            CountryCode::Portuguese        => 22,
+            CountryCode::Russia            => 23,
+            CountryCode::Slovakia          => 24,
+            CountryCode::Spanish           => 25,
+            CountryCode::Swedish           => 26,
+            CountryCode::SwissFrench       => 27,
+            CountryCode::SwissGerman       => 28,
+            CountryCode::Switzerland       => 29,
+            CountryCode::Taiwan            => 30,
+            CountryCode::TurkishQ          => 31,
+            CountryCode::UK                => 32,
+            CountryCode::US                => 33,
+            CountryCode::Yugoslavia        => 34,
+            CountryCode::TurkishF          => 35,
+
+            CountryCode::Other(code) => code,
+        }
+    }
+
+    /// Returns `true` if the hardware is localized.
+    pub const fn localized(&self) -> bool {
+        !matches!(*self, CountryCode::NotSupported)
+    }
+}
+
+impl core::convert::TryFrom<u8> for CountryCode {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<CountryCode, USBParseError> {
+        match code {
+             0 => Ok( CountryCode::NotSupported ),
+             1 => Ok( CountryCode::Arabic ),
+             2 => Ok( CountryCode::Belgian ),
+             3 => Ok( CountryCode::CanadianBilingual ),
+             4 => Ok( CountryCode::CanadianFrench ),
+             5 => Ok( CountryCode::CzechRepublic ),
+             6 => Ok( CountryCode::Danish ),
+             7 => Ok( CountryCode::Finnish ),
+             8 => Ok( CountryCode::French ),
+             9 => Ok( CountryCode::German ),
+            10 => Ok( CountryCode::Greek ),
+            11 => Ok( CountryCode::Hebrew ),
+            12 => Ok( CountryCode::Hungary ),
+            13 => Ok( CountryCode::International ),
+            14 => Ok( CountryCode::Italian ),
+            15 => Ok( CountryCode::JapanKatakana ),
+            16 => Ok( CountryCode::Korean ),
+            17 => Ok( CountryCode::LatinAmerican ),
+            18 => Ok( CountryCode::Dutch ),
+            19 => Ok( CountryCode::Norwegian ),
+            20 => Ok( CountryCode::Persian ),
+            21 => Ok( CountryCode::Poland ),
+            22 => Ok( CountryCode::Portuguese ),
+            23 => Ok( CountryCode::Russia ),
+            24 => Ok( CountryCode::Slovakia ),
+            25 => Ok( CountryCode::Spanish ),
+            26 => Ok( CountryCode::Swedish ),
+            27 => Ok( CountryCode::SwissFrench ),
+            28 => Ok( CountryCode::SwissGerman ),
+            29 => Ok( CountryCode::Switzerland ),
+            30 => Ok( CountryCode::Taiwan ),
+            31 => Ok( CountryCode::TurkishQ ),
+            32 => Ok( CountryCode::UK ),
+            33 => Ok( CountryCode::US ),
+            34 => Ok( CountryCode::Yugoslavia ),
+            35 => Ok( CountryCode::TurkishF ),
+
+            _ => Err( USBParseError::UnknownCountry(code) ),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for CountryCode {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
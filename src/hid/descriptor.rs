@@ -0,0 +1,136 @@
+//! HID descriptor.
+//! A structure representing the HID class descriptor, which follows the
+//! interface descriptor of HID interfaces and lists the report and physical
+//! descriptors of the interface.
+//! Documentation: Section 6.2.1 of the HID 1.11 specification.
+
+
+
+use crate::descriptor::{ BCDVersion, DescriptorType, ToBytes, read_u16 };
+use crate::error::*;
+use super::CountryCode;
+
+
+
+/// A HID descriptor borrowed from a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HIDDescriptor<'a> {
+    /// The whole descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> HIDDescriptor<'a> {
+    /// Size of this descriptor in bytes, with a single class descriptor
+    /// entry.
+    pub const LENGTH: u8 = 9;
+
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::HID.code();
+
+    /// Parses a HID descriptor from the start of the given buffer.
+    /// The descriptor must list at least one class descriptor, and be long
+    /// enough to hold all of them.
+    pub fn parse(bytes: &'a [u8]) -> Result<HIDDescriptor<'a>, USBParseError> {
+        if bytes.len() < Self::LENGTH as usize {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        if bytes[1] != Self::TYPE {
+            return Err( USBParseError::InvalidDescriptorType(bytes[1]) );
+        }
+
+        let length = bytes[0] as usize;
+
+        if (bytes[5] == 0) || (length < 6 + 3 * bytes[5] as usize) {
+            return Err( USBParseError::InvalidLength(bytes[0]) );
+        }
+
+        if bytes.len() < length {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        Ok( HIDDescriptor { bytes: &bytes[..length] } )
+    }
+
+    /// HID Specification release number.
+    pub const fn version(&self) -> BCDVersion {
+        BCDVersion::from_raw( read_u16(self.bytes, 2) )
+    }
+
+    /// Raw country code.
+    pub const fn country_code(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    /// Country of localized hardware.
+    /// Reserved codes are kept in `CountryCode::Other`.
+    pub fn country(&self) -> CountryCode {
+        CountryCode::decode( self.country_code() )
+    }
+
+    /// Number of class descriptors.
+    pub const fn num_descriptors(&self) -> u8 {
+        self.bytes[5]
+    }
+
+    /// Class descriptors of the interface.
+    pub fn descriptors(&self) -> ClassDescriptors<'a> {
+        let count = self.num_descriptors() as usize;
+
+        ClassDescriptors { bytes: &self.bytes[6..6 + 3 * count] }
+    }
+
+    /// Length of the report descriptor, to request with
+    /// `GET_DESCRIPTOR(Report)`.
+    pub fn report_length(&self) -> Option<u16> {
+        self.descriptors()
+            .find(|d| d.descriptor_type == DescriptorType::Report)
+            .map(|d| d.length)
+    }
+}
+
+impl<'a> ToBytes for HIDDescriptor<'a> {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.bytes.to_bytes(buffer)
+    }
+}
+
+/// Iterator over the class descriptors listed by a HID descriptor.
+#[derive(Clone, Debug)]
+pub struct ClassDescriptors<'a> {
+    /// Entries not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for ClassDescriptors<'a> {
+    type Item = ClassDescriptor;
+
+    fn next(&mut self) -> Option<ClassDescriptor> {
+        if self.bytes.len() < 3 {
+            return None;
+        }
+
+        let entry = ClassDescriptor {
+            descriptor_type: DescriptorType::decode(self.bytes[0]),
+            length: read_u16(self.bytes, 1),
+        };
+
+        self.bytes = &self.bytes[3..];
+
+        Some(entry)
+    }
+}
+
+/// A report or physical descriptor listed by a HID descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClassDescriptor {
+    /// Type of the descriptor.
+    pub descriptor_type: DescriptorType,
+
+    /// Total size of the descriptor.
+    pub length: u16,
+}
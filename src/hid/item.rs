@@ -0,0 +1,598 @@
+//! HID report descriptor items.
+//! Tokenization of report descriptors into short and long items, and typed
+//! decoding of the Main, Global and Local items.
+//! Documentation: Sections 5.3 to 6.2.2.8 of the HID 1.11 specification.
+
+
+
+use crate::error::*;
+
+
+
+/// Prefix of a long item.
+const LONG: u8 = 0xFE;
+
+/// Splits the first item from the given buffer.
+/// Returns the item and the remaining bytes.
+pub(crate) fn split(bytes: &[u8]) -> Result<(Item<'_>, &[u8]), USBParseError> {
+    let prefix = match bytes.first() {
+        Some(prefix) => *prefix,
+        _ => return Err( USBParseError::BufferTooShort(0) ),
+    };
+
+    if prefix == LONG {
+        if bytes.len() < 3 {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let end = 3 + bytes[1] as usize;
+
+        if bytes.len() < end {
+            return Err( USBParseError::BufferTooShort(bytes.len()) );
+        }
+
+        let item = Item::Long( LongItem { tag: bytes[2], data: &bytes[3..end] } );
+
+        return Ok( (item, &bytes[end..]) );
+    }
+
+    let end = match prefix & 0x03 {
+        3 => 5,
+        n => 1 + n as usize,
+    };
+
+    if bytes.len() < end {
+        return Err( USBParseError::BufferTooShort(bytes.len()) );
+    }
+
+    let item = Item::Short( ShortItem { prefix, data: &bytes[1..end] } );
+
+    Ok( (item, &bytes[end..]) )
+}
+
+
+
+/// An item of a report descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Item<'a> {
+    /// A short item, with up to 4 bytes of data.
+    Short(ShortItem<'a>),
+
+    /// A long item. No long item tags are defined.
+    Long(LongItem<'a>),
+}
+
+impl<'a> Item<'a> {
+    /// Size of the item in bytes, including its prefix.
+    pub const fn size(&self) -> usize {
+        match self {
+            Item::Short(item) => 1 + item.data.len(),
+            Item::Long(item) => 3 + item.data.len(),
+        }
+    }
+
+    /// Data of the item, without its prefix.
+    pub const fn data(&self) -> &'a [u8] {
+        match self {
+            Item::Short(item) => item.data,
+            Item::Long(item) => item.data,
+        }
+    }
+}
+
+
+
+/// Type of a short item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemType {
+    /// Main item: defines or groups data fields.
+    Main,
+
+    /// Global item: changes the state of all the following items.
+    Global,
+
+    /// Local item: changes the state of the next Main item.
+    Local,
+
+    /// Reserved item type.
+    Reserved,
+}
+
+
+
+/// A short item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortItem<'a> {
+    /// Item prefix: tag, type and size.
+    prefix: u8,
+
+    /// Item data, 0, 1, 2 or 4 bytes.
+    data: &'a [u8],
+}
+
+impl<'a> ShortItem<'a> {
+    /// Raw item prefix.
+    pub const fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Type of the item.
+    pub const fn item_type(&self) -> ItemType {
+        match (self.prefix >> 2) & 0x03 {
+            0 => ItemType::Main,
+            1 => ItemType::Global,
+            2 => ItemType::Local,
+            _ => ItemType::Reserved,
+        }
+    }
+
+    /// Tag of the item within its type.
+    pub const fn tag(&self) -> u8 {
+        self.prefix >> 4
+    }
+
+    /// Raw item data.
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Item data as an unsigned little-endian value.
+    pub const fn unsigned(&self) -> u32 {
+        let mut value = 0;
+        let mut i = self.data.len();
+
+        while i > 0 {
+            i -= 1;
+            value = (value << 8) | self.data[i] as u32;
+        }
+
+        value
+    }
+
+    /// Item data as a signed little-endian value.
+    pub const fn signed(&self) -> i32 {
+        let value = self.unsigned();
+
+        match self.data.len() {
+            1 => value as u8 as i8 as i32,
+            2 => value as u16 as i16 as i32,
+            _ => value as i32,
+        }
+    }
+
+    /// Decodes a Main item.
+    pub const fn main(&self) -> Option<MainItem> {
+        if !matches!(self.item_type(), ItemType::Main) {
+            return None;
+        }
+
+        let item = match self.tag() {
+            0x8 => MainItem::Input( MainFlags(self.unsigned()) ),
+            0x9 => MainItem::Output( MainFlags(self.unsigned()) ),
+            0xB => MainItem::Feature( MainFlags(self.unsigned()) ),
+            0xA => MainItem::Collection( CollectionType::decode(self.unsigned() as u8) ),
+            0xC => MainItem::EndCollection,
+
+            tag => MainItem::Other(tag),
+        };
+
+        Some(item)
+    }
+
+    /// Decodes a Global item.
+    /// Report IDs and Usage Pages are truncated to their width, items whose
+    /// data does not fit are rejected by `ReportDescriptor::parse`.
+    pub const fn global(&self) -> Option<GlobalItem> {
+        if !matches!(self.item_type(), ItemType::Global) {
+            return None;
+        }
+
+        let item = match self.tag() {
+            0x0 => GlobalItem::UsagePage( self.unsigned() as u16 ),
+            0x1 => GlobalItem::LogicalMinimum( self.signed() ),
+            0x2 => GlobalItem::LogicalMaximum( self.signed() ),
+            0x3 => GlobalItem::PhysicalMinimum( self.signed() ),
+            0x4 => GlobalItem::PhysicalMaximum( self.signed() ),
+            0x5 => GlobalItem::UnitExponent( self.exponent() ),
+            0x6 => GlobalItem::Unit( self.unsigned() ),
+            0x7 => GlobalItem::ReportSize( self.unsigned() ),
+            0x8 => GlobalItem::ReportId( self.unsigned() as u8 ),
+            0x9 => GlobalItem::ReportCount( self.unsigned() ),
+            0xA => GlobalItem::Push,
+            0xB => GlobalItem::Pop,
+
+            tag => GlobalItem::Other(tag),
+        };
+
+        Some(item)
+    }
+
+    /// Decodes a Local item.
+    pub const fn local(&self) -> Option<LocalItem> {
+        if !matches!(self.item_type(), ItemType::Local) {
+            return None;
+        }
+
+        let value = self.unsigned();
+
+        let item = match self.tag() {
+            0x0 => LocalItem::Usage(value),
+            0x1 => LocalItem::UsageMinimum(value),
+            0x2 => LocalItem::UsageMaximum(value),
+            0x3 => LocalItem::DesignatorIndex(value),
+            0x4 => LocalItem::DesignatorMinimum(value),
+            0x5 => LocalItem::DesignatorMaximum(value),
+            0x7 => LocalItem::StringIndex(value),
+            0x8 => LocalItem::StringMinimum(value),
+            0x9 => LocalItem::StringMaximum(value),
+            0xA => LocalItem::Delimiter(value),
+
+            tag => LocalItem::Other(tag),
+        };
+
+        Some(item)
+    }
+
+    /// Decodes the item according to its type.
+    /// Returns `None` for reserved item types.
+    pub const fn typed(&self) -> Option<TypedItem> {
+        match self.item_type() {
+            ItemType::Main => match self.main() {
+                Some(item) => Some( TypedItem::Main(item) ),
+                None => None,
+            },
+
+            ItemType::Global => match self.global() {
+                Some(item) => Some( TypedItem::Global(item) ),
+                None => None,
+            },
+
+            ItemType::Local => match self.local() {
+                Some(item) => Some( TypedItem::Local(item) ),
+                None => None,
+            },
+
+            ItemType::Reserved => None,
+        }
+    }
+
+    /// Returns `true` if a usage item holds its usage page in the high 16
+    /// bits of its data.
+    pub const fn extended_usage(&self) -> bool {
+        self.data.len() == 4
+    }
+
+    /// Decodes the unit exponent.
+    /// Descriptors commonly encode it as a 4 bit two's complement nibble.
+    const fn exponent(&self) -> i8 {
+        let value = self.unsigned();
+
+        match value <= 0x0F {
+            true => ((value as i8) << 4) >> 4,
+            _ => self.signed() as i8,
+        }
+    }
+}
+
+
+
+/// A long item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LongItem<'a> {
+    /// Long item tag.
+    tag: u8,
+
+    /// Item data.
+    data: &'a [u8],
+}
+
+impl<'a> LongItem<'a> {
+    /// Long item tag.
+    pub const fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// Item data.
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+
+
+/// A decoded short item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypedItem {
+    /// A Main item.
+    Main(MainItem),
+
+    /// A Global item.
+    Global(GlobalItem),
+
+    /// A Local item.
+    Local(LocalItem),
+}
+
+/// A Main item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MainItem {
+    /// Input fields, from the device to the host.
+    Input(MainFlags),
+
+    /// Output fields, from the host to the device.
+    Output(MainFlags),
+
+    /// Feature fields, read and written by control transfers.
+    Feature(MainFlags),
+
+    /// Start of a collection.
+    Collection(CollectionType),
+
+    /// End of the current collection.
+    EndCollection,
+
+    /// Reserved Main item tag, kept as is.
+    Other(u8),
+}
+
+/// A Global item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlobalItem {
+    /// Usage page of the following usages.
+    UsagePage(u16),
+
+    /// Minimum logical value of the following fields.
+    LogicalMinimum(i32),
+
+    /// Maximum logical value of the following fields.
+    LogicalMaximum(i32),
+
+    /// Minimum physical value of the following fields.
+    PhysicalMinimum(i32),
+
+    /// Maximum physical value of the following fields.
+    PhysicalMaximum(i32),
+
+    /// Base 10 exponent of the unit.
+    UnitExponent(i8),
+
+    /// Unit of the physical values.
+    Unit(u32),
+
+    /// Size of a field in bits.
+    ReportSize(u32),
+
+    /// Report ID prefixing the following reports.
+    ReportId(u8),
+
+    /// Number of fields of the next Main item.
+    ReportCount(u32),
+
+    /// Saves the global state on the stack.
+    Push,
+
+    /// Restores the global state from the stack.
+    Pop,
+
+    /// Reserved Global item tag, kept as is.
+    Other(u8),
+}
+
+/// A Local item.
+/// Usages of 4 bytes hold their usage page in the high 16 bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalItem {
+    /// Usage of the next field or collection.
+    Usage(u32),
+
+    /// First usage of a range.
+    UsageMinimum(u32),
+
+    /// Last usage of a range.
+    UsageMaximum(u32),
+
+    /// Physical descriptor of the next field.
+    DesignatorIndex(u32),
+
+    /// First physical descriptor of a range.
+    DesignatorMinimum(u32),
+
+    /// Last physical descriptor of a range.
+    DesignatorMaximum(u32),
+
+    /// String descriptor of the next field.
+    StringIndex(u32),
+
+    /// First string descriptor of a range.
+    StringMinimum(u32),
+
+    /// Last string descriptor of a range.
+    StringMaximum(u32),
+
+    /// Opens (1) or closes (0) a set of alternative usages.
+    Delimiter(u32),
+
+    /// Reserved Local item tag, kept as is.
+    Other(u8),
+}
+
+
+
+/// Data flags of an Input, Output or Feature item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MainFlags(pub u32);
+
+impl MainFlags {
+    /// Returns `true` for constant (padding) fields.
+    pub const fn constant(&self) -> bool {
+        (self.0 & (1 << 0)) != 0
+    }
+
+    /// Returns `true` for variable fields, `false` for arrays.
+    pub const fn variable(&self) -> bool {
+        (self.0 & (1 << 1)) != 0
+    }
+
+    /// Returns `true` for values relative to the previous report.
+    pub const fn relative(&self) -> bool {
+        (self.0 & (1 << 2)) != 0
+    }
+
+    /// Returns `true` if the value wraps around its extremes.
+    pub const fn wrap(&self) -> bool {
+        (self.0 & (1 << 3)) != 0
+    }
+
+    /// Returns `true` if the value is not linear with the physical value.
+    pub const fn non_linear(&self) -> bool {
+        (self.0 & (1 << 4)) != 0
+    }
+
+    /// Returns `true` if the control has no preferred state.
+    pub const fn no_preferred(&self) -> bool {
+        (self.0 & (1 << 5)) != 0
+    }
+
+    /// Returns `true` if the control has a null state outside of its
+    /// logical range.
+    pub const fn null_state(&self) -> bool {
+        (self.0 & (1 << 6)) != 0
+    }
+
+    /// Returns `true` if an output or feature value may change on its own.
+    pub const fn volatile(&self) -> bool {
+        (self.0 & (1 << 7)) != 0
+    }
+
+    /// Returns `true` if the field is a stream of bytes.
+    pub const fn buffered_bytes(&self) -> bool {
+        (self.0 & (1 << 8)) != 0
+    }
+}
+
+
+
+/// Type of a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollectionType {
+    /// Values collected at one geometric point.
+    Physical,
+
+    /// Items of a common function, such as a mouse or a keyboard.
+    Application,
+
+    /// Items forming a composite data structure.
+    Logical,
+
+    /// Fields of a single report.
+    Report,
+
+    /// An array of selector usages.
+    NamedArray,
+
+    /// A collection modifying the meaning of its usage.
+    UsageSwitch,
+
+    /// A collection modifying the meaning of its items.
+    UsageModifier,
+
+    /// Vendor defined collection type (80h to FFh), kept as is.
+    VendorDefined(u8),
+
+    /// Reserved collection type, kept as is.
+    Other(u8),
+}
+
+impl CollectionType {
+    /// Decodes a collection type without failing.
+    /// Reserved types are kept in the `Other` variant so they convert back
+    /// to the same byte. Use `TryFrom<u8>` to reject them instead.
+    pub const fn decode(code: u8) -> CollectionType {
+        match code {
+            0x00 => CollectionType::Physical,
+            0x01 => CollectionType::Application,
+            0x02 => CollectionType::Logical,
+            0x03 => CollectionType::Report,
+            0x04 => CollectionType::NamedArray,
+            0x05 => CollectionType::UsageSwitch,
+            0x06 => CollectionType::UsageModifier,
+
+            0x80..=0xFF => CollectionType::VendorDefined(code),
+
+            _ => CollectionType::Other(code),
+        }
+    }
+
+    /// Raw collection type.
+    pub const fn code(&self) -> u8 {
+        match *self {
+            CollectionType::Physical      => 0x00,
+            CollectionType::Application   => 0x01,
+            CollectionType::Logical       => 0x02,
+            CollectionType::Report        => 0x03,
+            CollectionType::NamedArray    => 0x04,
+            CollectionType::UsageSwitch   => 0x05,
+            CollectionType::UsageModifier => 0x06,
+
+            CollectionType::VendorDefined(code) => code,
+            CollectionType::Other(code) => code,
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for CollectionType {
+    type Error = USBParseError;
+
+    fn try_from(code: u8) -> Result<CollectionType, USBParseError> {
+        match CollectionType::decode(code) {
+            CollectionType::Other(code) => Err( USBParseError::UnknownCollectionType(code) ),
+            collection => Ok(collection),
+        }
+    }
+}
+
+impl core::convert::Into<u8> for CollectionType {
+    fn into(self) -> u8 {
+        self.code()
+    }
+}
+
+
+
+/// Iterator over the items of a report descriptor.
+/// Iteration stops at the first truncated item.
+#[derive(Clone, Copy, Debug)]
+pub struct Items<'a> {
+    /// Items not yet visited.
+    bytes: &'a [u8],
+}
+
+impl<'a> Items<'a> {
+    /// Creates an iterator over the given items.
+    pub const fn new(bytes: &'a [u8]) -> Items<'a> {
+        Items { bytes }
+    }
+
+    /// Returns the bytes that have not been iterated yet.
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Item<'a>> {
+        match split(self.bytes) {
+            Ok((item, rest)) => {
+                self.bytes = rest;
+                Some(item)
+            },
+
+            Err(_) => {
+                self.bytes = &[];
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! HID report layout.
+//! Bit level layout of the reports described by a report descriptor, and
+//! extraction and insertion of the values of their fields.
+//! Documentation: Sections 5.6, 5.8 and 8.4 of the HID 1.11 specification.
+
+
+
+use crate::error::*;
+use super::{ Fields, ReportDescriptor, ReportField, ReportKind, Usage };
+
+
+
+impl<'a> ReportDescriptor<'a> {
+    /// Returns `true` if the reports are prefixed by a report ID.
+    pub fn uses_report_ids(&self) -> bool {
+        self.fields().any(|field| field.report_id().is_some())
+    }
+
+    /// Layout of the report of the given kind and report ID.
+    /// The report is empty if no field belongs to it.
+    pub fn report(&self, kind: ReportKind, id: Option<u8>) -> Report<'a> {
+        Report { descriptor: *self, kind, id }
+    }
+
+    /// Layout of a raw report of the given kind.
+    /// The report is found by its report ID prefix when the descriptor uses
+    /// report IDs. Returns `None` if no field belongs to it.
+    pub fn report_of(&self, kind: ReportKind, report: &[u8]) -> Option<Report<'a>> {
+        let id = match self.uses_report_ids() {
+            true => Some( *report.first()? ),
+            _ => None,
+        };
+
+        let layout = self.report(kind, id);
+
+        layout.fields().next().map(|_| layout)
+    }
+
+    /// Layouts of all the reports of the descriptor, in order of their first
+    /// field.
+    pub fn reports(&self) -> Reports<'a> {
+        Reports { descriptor: *self, fields: self.fields(), seen: [0; SEEN_WORDS] }
+    }
+}
+
+
+
+/// Layout of a report: the fields of one kind sharing a report ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Report<'a> {
+    /// Descriptor of the report.
+    descriptor: ReportDescriptor<'a>,
+
+    /// Main item of the fields.
+    kind: ReportKind,
+
+    /// Report ID of the fields.
+    id: Option<u8>,
+}
+
+impl<'a> Report<'a> {
+    /// Kind of the report.
+    pub const fn kind(&self) -> ReportKind {
+        self.kind
+    }
+
+    /// Report ID prefixing the report, if any.
+    pub const fn id(&self) -> Option<u8> {
+        self.id
+    }
+
+    /// Size of the report in bits, including its report ID.
+    pub fn bits(&self) -> u32 {
+        let mut fields = self.fields();
+
+        while fields.next().is_some() {}
+
+        fields.offset
+    }
+
+    /// Size of the report in bytes, including its report ID.
+    pub fn size(&self) -> usize {
+        self.bits().div_ceil(8) as usize
+    }
+
+    /// Fields of the report with their positions.
+    pub fn fields(&self) -> ReportFields<'a> {
+        ReportFields {
+            fields: self.descriptor.fields(),
+            kind: self.kind,
+            id: self.id,
+            offset: if self.id.is_some() { 8 } else { 0 },
+        }
+    }
+}
+
+/// Number of report IDs, including the absence of a report ID.
+const REPORT_IDS: usize = 257;
+
+/// Size of the set of reports already yielded, one bit per kind and ID.
+const SEEN_WORDS: usize = (3 * REPORT_IDS).div_ceil(32);
+
+/// Iterator over the reports of a descriptor.
+#[derive(Clone, Debug)]
+pub struct Reports<'a> {
+    /// Descriptor of the reports.
+    descriptor: ReportDescriptor<'a>,
+
+    /// Fields not yet visited.
+    fields: Fields<'a>,
+
+    /// Reports already yielded, as a bit set of kinds and report IDs.
+    seen: [u32; SEEN_WORDS],
+}
+
+impl<'a> Iterator for Reports<'a> {
+    type Item = Report<'a>;
+
+    fn next(&mut self) -> Option<Report<'a>> {
+        loop {
+            let field = self.fields.next()?;
+
+            let kind = match field.kind() {
+                ReportKind::Input => 0,
+                ReportKind::Output => 1,
+                ReportKind::Feature => 2,
+            };
+
+            let id = match field.report_id() {
+                Some(id) => id as usize + 1,
+                None => 0,
+            };
+
+            let bit = kind * REPORT_IDS + id;
+            let (word, mask) = (bit / 32, 1 << (bit % 32));
+
+            if self.seen[word] & mask == 0 {
+                self.seen[word] |= mask;
+                return Some( self.descriptor.report(field.kind(), field.report_id()) );
+            }
+        }
+    }
+}
+
+/// Iterator over the fields of a report.
+#[derive(Clone, Debug)]
+pub struct ReportFields<'a> {
+    /// Fields of the descriptor not yet visited.
+    fields: Fields<'a>,
+
+    /// Kind of the report.
+    kind: ReportKind,
+
+    /// Report ID of the report.
+    id: Option<u8>,
+
+    /// Bit offset of the next field.
+    offset: u32,
+}
+
+impl<'a> Iterator for ReportFields<'a> {
+    type Item = FieldLayout<'a>;
+
+    fn next(&mut self) -> Option<FieldLayout<'a>> {
+        loop {
+            let field = self.fields.next()?;
+
+            if (field.kind() != self.kind) || (field.report_id() != self.id) {
+                continue;
+            }
+
+            let layout = FieldLayout { field, offset: self.offset };
+
+            self.offset = self.offset.saturating_add( layout.bit_size() );
+
+            return Some(layout);
+        }
+    }
+}
+
+
+
+/// A report field at its position in the report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldLayout<'a> {
+    /// The field.
+    field: ReportField<'a>,
+
+    /// Bit offset of the field from the start of the report, including its
+    /// report ID.
+    offset: u32,
+}
+
+impl<'a> FieldLayout<'a> {
+    /// The field, with its ranges and unit.
+    pub const fn field(&self) -> ReportField<'a> {
+        self.field
+    }
+
+    /// Bit offset of the field from the start of the report, including its
+    /// report ID.
+    pub const fn bit_offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Size of the field in bits.
+    pub const fn bit_size(&self) -> u32 {
+        self.field.report_size().saturating_mul( self.field.report_count() )
+    }
+
+    /// Bit offset of an element of the field.
+    pub const fn element_offset(&self, index: u32) -> u32 {
+        self.offset + index * self.field.report_size()
+    }
+
+    /// Returns `true` if the values of the field are signed, which is when
+    /// its logical minimum is negative.
+    pub const fn signed(&self) -> bool {
+        self.field.logical_minimum() < 0
+    }
+
+    /// Usage of an element of a variable field.
+    /// Elements past the last usage take the last usage. Returns `None` for
+    /// array fields, whose usage depends on their value.
+    pub fn usage(&self, index: u32) -> Option<Usage> {
+        if !self.field.flags().variable() {
+            return None;
+        }
+
+        let usages = self.field.usages();
+
+        match usages.clone().nth(index as usize) {
+            Some(usage) => Some(usage),
+            None => usages.last(),
+        }
+    }
+
+    /// Usage selected by a value of an array field.
+    /// Returns `None` for values outside of the logical range, which mean
+    /// that nothing is selected.
+    pub fn array_usage(&self, value: i64) -> Option<Usage> {
+        let minimum = self.field.logical_minimum() as i64;
+        let maximum = self.field.logical_maximum() as i64;
+
+        if (value < minimum) || (value > maximum) {
+            return None;
+        }
+
+        self.field.usages().nth( (value - minimum) as usize )
+    }
+
+    /// Reads an element of the field from a raw report.
+    /// Signed fields are sign extended.
+    pub fn read(&self, report: &[u8], index: u32) -> Result<i64, USBParseError> {
+        let size = self.element(index)?;
+
+        self.check(report)?;
+
+        let raw = extract(report, self.element_offset(index), size) as i64;
+
+        match self.signed() && (size > 0) {
+            true => Ok( (raw << (64 - size)) >> (64 - size) ),
+            _ => Ok( raw ),
+        }
+    }
+
+    /// Writes an element of the field into a raw report.
+    /// The other bits of the report are left untouched.
+    pub fn write(&self, report: &mut [u8], index: u32, value: i64) -> Result<(), USBSerializeError> {
+        let size = self.element(index).map_err(USBSerializeError::InvalidField)?;
+
+        let (minimum, maximum) = match (self.signed(), size) {
+            (_, 0) => (0, 0),
+            (true, n) => (-(1i64 << (n - 1)), (1i64 << (n - 1)) - 1),
+            (false, n) => (0, (1i64 << n) - 1),
+        };
+
+        if (value < minimum) || (value > maximum) {
+            return Err( USBSerializeError::ValueOutOfRange(value) );
+        }
+
+        let end = self.offset as usize + self.bit_size() as usize;
+
+        if report.len() * 8 < end {
+            return Err( USBSerializeError::BufferTooShort( end.div_ceil(8) ) );
+        }
+
+        insert(report, self.element_offset(index), size, value as u32);
+
+        Ok(())
+    }
+
+    /// Values of all the elements of the field in a raw report, with their
+    /// usages.
+    pub fn values<'r>(&self, report: &'r [u8]) -> Result<FieldValues<'a, 'r>, USBParseError> {
+        self.check(report)?;
+
+        Ok( FieldValues { layout: *self, report, index: 0 } )
+    }
+
+    /// Checks that the field has an element at this index, and returns the
+    /// size of its elements.
+    fn element(&self, index: u32) -> Result<u32, USBParseError> {
+        let size = self.field.report_size();
+
+        if size > 32 {
+            return Err( USBParseError::FieldTooWide(size) );
+        }
+
+        if index >= self.field.report_count() {
+            return Err( USBParseError::FieldIndexOutOfRange(index) );
+        }
+
+        Ok( size )
+    }
+
+    /// Checks that the report holds the field.
+    fn check(&self, report: &[u8]) -> Result<(), USBParseError> {
+        let size = self.field.report_size();
+
+        if size > 32 {
+            return Err( USBParseError::FieldTooWide(size) );
+        }
+
+        if report.len() * 8 < self.offset as usize + self.bit_size() as usize {
+            return Err( USBParseError::BufferTooShort(report.len()) );
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterator over the values of a field in a raw report.
+#[derive(Clone, Debug)]
+pub struct FieldValues<'a, 'r> {
+    /// Layout of the field.
+    layout: FieldLayout<'a>,
+
+    /// The raw report.
+    report: &'r [u8],
+
+    /// Index of the next element.
+    index: u32,
+}
+
+impl<'a, 'r> Iterator for FieldValues<'a, 'r> {
+    type Item = FieldValue;
+
+    fn next(&mut self) -> Option<FieldValue> {
+        if self.index >= self.layout.field.report_count() {
+            return None;
+        }
+
+        let value = self.layout.read(self.report, self.index).ok()?;
+
+        let usage = match self.layout.field.flags().variable() {
+            true => self.layout.usage(self.index),
+            _ => self.layout.array_usage(value),
+        };
+
+        self.index += 1;
+
+        Some( FieldValue { usage, value } )
+    }
+}
+
+/// A value of a report field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldValue {
+    /// Usage of a variable element, or usage selected by an array element.
+    pub usage: Option<Usage>,
+
+    /// Logical value of the element.
+    pub value: i64,
+}
+
+
+
+/// Reads an unsigned value of up to 32 bits at the given bit offset.
+fn extract(bytes: &[u8], offset: u32, size: u32) -> u32 {
+    let (first, last) = span(offset, size);
+
+    let mut value: u64 = 0;
+
+    for byte in bytes[first..last].iter().rev() {
+        value = (value << 8) | *byte as u64;
+    }
+
+    ((value >> (offset % 8)) & mask(size)) as u32
+}
+
+/// Writes an unsigned value of up to 32 bits at the given bit offset.
+fn insert(bytes: &mut [u8], offset: u32, size: u32, value: u32) {
+    let (first, last) = span(offset, size);
+    let shift = offset % 8;
+
+    let mut current: u64 = 0;
+
+    for byte in bytes[first..last].iter().rev() {
+        current = (current << 8) | *byte as u64;
+    }
+
+    current &= !(mask(size) << shift);
+    current |= (value as u64 & mask(size)) << shift;
+
+    for byte in bytes[first..last].iter_mut() {
+        *byte = current as u8;
+        current >>= 8;
+    }
+}
+
+/// Range of bytes holding the given bits.
+fn span(offset: u32, size: u32) -> (usize, usize) {
+    let first = offset as usize / 8;
+    let last = (offset as usize + size as usize).div_ceil(8);
+
+    (first, last.max(first))
+}
+
+/// Mask of the given number of low bits.
+fn mask(size: u32) -> u64 {
+    (1u64 << size) - 1
+}
@@ -0,0 +1,28 @@
+//! Human Interface Device class.
+//! Class specific descriptors of HID interfaces, and the parser and the
+//! builder of their report descriptors.
+
+
+
+mod boot;
+mod builder;
+mod country;
+mod descriptor;
+mod item;
+mod layout;
+mod names;
+mod report;
+
+#[cfg(feature = "hut")]
+pub mod usages;
+
+
+
+pub use boot::*;
+pub use builder::*;
+pub use country::*;
+pub use descriptor::*;
+pub use item::*;
+pub use layout::*;
+pub use names::*;
+pub use report::*;
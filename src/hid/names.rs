@@ -0,0 +1,258 @@
+//! Names of the HID report descriptor codes.
+//! Usage pages, common usages and data flags under the names used by the
+//! `hid_report!` macro. Usages of the other pages are given as numbers.
+//! Documentation: HID Usage Tables 1.5, Section 6.2.2.5 of the HID 1.11
+//! specification.
+
+
+
+#![allow(non_upper_case_globals)]
+
+
+
+/// Usage pages.
+pub mod page {
+    /// Generic Desktop page.
+    pub const GenericDesktop: u16 = 0x01;
+
+    /// Simulation Controls page.
+    pub const SimulationControls: u16 = 0x02;
+
+    /// VR Controls page.
+    pub const VRControls: u16 = 0x03;
+
+    /// Sport Controls page.
+    pub const SportControls: u16 = 0x04;
+
+    /// Game Controls page.
+    pub const GameControls: u16 = 0x05;
+
+    /// Generic Device Controls page.
+    pub const GenericDeviceControls: u16 = 0x06;
+
+    /// Keyboard/Keypad page.
+    pub const Keyboard: u16 = 0x07;
+
+    /// LED page.
+    pub const LED: u16 = 0x08;
+
+    /// Button page, whose usage ID is the button number.
+    pub const Button: u16 = 0x09;
+
+    /// Ordinal page, whose usage ID is the instance number.
+    pub const Ordinal: u16 = 0x0A;
+
+    /// Telephony Device page.
+    pub const Telephony: u16 = 0x0B;
+
+    /// Consumer page.
+    pub const Consumer: u16 = 0x0C;
+
+    /// Digitizers page.
+    pub const Digitizers: u16 = 0x0D;
+
+    /// Haptics page.
+    pub const Haptics: u16 = 0x0E;
+
+    /// Physical Input Device page.
+    pub const PhysicalInputDevice: u16 = 0x0F;
+
+    /// Unicode page, whose usage ID is the code point.
+    pub const Unicode: u16 = 0x10;
+
+    /// Sensors page.
+    pub const Sensors: u16 = 0x20;
+
+    /// Lighting And Illumination page.
+    pub const LightingAndIllumination: u16 = 0x59;
+
+    /// Power page.
+    pub const Power: u16 = 0x84;
+
+    /// Battery System page.
+    pub const BatterySystem: u16 = 0x85;
+
+    /// FIDO Alliance page.
+    pub const FIDOAlliance: u16 = 0xF1D0;
+
+    /// First vendor defined page.
+    pub const VendorDefined: u16 = 0xFF00;
+}
+
+
+
+/// Common usages of the Generic Desktop, Consumer and Digitizers pages.
+/// The usage page must be selected separately.
+pub mod usage {
+    /// Pointer (Generic Desktop).
+    pub const Pointer: u32 = 0x01;
+
+    /// Mouse (Generic Desktop).
+    pub const Mouse: u32 = 0x02;
+
+    /// Joystick (Generic Desktop).
+    pub const Joystick: u32 = 0x04;
+
+    /// Gamepad (Generic Desktop).
+    pub const Gamepad: u32 = 0x05;
+
+    /// Keyboard (Generic Desktop).
+    pub const Keyboard: u32 = 0x06;
+
+    /// Keypad (Generic Desktop).
+    pub const Keypad: u32 = 0x07;
+
+    /// Multi-axis Controller (Generic Desktop).
+    pub const MultiAxisController: u32 = 0x08;
+
+    /// X (Generic Desktop).
+    pub const X: u32 = 0x30;
+
+    /// Y (Generic Desktop).
+    pub const Y: u32 = 0x31;
+
+    /// Z (Generic Desktop).
+    pub const Z: u32 = 0x32;
+
+    /// Rx (Generic Desktop).
+    pub const Rx: u32 = 0x33;
+
+    /// Ry (Generic Desktop).
+    pub const Ry: u32 = 0x34;
+
+    /// Rz (Generic Desktop).
+    pub const Rz: u32 = 0x35;
+
+    /// Slider (Generic Desktop).
+    pub const Slider: u32 = 0x36;
+
+    /// Dial (Generic Desktop).
+    pub const Dial: u32 = 0x37;
+
+    /// Wheel (Generic Desktop).
+    pub const Wheel: u32 = 0x38;
+
+    /// Hat Switch (Generic Desktop).
+    pub const HatSwitch: u32 = 0x39;
+
+    /// Resolution Multiplier (Generic Desktop).
+    pub const ResolutionMultiplier: u32 = 0x48;
+
+    /// System Control (Generic Desktop).
+    pub const SystemControl: u32 = 0x80;
+
+    /// System Power Down (Generic Desktop).
+    pub const SystemPowerDown: u32 = 0x81;
+
+    /// System Sleep (Generic Desktop).
+    pub const SystemSleep: u32 = 0x82;
+
+    /// System Wake Up (Generic Desktop).
+    pub const SystemWakeUp: u32 = 0x83;
+
+    /// Consumer Control (Consumer).
+    pub const ConsumerControl: u32 = 0x01;
+
+    /// AC Pan (Consumer).
+    pub const ACPan: u32 = 0x0238;
+
+    /// Digitizer (Digitizers).
+    pub const Digitizer: u32 = 0x01;
+
+    /// Pen (Digitizers).
+    pub const Pen: u32 = 0x02;
+
+    /// Touch Screen (Digitizers).
+    pub const TouchScreen: u32 = 0x04;
+
+    /// Touch Pad (Digitizers).
+    pub const TouchPad: u32 = 0x05;
+
+    /// Stylus (Digitizers).
+    pub const Stylus: u32 = 0x20;
+
+    /// Finger (Digitizers).
+    pub const Finger: u32 = 0x22;
+
+    /// Tip Pressure (Digitizers).
+    pub const TipPressure: u32 = 0x30;
+
+    /// In Range (Digitizers).
+    pub const InRange: u32 = 0x32;
+
+    /// Tip Switch (Digitizers).
+    pub const TipSwitch: u32 = 0x42;
+
+    /// Contact Identifier (Digitizers).
+    pub const ContactIdentifier: u32 = 0x51;
+
+    /// Contact Count (Digitizers).
+    pub const ContactCount: u32 = 0x54;
+
+    /// Contact Count Maximum (Digitizers).
+    pub const ContactCountMaximum: u32 = 0x55;
+
+    /// Scan Time (Digitizers).
+    pub const ScanTime: u32 = 0x56;
+}
+
+
+
+/// Data flags of the Input, Output and Feature items.
+/// Each pair of names sets one bit, the first name being the cleared bit.
+pub mod flag {
+    /// The field holds data.
+    pub const Data: u32 = 0;
+
+    /// The field is constant, usually padding.
+    pub const Constant: u32 = 1 << 0;
+
+    /// The field is an array of selected usages.
+    pub const Array: u32 = 0;
+
+    /// The field holds one value per usage.
+    pub const Variable: u32 = 1 << 1;
+
+    /// The values are absolute.
+    pub const Absolute: u32 = 0;
+
+    /// The values are relative to the previous report.
+    pub const Relative: u32 = 1 << 2;
+
+    /// The values do not wrap around.
+    pub const NoWrap: u32 = 0;
+
+    /// The values wrap around their logical range.
+    pub const Wrap: u32 = 1 << 3;
+
+    /// The values are linear.
+    pub const Linear: u32 = 0;
+
+    /// The values are not linear.
+    pub const NonLinear: u32 = 1 << 4;
+
+    /// The control returns to a preferred state.
+    pub const PreferredState: u32 = 0;
+
+    /// The control has no preferred state.
+    pub const NoPreferred: u32 = 1 << 5;
+
+    /// The control has no null state.
+    pub const NoNullPosition: u32 = 0;
+
+    /// Values outside of the logical range are null.
+    pub const NullState: u32 = 1 << 6;
+
+    /// The value is not changed by the device (Output and Feature only).
+    pub const NonVolatile: u32 = 0;
+
+    /// The value may change without host interaction (Output and Feature
+    /// only).
+    pub const Volatile: u32 = 1 << 7;
+
+    /// The field is a bit field.
+    pub const BitField: u32 = 0;
+
+    /// The field is a stream of bytes.
+    pub const BufferedBytes: u32 = 1 << 8;
+}
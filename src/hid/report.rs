@@ -0,0 +1,731 @@
+//! HID report descriptor.
+//! A parser of report descriptors, which tracks the global and local state
+//! of the items and describes the collections and report fields of a HID
+//! interface.
+//! Documentation: Sections 5.2 to 6.2.2 of the HID 1.11 specification.
+
+
+
+use crate::descriptor::{ DescriptorType, ToBytes };
+use crate::error::*;
+use super::{ CollectionType, GlobalItem, Item, Items, LocalItem, MainFlags, MainItem, split };
+
+
+
+/// Maximum number of global states saved by Push items.
+pub const STACK_DEPTH: usize = 8;
+
+
+
+/// A report descriptor borrowed from a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportDescriptor<'a> {
+    /// The whole descriptor.
+    bytes: &'a [u8],
+}
+
+impl<'a> ReportDescriptor<'a> {
+    /// Descriptor type of this descriptor.
+    pub const TYPE: u8 = DescriptorType::Report.code();
+
+    /// Parses a report descriptor filling the given buffer.
+    /// All items must be complete, Push and Pop items must balance within
+    /// `STACK_DEPTH` levels, all collections must be closed, and Report IDs
+    /// and Usage Pages must fit their width. Report ID 0 is reserved.
+    pub fn parse(bytes: &'a [u8]) -> Result<ReportDescriptor<'a>, USBParseError> {
+        let mut rest = bytes;
+        let mut stack = 0;
+        let mut collections = 0;
+
+        while !rest.is_empty() {
+            let offset = bytes.len() - rest.len();
+
+            let (item, next) = match split(rest) {
+                Ok(split) => split,
+                Err(_) => return Err( USBParseError::ReportItemTruncated(offset) ),
+            };
+
+            if let Item::Short(item) = item {
+                match (item.main(), item.global()) {
+                    (Some(MainItem::Collection(_)), _) => collections += 1,
+
+                    (Some(MainItem::EndCollection), _) => match collections {
+                        0 => return Err( USBParseError::CollectionMismatch(offset) ),
+                        _ => collections -= 1,
+                    },
+
+                    (_, Some(GlobalItem::Push)) => match stack {
+                        STACK_DEPTH => return Err( USBParseError::ReportStackMismatch(offset) ),
+                        _ => stack += 1,
+                    },
+
+                    (_, Some(GlobalItem::Pop)) => match stack {
+                        0 => return Err( USBParseError::ReportStackMismatch(offset) ),
+                        _ => stack -= 1,
+                    },
+
+                    (_, Some(GlobalItem::ReportId(_))) if !matches!(item.unsigned(), 0x01..=0xFF) => {
+                        return Err( USBParseError::ReportItemOutOfRange(offset) );
+                    },
+
+                    (_, Some(GlobalItem::UsagePage(_))) if item.unsigned() > 0xFFFF => {
+                        return Err( USBParseError::ReportItemOutOfRange(offset) );
+                    },
+
+                    _ => (),
+                }
+            }
+
+            rest = next;
+        }
+
+        if collections != 0 {
+            return Err( USBParseError::CollectionMismatch(bytes.len()) );
+        }
+
+        Ok( ReportDescriptor { bytes } )
+    }
+
+    /// Raw bytes of the descriptor.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Items of the descriptor.
+    pub const fn items(&self) -> Items<'a> {
+        Items::new(self.bytes)
+    }
+
+    /// Collections and fields of the descriptor, in order.
+    pub fn parser(&self) -> ReportParser<'a> {
+        ReportParser::new(self.bytes)
+    }
+
+    /// Top level collections of the descriptor.
+    pub fn collections(&self) -> Collections<'a> {
+        Collections { parser: self.parser(), depth: 0 }
+    }
+
+    /// All the report fields of the descriptor, in order.
+    pub fn fields(&self) -> Fields<'a> {
+        Fields { parser: self.parser(), depth: 0, recursive: true }
+    }
+}
+
+impl<'a> ToBytes for ReportDescriptor<'a> {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, USBSerializeError> {
+        self.bytes.to_bytes(buffer)
+    }
+}
+
+
+
+/// A usage: a usage page and a usage ID within it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Usage {
+    /// Usage page.
+    pub page: u16,
+
+    /// Usage ID.
+    pub id: u16,
+}
+
+impl Usage {
+    /// Creates a usage.
+    pub const fn new(page: u16, id: u16) -> Usage {
+        Usage { page, id }
+    }
+
+    /// Decodes an extended usage, which holds its page in the high 16 bits.
+    pub const fn from_extended(usage: u32) -> Usage {
+        Usage { page: (usage >> 16) as u16, id: usage as u16 }
+    }
+
+    /// Encodes the usage as an extended usage.
+    pub const fn extended(&self) -> u32 {
+        ((self.page as u32) << 16) | self.id as u32
+    }
+}
+
+impl core::fmt::Display for Usage {
+    #[cfg(feature = "hut")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.page_name() {
+            Some(name) => write!(f, "{}", name)?,
+            None => write!(f, "{:04X}h", self.page)?,
+        }
+
+        match self.name() {
+            Some(_) if self.page == super::usages::UNICODE => write!(f, ": U+{:04X}", self.id),
+            Some(name) if self.numbered() => write!(f, ": {} {}", name, self.id),
+            Some(name) => write!(f, ": {}", name),
+            None => write!(f, ": {:04X}h", self.id),
+        }
+    }
+
+    #[cfg(not(feature = "hut"))]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:04X}h: {:04X}h", self.page, self.id)
+    }
+}
+
+
+
+/// The Main item that defines a report field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReportKind {
+    /// Input report, from the device to the host.
+    Input,
+
+    /// Output report, from the host to the device.
+    Output,
+
+    /// Feature report, read and written by control transfers.
+    Feature,
+}
+
+
+
+/// State set by the Global items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct GlobalState {
+    usage_page: u16,
+    logical_minimum: i32,
+    logical_maximum: i32,
+    physical_minimum: i32,
+    physical_maximum: i32,
+    unit_exponent: i8,
+    unit: u32,
+    report_size: u32,
+    report_id: Option<u8>,
+    report_count: u32,
+
+    /// Logical and physical maximums as unsigned values.
+    /// Descriptors often encode maximums above the signed range of their
+    /// item size, which only make sense with a positive minimum.
+    maximums: (u32, u32),
+}
+
+
+
+/// A parser of report descriptors.
+/// Yields the collections and report fields of the descriptor, in order.
+/// Unknown and long items are skipped.
+#[derive(Clone, Copy, Debug)]
+pub struct ReportParser<'a> {
+    /// The whole descriptor.
+    bytes: &'a [u8],
+
+    /// Offset of the next item.
+    offset: usize,
+
+    /// Offset of the Local items of the next Main item.
+    locals: usize,
+
+    /// Current global state.
+    global: GlobalState,
+
+    /// Global states saved by Push items.
+    stack: [GlobalState; STACK_DEPTH],
+
+    /// Number of saved global states.
+    depth: usize,
+}
+
+impl<'a> ReportParser<'a> {
+    /// Creates a parser over the given items.
+    /// Parsing stops at the first truncated item, so the descriptor should
+    /// be validated with `ReportDescriptor::parse` first.
+    pub fn new(bytes: &'a [u8]) -> ReportParser<'a> {
+        ReportParser {
+            bytes,
+            offset: 0,
+            locals: 0,
+            global: GlobalState::default(),
+            stack: [GlobalState::default(); STACK_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Creates a parser resuming at the given offset with the given global
+    /// state and no saved global states.
+    fn resume(bytes: &'a [u8], offset: usize, global: GlobalState) -> ReportParser<'a> {
+        ReportParser { offset, locals: offset, global, ..ReportParser::new(bytes) }
+    }
+
+    /// Applies a Global item.
+    fn apply(&mut self, item: GlobalItem, unsigned: u32) {
+        let global = &mut self.global;
+
+        match item {
+            GlobalItem::UsagePage(page) => global.usage_page = page,
+            GlobalItem::LogicalMinimum(value) => global.logical_minimum = value,
+            GlobalItem::LogicalMaximum(value) => {
+                global.logical_maximum = value;
+                global.maximums.0 = unsigned;
+            },
+            GlobalItem::PhysicalMinimum(value) => global.physical_minimum = value,
+            GlobalItem::PhysicalMaximum(value) => {
+                global.physical_maximum = value;
+                global.maximums.1 = unsigned;
+            },
+            GlobalItem::UnitExponent(exponent) => global.unit_exponent = exponent,
+            GlobalItem::Unit(unit) => global.unit = unit,
+            GlobalItem::ReportSize(size) => global.report_size = size,
+            GlobalItem::ReportId(id) => global.report_id = Some(id),
+            GlobalItem::ReportCount(count) => global.report_count = count,
+
+            GlobalItem::Push => if self.depth < STACK_DEPTH {
+                self.stack[self.depth] = *global;
+                self.depth += 1;
+            },
+
+            GlobalItem::Pop => if self.depth > 0 {
+                self.depth -= 1;
+                *global = self.stack[self.depth];
+            },
+
+            GlobalItem::Other(_) => (),
+        }
+    }
+
+    /// Global state of a Main item, with its maximums resolved.
+    fn resolved(&self) -> GlobalState {
+        let mut global = self.global;
+
+        if (global.logical_minimum >= 0) && (global.logical_maximum < 0) {
+            global.logical_maximum = global.maximums.0 as i32;
+        }
+
+        if (global.physical_minimum >= 0) && (global.physical_maximum < 0) {
+            global.physical_maximum = global.maximums.1 as i32;
+        }
+
+        global
+    }
+}
+
+impl<'a> Iterator for ReportParser<'a> {
+    type Item = ReportEvent<'a>;
+
+    fn next(&mut self) -> Option<ReportEvent<'a>> {
+        loop {
+            let start = self.offset;
+
+            let item = match split(&self.bytes[start..]) {
+                Ok((item, _)) => item,
+
+                Err(_) => {
+                    self.offset = self.bytes.len();
+                    return None;
+                },
+            };
+
+            self.offset += item.size();
+
+            let item = match item {
+                Item::Short(item) => item,
+                Item::Long(_) => continue,
+            };
+
+            if let Some(global) = item.global() {
+                self.apply(global, item.unsigned());
+                continue;
+            }
+
+            let main = match item.main() {
+                Some(main) => main,
+                None => continue,
+            };
+
+            let locals = &self.bytes[self.locals..start];
+            self.locals = self.offset;
+
+            let kind = match main {
+                MainItem::Input(flags) => (ReportKind::Input, flags),
+                MainItem::Output(flags) => (ReportKind::Output, flags),
+                MainItem::Feature(flags) => (ReportKind::Feature, flags),
+
+                MainItem::Collection(kind) => return Some(ReportEvent::Collection(Collection {
+                    kind,
+                    usage_page: self.global.usage_page,
+                    locals,
+                    bytes: self.bytes,
+                    offset: self.offset,
+                    global: self.global,
+                    pushed: self.depth != 0,
+                })),
+
+                MainItem::EndCollection => return Some(ReportEvent::EndCollection),
+
+                MainItem::Other(_) => continue,
+            };
+
+            return Some(ReportEvent::Field(ReportField {
+                kind: kind.0,
+                flags: kind.1,
+                global: self.resolved(),
+                locals,
+            }));
+        }
+    }
+}
+
+
+
+/// An event of a report descriptor parser.
+#[derive(Clone, Copy, Debug)]
+pub enum ReportEvent<'a> {
+    /// Start of a collection.
+    Collection(Collection<'a>),
+
+    /// End of the current collection.
+    EndCollection,
+
+    /// A report field.
+    Field(ReportField<'a>),
+}
+
+
+
+/// A collection of a report descriptor.
+#[derive(Clone, Copy, Debug)]
+pub struct Collection<'a> {
+    /// Type of the collection.
+    kind: CollectionType,
+
+    /// Usage page in effect at the Collection item.
+    usage_page: u16,
+
+    /// Local items of the Collection item.
+    locals: &'a [u8],
+
+    /// The whole descriptor.
+    bytes: &'a [u8],
+
+    /// Offset of the item following the Collection item.
+    offset: usize,
+
+    /// Global state at the Collection item.
+    global: GlobalState,
+
+    /// Global states were saved by Push items before the Collection item.
+    pushed: bool,
+}
+
+impl<'a> Collection<'a> {
+    /// Type of the collection.
+    pub const fn kind(&self) -> CollectionType {
+        self.kind
+    }
+
+    /// Usage of the collection.
+    pub fn usage(&self) -> Option<Usage> {
+        self.usages().next()
+    }
+
+    /// All the usages of the collection.
+    pub fn usages(&self) -> Usages<'a> {
+        Usages::new(self.locals, self.usage_page)
+    }
+
+    /// Collections nested directly in this collection.
+    pub fn children(&self) -> Collections<'a> {
+        Collections { parser: self.parser(), depth: 0 }
+    }
+
+    /// Report fields of this collection, outside of nested collections.
+    pub fn fields(&self) -> Fields<'a> {
+        Fields { parser: self.parser(), depth: 0, recursive: false }
+    }
+
+    /// Report fields of this collection and of its nested collections.
+    pub fn all_fields(&self) -> Fields<'a> {
+        Fields { parser: self.parser(), depth: 0, recursive: true }
+    }
+
+    /// Parser positioned after the Collection item.
+    /// The saved global states are not stored with the collection, so the
+    /// items before it are parsed again if a Push item is still open.
+    fn parser(&self) -> ReportParser<'a> {
+        if !self.pushed {
+            return ReportParser::resume(self.bytes, self.offset, self.global);
+        }
+
+        let mut parser = ReportParser::new(self.bytes);
+
+        while parser.offset < self.offset {
+            parser.next();
+        }
+
+        parser
+    }
+}
+
+/// Iterator over sibling collections.
+#[derive(Clone, Debug)]
+pub struct Collections<'a> {
+    /// Parser of the following items.
+    parser: ReportParser<'a>,
+
+    /// Depth of the parser below the siblings.
+    depth: usize,
+}
+
+impl<'a> Iterator for Collections<'a> {
+    type Item = Collection<'a>;
+
+    fn next(&mut self) -> Option<Collection<'a>> {
+        loop {
+            match self.parser.next()? {
+                ReportEvent::Collection(collection) => {
+                    self.depth += 1;
+
+                    if self.depth == 1 {
+                        return Some(collection);
+                    }
+                },
+
+                ReportEvent::EndCollection => match self.depth {
+                    0 => {
+                        self.parser.offset = self.parser.bytes.len();
+                        return None;
+                    },
+
+                    _ => self.depth -= 1,
+                },
+
+                ReportEvent::Field(_) => (),
+            }
+        }
+    }
+}
+
+/// Iterator over the report fields of a collection.
+#[derive(Clone, Debug)]
+pub struct Fields<'a> {
+    /// Parser of the following items.
+    parser: ReportParser<'a>,
+
+    /// Depth of the parser below the collection.
+    depth: usize,
+
+    /// Yield the fields of nested collections.
+    recursive: bool,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = ReportField<'a>;
+
+    fn next(&mut self) -> Option<ReportField<'a>> {
+        loop {
+            match self.parser.next()? {
+                ReportEvent::Collection(_) => self.depth += 1,
+
+                ReportEvent::EndCollection => match self.depth {
+                    0 => {
+                        self.parser.offset = self.parser.bytes.len();
+                        return None;
+                    },
+
+                    _ => self.depth -= 1,
+                },
+
+                ReportEvent::Field(field) => if self.recursive || (self.depth == 0) {
+                    return Some(field);
+                },
+            }
+        }
+    }
+}
+
+
+
+/// A report field: an Input, Output or Feature item with the state it was
+/// declared with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportField<'a> {
+    /// Main item of the field.
+    kind: ReportKind,
+
+    /// Data flags of the Main item.
+    flags: MainFlags,
+
+    /// Global state at the Main item.
+    global: GlobalState,
+
+    /// Local items of the Main item.
+    locals: &'a [u8],
+}
+
+impl<'a> ReportField<'a> {
+    /// Report of the field.
+    pub const fn kind(&self) -> ReportKind {
+        self.kind
+    }
+
+    /// Data flags of the field.
+    pub const fn flags(&self) -> MainFlags {
+        self.flags
+    }
+
+    /// Report ID of the report, if the descriptor uses report IDs.
+    pub const fn report_id(&self) -> Option<u8> {
+        self.global.report_id
+    }
+
+    /// Size of each element in bits.
+    pub const fn report_size(&self) -> u32 {
+        self.global.report_size
+    }
+
+    /// Number of elements.
+    pub const fn report_count(&self) -> u32 {
+        self.global.report_count
+    }
+
+    /// Usage page in effect at the Main item.
+    pub const fn usage_page(&self) -> u16 {
+        self.global.usage_page
+    }
+
+    /// Minimum logical value.
+    pub const fn logical_minimum(&self) -> i32 {
+        self.global.logical_minimum
+    }
+
+    /// Maximum logical value.
+    /// Maximums that only fit as unsigned values are read as such when the
+    /// minimum is not negative.
+    pub const fn logical_maximum(&self) -> i32 {
+        self.global.logical_maximum
+    }
+
+    /// Minimum physical value.
+    pub const fn physical_minimum(&self) -> i32 {
+        self.global.physical_minimum
+    }
+
+    /// Maximum physical value.
+    /// Both physical bounds are 0 when the physical values equal the
+    /// logical ones.
+    pub const fn physical_maximum(&self) -> i32 {
+        self.global.physical_maximum
+    }
+
+    /// Raw unit of the physical values.
+    pub const fn unit(&self) -> u32 {
+        self.global.unit
+    }
+
+    /// Base 10 exponent of the unit.
+    pub const fn unit_exponent(&self) -> i8 {
+        self.global.unit_exponent
+    }
+
+    /// Usages of the field, with their ranges expanded.
+    pub fn usages(&self) -> Usages<'a> {
+        Usages::new(self.locals, self.global.usage_page)
+    }
+}
+
+
+
+/// Iterator over the usages of a Main item.
+/// Usage ranges are expanded. Only the first usage of a delimited set of
+/// alternatives is kept.
+#[derive(Clone, Debug)]
+pub struct Usages<'a> {
+    /// Local items not yet visited.
+    items: Items<'a>,
+
+    /// Usage page of the usages without a page.
+    page: u16,
+
+    /// Pending usage minimum and maximum.
+    minimum: Option<u32>,
+    maximum: Option<u32>,
+
+    /// Usage range being expanded, as extended usages.
+    range: Option<(u32, u32)>,
+
+    /// State of the current delimited set: open, and a usage was taken.
+    delimiter: (bool, bool),
+}
+
+impl<'a> Usages<'a> {
+    /// Creates an iterator over the usages of the given Local items.
+    fn new(locals: &'a [u8], page: u16) -> Usages<'a> {
+        Usages {
+            items: Items::new(locals),
+            page,
+            minimum: None,
+            maximum: None,
+            range: None,
+            delimiter: (false, false),
+        }
+    }
+
+    /// Skips a usage inside a delimited set once one was taken.
+    fn skip(&mut self) -> bool {
+        match self.delimiter {
+            (true, true) => true,
+            (true, false) => {
+                self.delimiter.1 = true;
+                false
+            },
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Iterator for Usages<'a> {
+    type Item = Usage;
+
+    fn next(&mut self) -> Option<Usage> {
+        loop {
+            if let Some((next, last)) = self.range {
+                self.range = match next < last {
+                    true => Some((next + 1, last)),
+                    _ => None,
+                };
+
+                return Some( Usage::from_extended(next) );
+            }
+
+            let item = match self.items.next()? {
+                Item::Short(item) => item,
+                Item::Long(_) => continue,
+            };
+
+            let extended = match item.extended_usage() {
+                true => item.unsigned(),
+                _ => ((self.page as u32) << 16) | (item.unsigned() & 0xFFFF),
+            };
+
+            match item.local() {
+                Some(LocalItem::Usage(_)) if !self.skip() => return Some( Usage::from_extended(extended) ),
+
+                Some(LocalItem::UsageMinimum(_)) => self.minimum = Some(extended),
+                Some(LocalItem::UsageMaximum(_)) => self.maximum = Some(extended),
+
+                Some(LocalItem::Delimiter(open)) => self.delimiter = (open == 1, false),
+
+                _ => (),
+            }
+
+            if let (Some(minimum), Some(maximum)) = (self.minimum, self.maximum) {
+                self.minimum = None;
+                self.maximum = None;
+
+                if !self.skip() && (minimum <= maximum) {
+                    self.range = Some((minimum, maximum));
+                }
+            }
+        }
+    }
+}
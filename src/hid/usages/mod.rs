@@ -0,0 +1,223 @@
+//! HID usage tables.
+//! Names and kinds of the usage pages and usages, to print report
+//! descriptors and reports with readable names.
+//! Pages without a usage table only have a name.
+//! Documentation: HID Usage Tables 1.5.
+
+
+
+mod tables;
+
+
+
+use super::Usage;
+
+
+
+/// Usage page of buttons, whose usage ID is the button number.
+const BUTTON: u16 = 0x09;
+
+/// Usage page of ordinals, whose usage ID is the instance number.
+const ORDINAL: u16 = 0x0A;
+
+/// Usage page of Unicode characters, whose usage ID is the code point.
+pub(crate) const UNICODE: u16 = 0x10;
+
+
+
+/// Kind of a usage: how a control or a collection is meant to be used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UsageKind {
+    /// Linear Control (LC): a value in a range.
+    LinearControl,
+
+    /// On/Off Control (OOC): a toggle.
+    OnOffControl,
+
+    /// Momentary Control (MC): asserted while held.
+    MomentaryControl,
+
+    /// One Shot Control (OSC): triggers an event on assertion.
+    OneShotControl,
+
+    /// Re-trigger Control (RTC): repeats an event while held.
+    RetriggerControl,
+
+    /// Selector (Sel): an entry of a named array.
+    Selector,
+
+    /// Static Value (SV): a read-only value.
+    StaticValue,
+
+    /// Static Flag (SF): a read-only flag.
+    StaticFlag,
+
+    /// Dynamic Value (DV): a read and write value.
+    DynamicValue,
+
+    /// Dynamic Flag (DF): a read and write flag.
+    DynamicFlag,
+
+    /// Named Array (NAry): a collection of selectors.
+    NamedArray,
+
+    /// Application Collection (CA).
+    ApplicationCollection,
+
+    /// Logical Collection (CL).
+    LogicalCollection,
+
+    /// Physical Collection (CP).
+    PhysicalCollection,
+
+    /// Usage Switch (US).
+    UsageSwitch,
+
+    /// Usage Modifier (UM).
+    UsageModifier,
+
+    /// Buffered Bytes (BB).
+    BufferedBytes,
+}
+
+impl UsageKind {
+    /// Abbreviation of the kind used by the usage tables.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            UsageKind::LinearControl         => "LC",
+            UsageKind::OnOffControl          => "OOC",
+            UsageKind::MomentaryControl      => "MC",
+            UsageKind::OneShotControl        => "OSC",
+            UsageKind::RetriggerControl      => "RTC",
+            UsageKind::Selector              => "Sel",
+            UsageKind::StaticValue           => "SV",
+            UsageKind::StaticFlag            => "SF",
+            UsageKind::DynamicValue          => "DV",
+            UsageKind::DynamicFlag           => "DF",
+            UsageKind::NamedArray            => "NAry",
+            UsageKind::ApplicationCollection => "CA",
+            UsageKind::LogicalCollection     => "CL",
+            UsageKind::PhysicalCollection    => "CP",
+            UsageKind::UsageSwitch           => "US",
+            UsageKind::UsageModifier         => "UM",
+            UsageKind::BufferedBytes         => "BB",
+        }
+    }
+}
+
+
+
+/// Name and kinds of a usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UsageInfo {
+    /// Name of the usage.
+    /// Buttons, ordinals and Unicode characters share a name, and are told
+    /// apart by their usage ID.
+    pub name: &'static str,
+
+    /// Kinds of the usage, most common first.
+    pub kinds: &'static [UsageKind],
+}
+
+/// Name of a usage page.
+pub const fn page_name(page: u16) -> Option<&'static str> {
+    if page >= 0xFF00 {
+        return Some("Vendor Defined");
+    }
+
+    let pages = tables::PAGES;
+
+    let (mut low, mut high) = (0, pages.len());
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        match pages[middle].0 {
+            code if code == page => return Some(pages[middle].1),
+            code if code < page => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+
+    None
+}
+
+/// Name and kinds of a usage.
+pub const fn usage_info(page: u16, id: u16) -> Option<UsageInfo> {
+    match (page, id) {
+        (BUTTON, 0) => return Some( UsageInfo { name: "No Button Pressed", kinds: &[UsageKind::Selector] } ),
+        (BUTTON, _) => return Some( UsageInfo { name: "Button", kinds: BUTTONS } ),
+        (ORDINAL, 0) => return None,
+        (ORDINAL, _) => return Some( UsageInfo { name: "Instance", kinds: &[UsageKind::UsageModifier] } ),
+        (UNICODE, _) => return Some( UsageInfo { name: "Unicode Character", kinds: &[UsageKind::Selector] } ),
+        _ => (),
+    }
+
+    let table = match tables::table(page) {
+        Some(table) => table,
+        None => return None,
+    };
+
+    let (mut low, mut high) = (0, table.len());
+
+    while low < high {
+        let middle = (low + high) / 2;
+        let (code, name, kinds) = table[middle];
+
+        match code {
+            code if code == id => return Some( UsageInfo { name, kinds } ),
+            code if code < id => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+
+    None
+}
+
+/// Kinds of the buttons.
+const BUTTONS: &[UsageKind] = &[
+    UsageKind::Selector,
+    UsageKind::OnOffControl,
+    UsageKind::MomentaryControl,
+    UsageKind::OneShotControl,
+];
+
+
+
+impl Usage {
+    /// Name of the usage page.
+    pub const fn page_name(&self) -> Option<&'static str> {
+        page_name(self.page)
+    }
+
+    /// Name and kinds of the usage.
+    pub const fn info(&self) -> Option<UsageInfo> {
+        usage_info(self.page, self.id)
+    }
+
+    /// Name of the usage.
+    pub const fn name(&self) -> Option<&'static str> {
+        match self.info() {
+            Some(info) => Some(info.name),
+            _ => None,
+        }
+    }
+
+    /// Most common kind of the usage.
+    pub const fn kind(&self) -> Option<UsageKind> {
+        match self.info() {
+            Some(UsageInfo { kinds: [kind, ..], .. }) => Some(*kind),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the usage ID of the usage is a number rather than
+    /// a named usage: a button, an ordinal or a Unicode character.
+    pub const fn numbered(&self) -> bool {
+        match self.page {
+            BUTTON | ORDINAL => self.id != 0,
+            UNICODE => true,
+            _ => false,
+        }
+    }
+}
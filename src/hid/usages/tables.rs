@@ -0,0 +1,1719 @@
+//! Usage tables.
+//! Names and kinds of the usages of the HID Usage Tables 1.5. Each table is
+//! sorted by usage ID so it can be searched in const functions, which is
+//! checked when building the crate.
+
+
+
+use super::UsageKind::{ self, * };
+
+
+
+/// A usage ID, its name and its kinds.
+pub(super) type Entry = (u16, &'static str, &'static [UsageKind]);
+
+
+
+/// Names of the usage pages, sorted by page.
+pub(super) const PAGES: &[(u16, &str)] = &[
+    (0x0001, "Generic Desktop"),
+    (0x0002, "Simulation Controls"),
+    (0x0003, "VR Controls"),
+    (0x0004, "Sport Controls"),
+    (0x0005, "Game Controls"),
+    (0x0006, "Generic Device Controls"),
+    (0x0007, "Keyboard/Keypad"),
+    (0x0008, "LED"),
+    (0x0009, "Button"),
+    (0x000A, "Ordinal"),
+    (0x000B, "Telephony Device"),
+    (0x000C, "Consumer"),
+    (0x000D, "Digitizers"),
+    (0x000E, "Haptics"),
+    (0x000F, "Physical Input Device"),
+    (0x0010, "Unicode"),
+    (0x0011, "SoC"),
+    (0x0012, "Eye and Head Trackers"),
+    (0x0014, "Auxiliary Display"),
+    (0x0020, "Sensors"),
+    (0x0040, "Medical Instrument"),
+    (0x0041, "Braille Display"),
+    (0x0059, "Lighting And Illumination"),
+    (0x0080, "Monitor"),
+    (0x0081, "Monitor Enumerated"),
+    (0x0082, "VESA Virtual Controls"),
+    (0x0084, "Power"),
+    (0x0085, "Battery System"),
+    (0x008C, "Barcode Scanner"),
+    (0x008D, "Scales"),
+    (0x008E, "Magnetic Stripe Reader"),
+    (0x0090, "Camera Control"),
+    (0x0091, "Arcade"),
+    (0x0092, "Gaming Device"),
+    (0xF1D0, "FIDO Alliance"),
+];
+
+
+
+/// Usages of the pages with a usage table.
+pub(super) const fn table(page: u16) -> Option<&'static [Entry]> {
+    match page {
+        0x0001 => Some(GENERIC_DESKTOP),
+        0x0002 => Some(SIMULATION_CONTROLS),
+        0x0005 => Some(GAME_CONTROLS),
+        0x0006 => Some(GENERIC_DEVICE_CONTROLS),
+        0x0007 => Some(KEYBOARD),
+        0x0008 => Some(LED),
+        0x000B => Some(TELEPHONY),
+        0x000C => Some(CONSUMER),
+        0x000D => Some(DIGITIZERS),
+        0x000E => Some(HAPTICS),
+        0x0020 => Some(SENSORS),
+        0x0084 => Some(POWER),
+        0x0085 => Some(BATTERY_SYSTEM),
+        0xF1D0 => Some(FIDO_ALLIANCE),
+
+        _ => None,
+    }
+}
+
+// The lookups rely on the tables being sorted.
+const _: () = {
+    let mut i = 1;
+
+    while i < PAGES.len() {
+        assert!(PAGES[i - 1].0 < PAGES[i].0, "usage pages are not strictly increasing");
+        i += 1;
+    }
+
+    let mut page = 0;
+
+    while page < PAGES.len() {
+        if let Some(table) = table(PAGES[page].0) {
+            let mut i = 1;
+
+            while i < table.len() {
+                assert!(table[i - 1].0 < table[i].0, "usage IDs are not strictly increasing");
+                i += 1;
+            }
+        }
+
+        page += 1;
+    }
+};
+
+
+
+/// Generic Desktop page (01h).
+const GENERIC_DESKTOP: &[Entry] = &[
+    (0x0001, "Pointer", &[PhysicalCollection]),
+    (0x0002, "Mouse", &[ApplicationCollection]),
+    (0x0004, "Joystick", &[ApplicationCollection]),
+    (0x0005, "Gamepad", &[ApplicationCollection]),
+    (0x0006, "Keyboard", &[ApplicationCollection]),
+    (0x0007, "Keypad", &[ApplicationCollection]),
+    (0x0008, "Multi-axis Controller", &[ApplicationCollection]),
+    (0x0009, "Tablet PC System Controls", &[ApplicationCollection]),
+    (0x000A, "Water Cooling Device", &[ApplicationCollection]),
+    (0x000B, "Computer Chassis Device", &[ApplicationCollection]),
+    (0x000C, "Wireless Radio Controls", &[ApplicationCollection]),
+    (0x000D, "Portable Device Control", &[ApplicationCollection]),
+    (0x000E, "System Multi-Axis Controller", &[ApplicationCollection]),
+    (0x000F, "Spatial Controller", &[ApplicationCollection]),
+    (0x0010, "Assistive Control", &[ApplicationCollection]),
+    (0x0011, "Device Dock", &[ApplicationCollection]),
+    (0x0012, "Dockable Device", &[ApplicationCollection]),
+    (0x0013, "Call State Management Control", &[ApplicationCollection]),
+    (0x0030, "X", &[DynamicValue]),
+    (0x0031, "Y", &[DynamicValue]),
+    (0x0032, "Z", &[DynamicValue]),
+    (0x0033, "Rx", &[DynamicValue]),
+    (0x0034, "Ry", &[DynamicValue]),
+    (0x0035, "Rz", &[DynamicValue]),
+    (0x0036, "Slider", &[DynamicValue]),
+    (0x0037, "Dial", &[DynamicValue]),
+    (0x0038, "Wheel", &[DynamicValue]),
+    (0x0039, "Hat Switch", &[DynamicValue]),
+    (0x003A, "Counted Buffer", &[LogicalCollection]),
+    (0x003B, "Byte Count", &[DynamicValue]),
+    (0x003C, "Motion Wakeup", &[OneShotControl, DynamicFlag]),
+    (0x003D, "Start", &[OnOffControl]),
+    (0x003E, "Select", &[OnOffControl]),
+    (0x0040, "Vx", &[DynamicValue]),
+    (0x0041, "Vy", &[DynamicValue]),
+    (0x0042, "Vz", &[DynamicValue]),
+    (0x0043, "Vbrx", &[DynamicValue]),
+    (0x0044, "Vbry", &[DynamicValue]),
+    (0x0045, "Vbrz", &[DynamicValue]),
+    (0x0046, "Vno", &[DynamicValue]),
+    (0x0047, "Feature Notification", &[DynamicValue, DynamicFlag]),
+    (0x0048, "Resolution Multiplier", &[DynamicValue]),
+    (0x0049, "Qx", &[DynamicValue]),
+    (0x004A, "Qy", &[DynamicValue]),
+    (0x004B, "Qz", &[DynamicValue]),
+    (0x004C, "Qw", &[DynamicValue]),
+    (0x0080, "System Control", &[ApplicationCollection]),
+    (0x0081, "System Power Down", &[OneShotControl]),
+    (0x0082, "System Sleep", &[OneShotControl]),
+    (0x0083, "System Wake Up", &[OneShotControl]),
+    (0x0084, "System Context Menu", &[OneShotControl]),
+    (0x0085, "System Main Menu", &[OneShotControl]),
+    (0x0086, "System App Menu", &[OneShotControl]),
+    (0x0087, "System Menu Help", &[OneShotControl]),
+    (0x0088, "System Menu Exit", &[OneShotControl]),
+    (0x0089, "System Menu Select", &[OneShotControl]),
+    (0x008A, "System Menu Right", &[RetriggerControl]),
+    (0x008B, "System Menu Left", &[RetriggerControl]),
+    (0x008C, "System Menu Up", &[RetriggerControl]),
+    (0x008D, "System Menu Down", &[RetriggerControl]),
+    (0x008E, "System Cold Restart", &[OneShotControl]),
+    (0x008F, "System Warm Restart", &[OneShotControl]),
+    (0x0090, "D-pad Up", &[OnOffControl]),
+    (0x0091, "D-pad Down", &[OnOffControl]),
+    (0x0092, "D-pad Right", &[OnOffControl]),
+    (0x0093, "D-pad Left", &[OnOffControl]),
+    (0x0094, "Index Trigger", &[MomentaryControl, DynamicValue]),
+    (0x0095, "Palm Trigger", &[MomentaryControl, DynamicValue]),
+    (0x0096, "Thumbstick", &[PhysicalCollection]),
+    (0x0097, "System Function Shift", &[MomentaryControl]),
+    (0x0098, "System Function Shift Lock", &[OnOffControl]),
+    (0x0099, "System Function Shift Lock Indicator", &[DynamicValue]),
+    (0x009A, "System Dismiss Notification", &[OneShotControl]),
+    (0x009B, "System Do Not Disturb", &[OnOffControl]),
+    (0x00A0, "System Dock", &[OneShotControl]),
+    (0x00A1, "System Undock", &[OneShotControl]),
+    (0x00A2, "System Setup", &[OneShotControl]),
+    (0x00A3, "System Break", &[OneShotControl]),
+    (0x00A4, "System Debugger Break", &[OneShotControl]),
+    (0x00A5, "Application Break", &[OneShotControl]),
+    (0x00A6, "Application Debugger Break", &[OneShotControl]),
+    (0x00A7, "System Speaker Mute", &[OneShotControl]),
+    (0x00A8, "System Hibernate", &[OneShotControl]),
+    (0x00A9, "System Microphone Mute", &[OnOffControl]),
+    (0x00B0, "System Display Invert", &[OneShotControl]),
+    (0x00B1, "System Display Internal", &[OneShotControl]),
+    (0x00B2, "System Display External", &[OneShotControl]),
+    (0x00B3, "System Display Both", &[OneShotControl]),
+    (0x00B4, "System Display Dual", &[OneShotControl]),
+    (0x00B5, "System Display Toggle Int/Ext Mode", &[OneShotControl]),
+    (0x00B6, "System Display Swap Primary/Secondary", &[OneShotControl]),
+    (0x00B7, "System Display Toggle LCD Autoscale", &[OneShotControl]),
+    (0x00C0, "Sensor Zone", &[LogicalCollection]),
+    (0x00C1, "RPM", &[DynamicValue]),
+    (0x00C2, "Coolant Level", &[DynamicValue]),
+    (0x00C3, "Coolant Critical Level", &[StaticValue]),
+    (0x00C4, "Coolant Pump", &[UsageSwitch]),
+    (0x00C5, "Chassis Enclosure", &[LogicalCollection]),
+    (0x00C6, "Wireless Radio Button", &[OnOffControl]),
+    (0x00C7, "Wireless Radio LED", &[OnOffControl]),
+    (0x00C8, "Wireless Radio Slider Switch", &[OnOffControl]),
+    (0x00C9, "System Display Rotation Lock Button", &[OnOffControl]),
+    (0x00CA, "System Display Rotation Lock Slider Switch", &[OnOffControl]),
+    (0x00CB, "Control Enable", &[DynamicFlag]),
+    (0x00D0, "Dockable Device Unique ID", &[DynamicValue]),
+    (0x00D1, "Dockable Device Vendor ID", &[DynamicValue]),
+    (0x00D2, "Dockable Device Primary Usage Page", &[DynamicValue]),
+    (0x00D3, "Dockable Device Primary Usage ID", &[DynamicValue]),
+    (0x00D4, "Dockable Device Docking State", &[DynamicFlag]),
+    (0x00D5, "Dockable Device Display Occlusion", &[LogicalCollection]),
+    (0x00D6, "Dockable Device Object Type", &[DynamicValue]),
+    (0x00E0, "Call Active LED", &[OnOffControl]),
+    (0x00E1, "Call Mute Toggle", &[OneShotControl]),
+    (0x00E2, "Call Mute LED", &[OnOffControl]),
+];
+
+
+
+/// Simulation Controls page (02h).
+const SIMULATION_CONTROLS: &[Entry] = &[
+    (0x0001, "Flight Simulation Device", &[ApplicationCollection]),
+    (0x0002, "Automobile Simulation Device", &[ApplicationCollection]),
+    (0x0003, "Tank Simulation Device", &[ApplicationCollection]),
+    (0x0004, "Spaceship Simulation Device", &[ApplicationCollection]),
+    (0x0005, "Submarine Simulation Device", &[ApplicationCollection]),
+    (0x0006, "Sailing Simulation Device", &[ApplicationCollection]),
+    (0x0007, "Motorcycle Simulation Device", &[ApplicationCollection]),
+    (0x0008, "Sports Simulation Device", &[ApplicationCollection]),
+    (0x0009, "Airplane Simulation Device", &[ApplicationCollection]),
+    (0x000A, "Helicopter Simulation Device", &[ApplicationCollection]),
+    (0x000B, "Magic Carpet Simulation Device", &[ApplicationCollection]),
+    (0x000C, "Bicycle Simulation Device", &[ApplicationCollection]),
+    (0x0020, "Flight Control Stick", &[ApplicationCollection]),
+    (0x0021, "Flight Stick", &[ApplicationCollection]),
+    (0x0022, "Cyclic Control", &[PhysicalCollection]),
+    (0x0023, "Cyclic Trim", &[PhysicalCollection]),
+    (0x0024, "Flight Yoke", &[ApplicationCollection]),
+    (0x0025, "Track Control", &[PhysicalCollection]),
+    (0x00B0, "Aileron", &[DynamicValue]),
+    (0x00B1, "Aileron Trim", &[DynamicValue]),
+    (0x00B2, "Anti-Torque Control", &[DynamicValue]),
+    (0x00B3, "Autopilot Enable", &[OnOffControl]),
+    (0x00B4, "Chaff Release", &[OneShotControl]),
+    (0x00B5, "Collective Control", &[DynamicValue]),
+    (0x00B6, "Dive Brake", &[DynamicValue]),
+    (0x00B7, "Electronic Countermeasures", &[OnOffControl]),
+    (0x00B8, "Elevator", &[DynamicValue]),
+    (0x00B9, "Elevator Trim", &[DynamicValue]),
+    (0x00BA, "Rudder", &[DynamicValue]),
+    (0x00BB, "Throttle", &[DynamicValue]),
+    (0x00BC, "Flight Communications", &[OnOffControl]),
+    (0x00BD, "Flare Release", &[OneShotControl]),
+    (0x00BE, "Landing Gear", &[OnOffControl]),
+    (0x00BF, "Toe Brake", &[DynamicValue]),
+    (0x00C0, "Trigger", &[MomentaryControl]),
+    (0x00C1, "Weapons Arm", &[OnOffControl]),
+    (0x00C2, "Weapons Select", &[OneShotControl]),
+    (0x00C3, "Wing Flaps", &[DynamicValue]),
+    (0x00C4, "Accelerator", &[DynamicValue]),
+    (0x00C5, "Brake", &[DynamicValue]),
+    (0x00C6, "Clutch", &[DynamicValue]),
+    (0x00C7, "Shifter", &[DynamicValue]),
+    (0x00C8, "Steering", &[DynamicValue]),
+    (0x00C9, "Turret Direction", &[DynamicValue]),
+    (0x00CA, "Barrel Elevation", &[DynamicValue]),
+    (0x00CB, "Dive Plane", &[DynamicValue]),
+    (0x00CC, "Ballast", &[DynamicValue]),
+    (0x00CD, "Bicycle Crank", &[DynamicValue]),
+    (0x00CE, "Handle Bars", &[DynamicValue]),
+    (0x00CF, "Front Brake", &[DynamicValue]),
+    (0x00D0, "Rear Brake", &[DynamicValue]),
+];
+
+
+
+/// Game Controls page (05h).
+const GAME_CONTROLS: &[Entry] = &[
+    (0x0001, "3D Game Controller", &[ApplicationCollection]),
+    (0x0002, "Pinball Device", &[ApplicationCollection]),
+    (0x0003, "Gun Device", &[ApplicationCollection]),
+    (0x0020, "Point of View", &[PhysicalCollection]),
+    (0x0021, "Turn Right/Left", &[DynamicValue]),
+    (0x0022, "Pitch Forward/Backward", &[DynamicValue]),
+    (0x0023, "Roll Right/Left", &[DynamicValue]),
+    (0x0024, "Move Right/Left", &[DynamicValue]),
+    (0x0025, "Move Forward/Backward", &[DynamicValue]),
+    (0x0026, "Move Up/Down", &[DynamicValue]),
+    (0x0027, "Lean Right/Left", &[DynamicValue]),
+    (0x0028, "Lean Forward/Backward", &[DynamicValue]),
+    (0x0029, "Height of POV", &[DynamicValue]),
+    (0x002A, "Flipper", &[MomentaryControl]),
+    (0x002B, "Secondary Flipper", &[MomentaryControl]),
+    (0x002C, "Bump", &[MomentaryControl]),
+    (0x002D, "New Game", &[OneShotControl]),
+    (0x002E, "Shoot Ball", &[OneShotControl]),
+    (0x002F, "Player", &[OneShotControl]),
+    (0x0030, "Gun Bolt", &[OnOffControl]),
+    (0x0031, "Gun Clip", &[OnOffControl]),
+    (0x0032, "Gun Selector", &[NamedArray]),
+    (0x0033, "Gun Single Shot", &[Selector]),
+    (0x0034, "Gun Burst", &[Selector]),
+    (0x0035, "Gun Automatic", &[Selector]),
+    (0x0036, "Gun Safety", &[OnOffControl]),
+    (0x0037, "Gamepad Fire/Jump", &[LogicalCollection]),
+    (0x0039, "Gamepad Trigger", &[LogicalCollection]),
+    (0x003A, "Form-fitting Gamepad", &[StaticFlag]),
+];
+
+
+
+/// Generic Device Controls page (06h).
+const GENERIC_DEVICE_CONTROLS: &[Entry] = &[
+    (0x0001, "Background/Nonuser Controls", &[ApplicationCollection]),
+    (0x0020, "Battery Strength", &[DynamicValue]),
+    (0x0021, "Wireless Channel", &[DynamicValue]),
+    (0x0022, "Wireless ID", &[DynamicValue]),
+    (0x0023, "Discover Wireless Control", &[OneShotControl]),
+    (0x0024, "Security Code Character Entered", &[OneShotControl]),
+    (0x0025, "Security Code Character Erased", &[OneShotControl]),
+    (0x0026, "Security Code Cleared", &[OneShotControl]),
+    (0x0027, "Sequence ID", &[DynamicValue]),
+    (0x0028, "Sequence ID Reset", &[DynamicFlag]),
+    (0x0029, "RF Signal Strength", &[DynamicValue]),
+    (0x002A, "Software Version", &[LogicalCollection]),
+    (0x002B, "Protocol Version", &[LogicalCollection]),
+    (0x002C, "Hardware Version", &[LogicalCollection]),
+    (0x002D, "Major", &[StaticValue]),
+    (0x002E, "Minor", &[StaticValue]),
+    (0x002F, "Revision", &[StaticValue]),
+    (0x0030, "Handedness", &[NamedArray]),
+    (0x0031, "Either Hand", &[Selector]),
+    (0x0032, "Left Hand", &[Selector]),
+    (0x0033, "Right Hand", &[Selector]),
+    (0x0034, "Both Hands", &[Selector]),
+    (0x0040, "Grip Pose Offset", &[PhysicalCollection]),
+    (0x0041, "Pointer Pose Offset", &[PhysicalCollection]),
+];
+
+
+
+/// Keyboard/Keypad page (07h).
+const KEYBOARD: &[Entry] = &[
+    (0x0001, "ErrorRollOver", &[Selector]),
+    (0x0002, "POSTFail", &[Selector]),
+    (0x0003, "ErrorUndefined", &[Selector]),
+    (0x0004, "Keyboard a and A", &[Selector]),
+    (0x0005, "Keyboard b and B", &[Selector]),
+    (0x0006, "Keyboard c and C", &[Selector]),
+    (0x0007, "Keyboard d and D", &[Selector]),
+    (0x0008, "Keyboard e and E", &[Selector]),
+    (0x0009, "Keyboard f and F", &[Selector]),
+    (0x000A, "Keyboard g and G", &[Selector]),
+    (0x000B, "Keyboard h and H", &[Selector]),
+    (0x000C, "Keyboard i and I", &[Selector]),
+    (0x000D, "Keyboard j and J", &[Selector]),
+    (0x000E, "Keyboard k and K", &[Selector]),
+    (0x000F, "Keyboard l and L", &[Selector]),
+    (0x0010, "Keyboard m and M", &[Selector]),
+    (0x0011, "Keyboard n and N", &[Selector]),
+    (0x0012, "Keyboard o and O", &[Selector]),
+    (0x0013, "Keyboard p and P", &[Selector]),
+    (0x0014, "Keyboard q and Q", &[Selector]),
+    (0x0015, "Keyboard r and R", &[Selector]),
+    (0x0016, "Keyboard s and S", &[Selector]),
+    (0x0017, "Keyboard t and T", &[Selector]),
+    (0x0018, "Keyboard u and U", &[Selector]),
+    (0x0019, "Keyboard v and V", &[Selector]),
+    (0x001A, "Keyboard w and W", &[Selector]),
+    (0x001B, "Keyboard x and X", &[Selector]),
+    (0x001C, "Keyboard y and Y", &[Selector]),
+    (0x001D, "Keyboard z and Z", &[Selector]),
+    (0x001E, "Keyboard 1 and !", &[Selector]),
+    (0x001F, "Keyboard 2 and @", &[Selector]),
+    (0x0020, "Keyboard 3 and #", &[Selector]),
+    (0x0021, "Keyboard 4 and $", &[Selector]),
+    (0x0022, "Keyboard 5 and %", &[Selector]),
+    (0x0023, "Keyboard 6 and ^", &[Selector]),
+    (0x0024, "Keyboard 7 and &", &[Selector]),
+    (0x0025, "Keyboard 8 and *", &[Selector]),
+    (0x0026, "Keyboard 9 and (", &[Selector]),
+    (0x0027, "Keyboard 0 and )", &[Selector]),
+    (0x0028, "Keyboard Return (ENTER)", &[Selector]),
+    (0x0029, "Keyboard ESCAPE", &[Selector]),
+    (0x002A, "Keyboard DELETE (Backspace)", &[Selector]),
+    (0x002B, "Keyboard Tab", &[Selector]),
+    (0x002C, "Keyboard Spacebar", &[Selector]),
+    (0x002D, "Keyboard - and (underscore)", &[Selector]),
+    (0x002E, "Keyboard = and +", &[Selector]),
+    (0x002F, "Keyboard [ and {", &[Selector]),
+    (0x0030, "Keyboard ] and }", &[Selector]),
+    (0x0031, "Keyboard \\ and |", &[Selector]),
+    (0x0032, "Keyboard Non-US # and ~", &[Selector]),
+    (0x0033, "Keyboard ; and :", &[Selector]),
+    (0x0034, "Keyboard ' and \"", &[Selector]),
+    (0x0035, "Keyboard Grave Accent and Tilde", &[Selector]),
+    (0x0036, "Keyboard , and <", &[Selector]),
+    (0x0037, "Keyboard . and >", &[Selector]),
+    (0x0038, "Keyboard / and ?", &[Selector]),
+    (0x0039, "Keyboard Caps Lock", &[Selector]),
+    (0x003A, "Keyboard F1", &[Selector]),
+    (0x003B, "Keyboard F2", &[Selector]),
+    (0x003C, "Keyboard F3", &[Selector]),
+    (0x003D, "Keyboard F4", &[Selector]),
+    (0x003E, "Keyboard F5", &[Selector]),
+    (0x003F, "Keyboard F6", &[Selector]),
+    (0x0040, "Keyboard F7", &[Selector]),
+    (0x0041, "Keyboard F8", &[Selector]),
+    (0x0042, "Keyboard F9", &[Selector]),
+    (0x0043, "Keyboard F10", &[Selector]),
+    (0x0044, "Keyboard F11", &[Selector]),
+    (0x0045, "Keyboard F12", &[Selector]),
+    (0x0046, "Keyboard PrintScreen", &[Selector]),
+    (0x0047, "Keyboard Scroll Lock", &[Selector]),
+    (0x0048, "Keyboard Pause", &[Selector]),
+    (0x0049, "Keyboard Insert", &[Selector]),
+    (0x004A, "Keyboard Home", &[Selector]),
+    (0x004B, "Keyboard PageUp", &[Selector]),
+    (0x004C, "Keyboard Delete Forward", &[Selector]),
+    (0x004D, "Keyboard End", &[Selector]),
+    (0x004E, "Keyboard PageDown", &[Selector]),
+    (0x004F, "Keyboard RightArrow", &[Selector]),
+    (0x0050, "Keyboard LeftArrow", &[Selector]),
+    (0x0051, "Keyboard DownArrow", &[Selector]),
+    (0x0052, "Keyboard UpArrow", &[Selector]),
+    (0x0053, "Keypad Num Lock and Clear", &[Selector]),
+    (0x0054, "Keypad /", &[Selector]),
+    (0x0055, "Keypad *", &[Selector]),
+    (0x0056, "Keypad -", &[Selector]),
+    (0x0057, "Keypad +", &[Selector]),
+    (0x0058, "Keypad ENTER", &[Selector]),
+    (0x0059, "Keypad 1 and End", &[Selector]),
+    (0x005A, "Keypad 2 and Down Arrow", &[Selector]),
+    (0x005B, "Keypad 3 and PageDn", &[Selector]),
+    (0x005C, "Keypad 4 and Left Arrow", &[Selector]),
+    (0x005D, "Keypad 5", &[Selector]),
+    (0x005E, "Keypad 6 and Right Arrow", &[Selector]),
+    (0x005F, "Keypad 7 and Home", &[Selector]),
+    (0x0060, "Keypad 8 and Up Arrow", &[Selector]),
+    (0x0061, "Keypad 9 and PageUp", &[Selector]),
+    (0x0062, "Keypad 0 and Insert", &[Selector]),
+    (0x0063, "Keypad . and Delete", &[Selector]),
+    (0x0064, "Keyboard Non-US \\ and |", &[Selector]),
+    (0x0065, "Keyboard Application", &[Selector]),
+    (0x0066, "Keyboard Power", &[Selector]),
+    (0x0067, "Keypad =", &[Selector]),
+    (0x0068, "Keyboard F13", &[Selector]),
+    (0x0069, "Keyboard F14", &[Selector]),
+    (0x006A, "Keyboard F15", &[Selector]),
+    (0x006B, "Keyboard F16", &[Selector]),
+    (0x006C, "Keyboard F17", &[Selector]),
+    (0x006D, "Keyboard F18", &[Selector]),
+    (0x006E, "Keyboard F19", &[Selector]),
+    (0x006F, "Keyboard F20", &[Selector]),
+    (0x0070, "Keyboard F21", &[Selector]),
+    (0x0071, "Keyboard F22", &[Selector]),
+    (0x0072, "Keyboard F23", &[Selector]),
+    (0x0073, "Keyboard F24", &[Selector]),
+    (0x0074, "Keyboard Execute", &[Selector]),
+    (0x0075, "Keyboard Help", &[Selector]),
+    (0x0076, "Keyboard Menu", &[Selector]),
+    (0x0077, "Keyboard Select", &[Selector]),
+    (0x0078, "Keyboard Stop", &[Selector]),
+    (0x0079, "Keyboard Again", &[Selector]),
+    (0x007A, "Keyboard Undo", &[Selector]),
+    (0x007B, "Keyboard Cut", &[Selector]),
+    (0x007C, "Keyboard Copy", &[Selector]),
+    (0x007D, "Keyboard Paste", &[Selector]),
+    (0x007E, "Keyboard Find", &[Selector]),
+    (0x007F, "Keyboard Mute", &[Selector]),
+    (0x0080, "Keyboard Volume Up", &[Selector]),
+    (0x0081, "Keyboard Volume Down", &[Selector]),
+    (0x0082, "Keyboard Locking Caps Lock", &[Selector]),
+    (0x0083, "Keyboard Locking Num Lock", &[Selector]),
+    (0x0084, "Keyboard Locking Scroll Lock", &[Selector]),
+    (0x0085, "Keypad Comma", &[Selector]),
+    (0x0086, "Keypad Equal Sign", &[Selector]),
+    (0x0087, "Keyboard International1", &[Selector]),
+    (0x0088, "Keyboard International2", &[Selector]),
+    (0x0089, "Keyboard International3", &[Selector]),
+    (0x008A, "Keyboard International4", &[Selector]),
+    (0x008B, "Keyboard International5", &[Selector]),
+    (0x008C, "Keyboard International6", &[Selector]),
+    (0x008D, "Keyboard International7", &[Selector]),
+    (0x008E, "Keyboard International8", &[Selector]),
+    (0x008F, "Keyboard International9", &[Selector]),
+    (0x0090, "Keyboard LANG1", &[Selector]),
+    (0x0091, "Keyboard LANG2", &[Selector]),
+    (0x0092, "Keyboard LANG3", &[Selector]),
+    (0x0093, "Keyboard LANG4", &[Selector]),
+    (0x0094, "Keyboard LANG5", &[Selector]),
+    (0x0095, "Keyboard LANG6", &[Selector]),
+    (0x0096, "Keyboard LANG7", &[Selector]),
+    (0x0097, "Keyboard LANG8", &[Selector]),
+    (0x0098, "Keyboard LANG9", &[Selector]),
+    (0x0099, "Keyboard Alternate Erase", &[Selector]),
+    (0x009A, "Keyboard SysReq/Attention", &[Selector]),
+    (0x009B, "Keyboard Cancel", &[Selector]),
+    (0x009C, "Keyboard Clear", &[Selector]),
+    (0x009D, "Keyboard Prior", &[Selector]),
+    (0x009E, "Keyboard Return", &[Selector]),
+    (0x009F, "Keyboard Separator", &[Selector]),
+    (0x00A0, "Keyboard Out", &[Selector]),
+    (0x00A1, "Keyboard Oper", &[Selector]),
+    (0x00A2, "Keyboard Clear/Again", &[Selector]),
+    (0x00A3, "Keyboard CrSel/Props", &[Selector]),
+    (0x00A4, "Keyboard ExSel", &[Selector]),
+    (0x00B0, "Keypad 00", &[Selector]),
+    (0x00B1, "Keypad 000", &[Selector]),
+    (0x00B2, "Thousands Separator", &[Selector]),
+    (0x00B3, "Decimal Separator", &[Selector]),
+    (0x00B4, "Currency Unit", &[Selector]),
+    (0x00B5, "Currency Sub-unit", &[Selector]),
+    (0x00B6, "Keypad (", &[Selector]),
+    (0x00B7, "Keypad )", &[Selector]),
+    (0x00B8, "Keypad {", &[Selector]),
+    (0x00B9, "Keypad }", &[Selector]),
+    (0x00BA, "Keypad Tab", &[Selector]),
+    (0x00BB, "Keypad Backspace", &[Selector]),
+    (0x00BC, "Keypad A", &[Selector]),
+    (0x00BD, "Keypad B", &[Selector]),
+    (0x00BE, "Keypad C", &[Selector]),
+    (0x00BF, "Keypad D", &[Selector]),
+    (0x00C0, "Keypad E", &[Selector]),
+    (0x00C1, "Keypad F", &[Selector]),
+    (0x00C2, "Keypad XOR", &[Selector]),
+    (0x00C3, "Keypad ^", &[Selector]),
+    (0x00C4, "Keypad %", &[Selector]),
+    (0x00C5, "Keypad <", &[Selector]),
+    (0x00C6, "Keypad >", &[Selector]),
+    (0x00C7, "Keypad &", &[Selector]),
+    (0x00C8, "Keypad &&", &[Selector]),
+    (0x00C9, "Keypad |", &[Selector]),
+    (0x00CA, "Keypad ||", &[Selector]),
+    (0x00CB, "Keypad :", &[Selector]),
+    (0x00CC, "Keypad #", &[Selector]),
+    (0x00CD, "Keypad Space", &[Selector]),
+    (0x00CE, "Keypad @", &[Selector]),
+    (0x00CF, "Keypad !", &[Selector]),
+    (0x00D0, "Keypad Memory Store", &[Selector]),
+    (0x00D1, "Keypad Memory Recall", &[Selector]),
+    (0x00D2, "Keypad Memory Clear", &[Selector]),
+    (0x00D3, "Keypad Memory Add", &[Selector]),
+    (0x00D4, "Keypad Memory Subtract", &[Selector]),
+    (0x00D5, "Keypad Memory Multiply", &[Selector]),
+    (0x00D6, "Keypad Memory Divide", &[Selector]),
+    (0x00D7, "Keypad +/-", &[Selector]),
+    (0x00D8, "Keypad Clear", &[Selector]),
+    (0x00D9, "Keypad Clear Entry", &[Selector]),
+    (0x00DA, "Keypad Binary", &[Selector]),
+    (0x00DB, "Keypad Octal", &[Selector]),
+    (0x00DC, "Keypad Decimal", &[Selector]),
+    (0x00DD, "Keypad Hexadecimal", &[Selector]),
+    (0x00E0, "Keyboard LeftControl", &[DynamicValue]),
+    (0x00E1, "Keyboard LeftShift", &[DynamicValue]),
+    (0x00E2, "Keyboard LeftAlt", &[DynamicValue]),
+    (0x00E3, "Keyboard Left GUI", &[DynamicValue]),
+    (0x00E4, "Keyboard RightControl", &[DynamicValue]),
+    (0x00E5, "Keyboard RightShift", &[DynamicValue]),
+    (0x00E6, "Keyboard RightAlt", &[DynamicValue]),
+    (0x00E7, "Keyboard Right GUI", &[DynamicValue]),
+];
+
+
+
+/// LED page (08h).
+const LED: &[Entry] = &[
+    (0x0001, "Num Lock", &[OnOffControl]),
+    (0x0002, "Caps Lock", &[OnOffControl]),
+    (0x0003, "Scroll Lock", &[OnOffControl]),
+    (0x0004, "Compose", &[OnOffControl]),
+    (0x0005, "Kana", &[OnOffControl]),
+    (0x0006, "Power", &[OnOffControl]),
+    (0x0007, "Shift", &[OnOffControl]),
+    (0x0008, "Do Not Disturb", &[OnOffControl]),
+    (0x0009, "Mute", &[OnOffControl]),
+    (0x000A, "Tone Enable", &[OnOffControl]),
+    (0x000B, "High Cut Filter", &[OnOffControl]),
+    (0x000C, "Low Cut Filter", &[OnOffControl]),
+    (0x000D, "Equalizer Enable", &[OnOffControl]),
+    (0x000E, "Sound Field On", &[OnOffControl]),
+    (0x000F, "Surround On", &[OnOffControl]),
+    (0x0010, "Repeat", &[OnOffControl]),
+    (0x0011, "Stereo", &[OnOffControl]),
+    (0x0012, "Sampling Rate Detect", &[OnOffControl]),
+    (0x0013, "Spinning", &[OnOffControl]),
+    (0x0014, "CAV", &[OnOffControl]),
+    (0x0015, "CLV", &[OnOffControl]),
+    (0x0016, "Recording Format Detect", &[OnOffControl]),
+    (0x0017, "Off-Hook", &[OnOffControl]),
+    (0x0018, "Ring", &[OnOffControl]),
+    (0x0019, "Message Waiting", &[OnOffControl]),
+    (0x001A, "Data Mode", &[OnOffControl]),
+    (0x001B, "Battery Operation", &[OnOffControl]),
+    (0x001C, "Battery OK", &[OnOffControl]),
+    (0x001D, "Battery Low", &[OnOffControl]),
+    (0x001E, "Speaker", &[OnOffControl]),
+    (0x001F, "Headset", &[OnOffControl]),
+    (0x0020, "Hold", &[OnOffControl]),
+    (0x0021, "Microphone", &[OnOffControl]),
+    (0x0022, "Coverage", &[OnOffControl]),
+    (0x0023, "Night Mode", &[OnOffControl]),
+    (0x0024, "Send Calls", &[OnOffControl]),
+    (0x0025, "Call Pickup", &[OnOffControl]),
+    (0x0026, "Conference", &[OnOffControl]),
+    (0x0027, "Stand-by", &[OnOffControl]),
+    (0x0028, "Camera On", &[OnOffControl]),
+    (0x0029, "Camera Off", &[OnOffControl]),
+    (0x002A, "On-Line", &[OnOffControl]),
+    (0x002B, "Off-Line", &[OnOffControl]),
+    (0x002C, "Busy", &[OnOffControl]),
+    (0x002D, "Ready", &[OnOffControl]),
+    (0x002E, "Paper-Out", &[OnOffControl]),
+    (0x002F, "Paper-Jam", &[OnOffControl]),
+    (0x0030, "Remote", &[OnOffControl]),
+    (0x0031, "Forward", &[OnOffControl]),
+    (0x0032, "Reverse", &[OnOffControl]),
+    (0x0033, "Stop", &[OnOffControl]),
+    (0x0034, "Rewind", &[OnOffControl]),
+    (0x0035, "Fast Forward", &[OnOffControl]),
+    (0x0036, "Play", &[OnOffControl]),
+    (0x0037, "Pause", &[OnOffControl]),
+    (0x0038, "Record", &[OnOffControl]),
+    (0x0039, "Error", &[OnOffControl]),
+    (0x003A, "Usage Selected Indicator", &[UsageSwitch]),
+    (0x003B, "Usage In Use Indicator", &[UsageSwitch]),
+    (0x003C, "Usage Multi Mode Indicator", &[UsageModifier]),
+    (0x003D, "Indicator On", &[Selector]),
+    (0x003E, "Indicator Flash", &[Selector]),
+    (0x003F, "Indicator Slow Blink", &[Selector]),
+    (0x0040, "Indicator Fast Blink", &[Selector]),
+    (0x0041, "Indicator Off", &[Selector]),
+    (0x0042, "Flash On Time", &[DynamicValue]),
+    (0x0043, "Slow Blink On Time", &[DynamicValue]),
+    (0x0044, "Slow Blink Off Time", &[DynamicValue]),
+    (0x0045, "Fast Blink On Time", &[DynamicValue]),
+    (0x0046, "Fast Blink Off Time", &[DynamicValue]),
+    (0x0047, "Usage Indicator Color", &[UsageModifier]),
+    (0x0048, "Indicator Red", &[Selector]),
+    (0x0049, "Indicator Green", &[Selector]),
+    (0x004A, "Indicator Amber", &[Selector]),
+    (0x004B, "Generic Indicator", &[OnOffControl]),
+    (0x004C, "System Suspend", &[OnOffControl]),
+    (0x004D, "External Power Connected", &[OnOffControl]),
+    (0x004E, "Indicator Blue", &[Selector]),
+    (0x004F, "Indicator Orange", &[Selector]),
+    (0x0050, "Good Status", &[OnOffControl]),
+    (0x0051, "Warning Status", &[OnOffControl]),
+    (0x0052, "RGB LED", &[LogicalCollection]),
+    (0x0053, "Red LED Channel", &[DynamicValue]),
+    (0x0054, "Blue LED Channel", &[DynamicValue]),
+    (0x0055, "Green LED Channel", &[DynamicValue]),
+    (0x0056, "LED Intensity", &[DynamicValue]),
+    (0x0060, "Player Indicator", &[NamedArray]),
+    (0x0061, "Player 1", &[Selector]),
+    (0x0062, "Player 2", &[Selector]),
+    (0x0063, "Player 3", &[Selector]),
+    (0x0064, "Player 4", &[Selector]),
+    (0x0065, "Player 5", &[Selector]),
+    (0x0066, "Player 6", &[Selector]),
+    (0x0067, "Player 7", &[Selector]),
+    (0x0068, "Player 8", &[Selector]),
+];
+
+
+
+/// Telephony Device page (0Bh).
+const TELEPHONY: &[Entry] = &[
+    (0x0001, "Phone", &[ApplicationCollection]),
+    (0x0002, "Answering Machine", &[ApplicationCollection]),
+    (0x0003, "Message Controls", &[LogicalCollection]),
+    (0x0004, "Handset", &[LogicalCollection]),
+    (0x0005, "Headset", &[LogicalCollection]),
+    (0x0006, "Telephony Key Pad", &[NamedArray]),
+    (0x0007, "Programmable Button", &[NamedArray]),
+    (0x0020, "Hook Switch", &[OnOffControl]),
+    (0x0021, "Flash", &[MomentaryControl]),
+    (0x0022, "Feature", &[OneShotControl]),
+    (0x0023, "Hold", &[OnOffControl]),
+    (0x0024, "Redial", &[OneShotControl]),
+    (0x0025, "Transfer", &[OneShotControl]),
+    (0x0026, "Drop", &[OneShotControl]),
+    (0x0027, "Park", &[OnOffControl]),
+    (0x0028, "Forward Calls", &[OnOffControl]),
+    (0x0029, "Alternate Function", &[MomentaryControl]),
+    (0x002A, "Line", &[OneShotControl, NamedArray]),
+    (0x002B, "Speaker Phone", &[OnOffControl]),
+    (0x002C, "Conference", &[OnOffControl]),
+    (0x002D, "Ring Enable", &[OnOffControl]),
+    (0x002E, "Ring Select", &[OneShotControl]),
+    (0x002F, "Phone Mute", &[OnOffControl]),
+    (0x0030, "Caller ID", &[MomentaryControl]),
+    (0x0031, "Send", &[OnOffControl]),
+    (0x0050, "Speed Dial", &[OneShotControl]),
+    (0x0051, "Store Number", &[OneShotControl]),
+    (0x0052, "Recall Number", &[OneShotControl]),
+    (0x0053, "Phone Directory", &[OnOffControl]),
+    (0x0070, "Voice Mail", &[OnOffControl]),
+    (0x0071, "Screen Calls", &[OnOffControl]),
+    (0x0072, "Do Not Disturb", &[OnOffControl]),
+    (0x0073, "Message", &[OneShotControl]),
+    (0x0074, "Answer On/Off", &[OnOffControl]),
+    (0x0090, "Inside Dial Tone", &[MomentaryControl]),
+    (0x0091, "Outside Dial Tone", &[MomentaryControl]),
+    (0x0092, "Inside Ring Tone", &[MomentaryControl]),
+    (0x0093, "Outside Ring Tone", &[MomentaryControl]),
+    (0x0094, "Priority Ring Tone", &[MomentaryControl]),
+    (0x0095, "Inside Ringback", &[MomentaryControl]),
+    (0x0096, "Priority Ringback", &[MomentaryControl]),
+    (0x0097, "Line Busy Tone", &[MomentaryControl]),
+    (0x0098, "Reorder Tone", &[MomentaryControl]),
+    (0x0099, "Call Waiting Tone", &[MomentaryControl]),
+    (0x009A, "Confirmation Tone 1", &[MomentaryControl]),
+    (0x009B, "Confirmation Tone 2", &[MomentaryControl]),
+    (0x009C, "Tones Off", &[OnOffControl]),
+    (0x009D, "Outside Ringback", &[MomentaryControl]),
+    (0x009E, "Ringer", &[OnOffControl]),
+    (0x00B0, "Phone Key 0", &[Selector]),
+    (0x00B1, "Phone Key 1", &[Selector]),
+    (0x00B2, "Phone Key 2", &[Selector]),
+    (0x00B3, "Phone Key 3", &[Selector]),
+    (0x00B4, "Phone Key 4", &[Selector]),
+    (0x00B5, "Phone Key 5", &[Selector]),
+    (0x00B6, "Phone Key 6", &[Selector]),
+    (0x00B7, "Phone Key 7", &[Selector]),
+    (0x00B8, "Phone Key 8", &[Selector]),
+    (0x00B9, "Phone Key 9", &[Selector]),
+    (0x00BA, "Phone Key Star", &[Selector]),
+    (0x00BB, "Phone Key Pound", &[Selector]),
+    (0x00BC, "Phone Key A", &[Selector]),
+    (0x00BD, "Phone Key B", &[Selector]),
+    (0x00BE, "Phone Key C", &[Selector]),
+    (0x00BF, "Phone Key D", &[Selector]),
+    (0x00C0, "Phone Call History Key", &[Selector]),
+    (0x00C1, "Phone Caller ID Key", &[Selector]),
+    (0x00C2, "Phone Settings Key", &[Selector]),
+    (0x00F0, "Host Control", &[OnOffControl]),
+    (0x00F1, "Host Available", &[OnOffControl]),
+    (0x00F2, "Host Call Active", &[OnOffControl]),
+    (0x00F3, "Activate Handset Audio", &[OnOffControl]),
+    (0x00F4, "Ring Type", &[NamedArray]),
+    (0x00F5, "Re-dialable Phone Number", &[OnOffControl]),
+    (0x00F8, "Stop Ring Tone", &[Selector]),
+    (0x00F9, "PSTN Ring Tone", &[Selector]),
+    (0x00FA, "Host Ring Tone", &[Selector]),
+    (0x00FB, "Alert Sound Error", &[Selector]),
+    (0x00FC, "Alert Sound Confirm", &[Selector]),
+    (0x00FD, "Alert Sound Notification", &[Selector]),
+    (0x00FE, "Silent Ring", &[Selector]),
+    (0x0108, "Email Message Waiting", &[OnOffControl]),
+    (0x0109, "Voicemail Message Waiting", &[OnOffControl]),
+    (0x010A, "Host Hold", &[OnOffControl]),
+    (0x0110, "Incoming Call History Count", &[DynamicValue]),
+    (0x0111, "Outgoing Call History Count", &[DynamicValue]),
+    (0x0112, "Incoming Call History", &[LogicalCollection]),
+    (0x0113, "Outgoing Call History", &[LogicalCollection]),
+    (0x0114, "Phone Locale", &[DynamicValue]),
+    (0x0140, "Phone Time Second", &[DynamicValue]),
+    (0x0141, "Phone Time Minute", &[DynamicValue]),
+    (0x0142, "Phone Time Hour", &[DynamicValue]),
+    (0x0143, "Phone Date Day", &[DynamicValue]),
+    (0x0144, "Phone Date Month", &[DynamicValue]),
+    (0x0145, "Phone Date Year", &[DynamicValue]),
+    (0x0146, "Handset Nickname", &[DynamicValue]),
+    (0x0147, "Address Book ID", &[DynamicValue]),
+    (0x014A, "Call Duration", &[DynamicValue]),
+    (0x014B, "Dual Mode Phone", &[ApplicationCollection]),
+];
+
+
+
+/// Consumer page (0Ch).
+const CONSUMER: &[Entry] = &[
+    (0x0001, "Consumer Control", &[ApplicationCollection]),
+    (0x0002, "Numeric Key Pad", &[NamedArray]),
+    (0x0003, "Programmable Buttons", &[NamedArray]),
+    (0x0004, "Microphone", &[ApplicationCollection]),
+    (0x0005, "Headphone", &[ApplicationCollection]),
+    (0x0006, "Graphic Equalizer", &[ApplicationCollection]),
+    (0x0020, "+10", &[OneShotControl]),
+    (0x0021, "+100", &[OneShotControl]),
+    (0x0022, "AM/PM", &[OneShotControl]),
+    (0x0030, "Power", &[OnOffControl]),
+    (0x0031, "Reset", &[OneShotControl]),
+    (0x0032, "Sleep", &[OneShotControl]),
+    (0x0033, "Sleep After", &[OneShotControl]),
+    (0x0034, "Sleep Mode", &[RetriggerControl]),
+    (0x0035, "Illumination", &[OnOffControl]),
+    (0x0036, "Function Buttons", &[NamedArray]),
+    (0x0040, "Menu", &[OnOffControl]),
+    (0x0041, "Menu Pick", &[OneShotControl]),
+    (0x0042, "Menu Up", &[OneShotControl]),
+    (0x0043, "Menu Down", &[OneShotControl]),
+    (0x0044, "Menu Left", &[OneShotControl]),
+    (0x0045, "Menu Right", &[OneShotControl]),
+    (0x0046, "Menu Escape", &[OneShotControl]),
+    (0x0047, "Menu Value Increase", &[OneShotControl]),
+    (0x0048, "Menu Value Decrease", &[OneShotControl]),
+    (0x0060, "Data On Screen", &[OnOffControl]),
+    (0x0061, "Closed Caption", &[OnOffControl]),
+    (0x0062, "Closed Caption Select", &[Selector]),
+    (0x0063, "VCR/TV", &[OnOffControl]),
+    (0x0064, "Broadcast Mode", &[OneShotControl]),
+    (0x0065, "Snapshot", &[OneShotControl]),
+    (0x0066, "Still", &[OneShotControl]),
+    (0x0067, "Picture-in-Picture Toggle", &[OneShotControl]),
+    (0x0068, "Picture-in-Picture Swap", &[OneShotControl]),
+    (0x0069, "Red Menu Button", &[MomentaryControl]),
+    (0x006A, "Green Menu Button", &[MomentaryControl]),
+    (0x006B, "Blue Menu Button", &[MomentaryControl]),
+    (0x006C, "Yellow Menu Button", &[MomentaryControl]),
+    (0x006D, "Aspect", &[OneShotControl]),
+    (0x006E, "3D Mode Select", &[OneShotControl]),
+    (0x006F, "Display Brightness Increment", &[RetriggerControl]),
+    (0x0070, "Display Brightness Decrement", &[RetriggerControl]),
+    (0x0071, "Display Brightness", &[LinearControl]),
+    (0x0072, "Display Backlight Toggle", &[OnOffControl]),
+    (0x0073, "Display Set Brightness to Minimum", &[OneShotControl]),
+    (0x0074, "Display Set Brightness to Maximum", &[OneShotControl]),
+    (0x0075, "Display Set Auto Brightness", &[OnOffControl]),
+    (0x0076, "Camera Access Enabled", &[OnOffControl]),
+    (0x0077, "Camera Access Disabled", &[OnOffControl]),
+    (0x0078, "Camera Access Toggle", &[OnOffControl]),
+    (0x0079, "Keyboard Brightness Increment", &[OneShotControl]),
+    (0x007A, "Keyboard Brightness Decrement", &[OneShotControl]),
+    (0x007B, "Keyboard Backlight Set Level", &[LinearControl]),
+    (0x007C, "Keyboard Backlight OOC", &[OnOffControl]),
+    (0x007D, "Keyboard Backlight Set Minimum", &[OneShotControl]),
+    (0x007E, "Keyboard Backlight Set Maximum", &[OneShotControl]),
+    (0x007F, "Keyboard Backlight Auto", &[OnOffControl]),
+    (0x0080, "Selection", &[NamedArray]),
+    (0x0081, "Assign Selection", &[OneShotControl]),
+    (0x0082, "Mode Step", &[OneShotControl]),
+    (0x0083, "Recall Last", &[OneShotControl]),
+    (0x0084, "Enter Channel", &[OneShotControl]),
+    (0x0085, "Order Movie", &[OneShotControl]),
+    (0x0086, "Channel", &[LinearControl]),
+    (0x0087, "Media Selection", &[NamedArray]),
+    (0x0088, "Media Select Computer", &[Selector]),
+    (0x0089, "Media Select TV", &[Selector]),
+    (0x008A, "Media Select WWW", &[Selector]),
+    (0x008B, "Media Select DVD", &[Selector]),
+    (0x008C, "Media Select Telephone", &[Selector]),
+    (0x008D, "Media Select Program Guide", &[Selector]),
+    (0x008E, "Media Select Video Phone", &[Selector]),
+    (0x008F, "Media Select Games", &[Selector]),
+    (0x0090, "Media Select Messages", &[Selector]),
+    (0x0091, "Media Select CD", &[Selector]),
+    (0x0092, "Media Select VCR", &[Selector]),
+    (0x0093, "Media Select Tuner", &[Selector]),
+    (0x0094, "Quit", &[OneShotControl]),
+    (0x0095, "Help", &[OnOffControl]),
+    (0x0096, "Media Select Tape", &[Selector]),
+    (0x0097, "Media Select Cable", &[Selector]),
+    (0x0098, "Media Select Satellite", &[Selector]),
+    (0x0099, "Media Select Security", &[Selector]),
+    (0x009A, "Media Select Home", &[Selector]),
+    (0x009B, "Media Select Call", &[Selector]),
+    (0x009C, "Channel Increment", &[OneShotControl]),
+    (0x009D, "Channel Decrement", &[OneShotControl]),
+    (0x009E, "Media Select SAP", &[Selector]),
+    (0x00A0, "VCR Plus", &[OneShotControl]),
+    (0x00A1, "Once", &[OneShotControl]),
+    (0x00A2, "Daily", &[OneShotControl]),
+    (0x00A3, "Weekly", &[OneShotControl]),
+    (0x00A4, "Monthly", &[OneShotControl]),
+    (0x00B0, "Play", &[OnOffControl]),
+    (0x00B1, "Pause", &[OnOffControl]),
+    (0x00B2, "Record", &[OnOffControl]),
+    (0x00B3, "Fast Forward", &[OnOffControl]),
+    (0x00B4, "Rewind", &[OnOffControl]),
+    (0x00B5, "Scan Next Track", &[OneShotControl]),
+    (0x00B6, "Scan Previous Track", &[OneShotControl]),
+    (0x00B7, "Stop", &[OneShotControl]),
+    (0x00B8, "Eject", &[OneShotControl]),
+    (0x00B9, "Random Play", &[OnOffControl]),
+    (0x00BA, "Select Disc", &[NamedArray]),
+    (0x00BB, "Enter Disc", &[MomentaryControl]),
+    (0x00BC, "Repeat", &[OneShotControl]),
+    (0x00BD, "Tracking", &[LinearControl]),
+    (0x00BE, "Track Normal", &[OneShotControl]),
+    (0x00BF, "Slow Tracking", &[LinearControl]),
+    (0x00C0, "Frame Forward", &[RetriggerControl]),
+    (0x00C1, "Frame Back", &[RetriggerControl]),
+    (0x00C2, "Mark", &[OneShotControl]),
+    (0x00C3, "Clear Mark", &[OneShotControl]),
+    (0x00C4, "Repeat From Mark", &[OnOffControl]),
+    (0x00C5, "Return To Mark", &[OneShotControl]),
+    (0x00C6, "Search Mark Forward", &[OneShotControl]),
+    (0x00C7, "Search Mark Backwards", &[OneShotControl]),
+    (0x00C8, "Counter Reset", &[OneShotControl]),
+    (0x00C9, "Show Counter", &[OneShotControl]),
+    (0x00CA, "Tracking Increment", &[RetriggerControl]),
+    (0x00CB, "Tracking Decrement", &[RetriggerControl]),
+    (0x00CC, "Stop/Eject", &[OneShotControl]),
+    (0x00CD, "Play/Pause", &[OneShotControl]),
+    (0x00CE, "Play/Skip", &[OneShotControl]),
+    (0x00CF, "Voice Command", &[OneShotControl]),
+    (0x00D0, "Invoke Capture Interface", &[Selector]),
+    (0x00D1, "Start or Stop Game Recording", &[Selector]),
+    (0x00D2, "Historical Game Capture", &[Selector]),
+    (0x00D3, "Capture Game Screenshot", &[Selector]),
+    (0x00D4, "Show or Hide Recording Indicator", &[Selector]),
+    (0x00D5, "Start or Stop Microphone Capture", &[Selector]),
+    (0x00D6, "Start or Stop Camera Capture", &[Selector]),
+    (0x00D7, "Start or Stop Game Broadcast", &[Selector]),
+    (0x00D8, "Start or Stop Voice Dictation Session", &[OnOffControl]),
+    (0x00D9, "Invoke/Dismiss Emoji Picker", &[OnOffControl]),
+    (0x00E0, "Volume", &[LinearControl]),
+    (0x00E1, "Balance", &[LinearControl]),
+    (0x00E2, "Mute", &[OnOffControl]),
+    (0x00E3, "Bass", &[LinearControl]),
+    (0x00E4, "Treble", &[LinearControl]),
+    (0x00E5, "Bass Boost", &[OnOffControl]),
+    (0x00E6, "Surround Mode", &[OneShotControl]),
+    (0x00E7, "Loudness", &[OnOffControl]),
+    (0x00E8, "MPX", &[OnOffControl]),
+    (0x00E9, "Volume Increment", &[RetriggerControl]),
+    (0x00EA, "Volume Decrement", &[RetriggerControl]),
+    (0x00F0, "Speed Select", &[OneShotControl]),
+    (0x00F1, "Playback Speed", &[NamedArray]),
+    (0x00F2, "Standard Play", &[Selector]),
+    (0x00F3, "Long Play", &[Selector]),
+    (0x00F4, "Extended Play", &[Selector]),
+    (0x00F5, "Slow", &[OneShotControl]),
+    (0x0100, "Fan Enable", &[OnOffControl]),
+    (0x0101, "Fan Speed", &[LinearControl]),
+    (0x0102, "Light Enable", &[OnOffControl]),
+    (0x0103, "Light Illumination Level", &[LinearControl]),
+    (0x0104, "Climate Control Enable", &[OnOffControl]),
+    (0x0105, "Room Temperature", &[LinearControl]),
+    (0x0106, "Security Enable", &[OnOffControl]),
+    (0x0107, "Fire Alarm", &[OneShotControl]),
+    (0x0108, "Police Alarm", &[OneShotControl]),
+    (0x0109, "Proximity", &[LinearControl]),
+    (0x010A, "Motion", &[OneShotControl]),
+    (0x010B, "Duress Alarm", &[OneShotControl]),
+    (0x010C, "Holdup Alarm", &[OneShotControl]),
+    (0x010D, "Medical Alarm", &[OneShotControl]),
+    (0x0150, "Balance Right", &[RetriggerControl]),
+    (0x0151, "Balance Left", &[RetriggerControl]),
+    (0x0152, "Bass Increment", &[RetriggerControl]),
+    (0x0153, "Bass Decrement", &[RetriggerControl]),
+    (0x0154, "Treble Increment", &[RetriggerControl]),
+    (0x0155, "Treble Decrement", &[RetriggerControl]),
+    (0x0160, "Speaker System", &[LogicalCollection]),
+    (0x0161, "Channel Left", &[LogicalCollection]),
+    (0x0162, "Channel Right", &[LogicalCollection]),
+    (0x0163, "Channel Center", &[LogicalCollection]),
+    (0x0164, "Channel Front", &[LogicalCollection]),
+    (0x0165, "Channel Center Front", &[LogicalCollection]),
+    (0x0166, "Channel Side", &[LogicalCollection]),
+    (0x0167, "Channel Surround", &[LogicalCollection]),
+    (0x0168, "Channel Low Frequency Enhancement", &[LogicalCollection]),
+    (0x0169, "Channel Top", &[LogicalCollection]),
+    (0x016A, "Channel Unknown", &[LogicalCollection]),
+    (0x0170, "Sub-channel", &[LinearControl]),
+    (0x0171, "Sub-channel Increment", &[OneShotControl]),
+    (0x0172, "Sub-channel Decrement", &[OneShotControl]),
+    (0x0173, "Alternate Audio Increment", &[OneShotControl]),
+    (0x0174, "Alternate Audio Decrement", &[OneShotControl]),
+    (0x0180, "Application Launch Buttons", &[NamedArray]),
+    (0x0181, "AL Launch Button Configuration Tool", &[Selector]),
+    (0x0182, "AL Programmable Button Configuration", &[Selector]),
+    (0x0183, "AL Consumer Control Configuration", &[Selector]),
+    (0x0184, "AL Word Processor", &[Selector]),
+    (0x0185, "AL Text Editor", &[Selector]),
+    (0x0186, "AL Spreadsheet", &[Selector]),
+    (0x0187, "AL Graphics Editor", &[Selector]),
+    (0x0188, "AL Presentation App", &[Selector]),
+    (0x0189, "AL Database App", &[Selector]),
+    (0x018A, "AL Email Reader", &[Selector]),
+    (0x018B, "AL Newsreader", &[Selector]),
+    (0x018C, "AL Voicemail", &[Selector]),
+    (0x018D, "AL Contacts/Address Book", &[Selector]),
+    (0x018E, "AL Calendar/Schedule", &[Selector]),
+    (0x018F, "AL Task/Project Manager", &[Selector]),
+    (0x0190, "AL Log/Journal/Timecard", &[Selector]),
+    (0x0191, "AL Checkbook/Finance", &[Selector]),
+    (0x0192, "AL Calculator", &[Selector]),
+    (0x0193, "AL A/V Capture/Playback", &[Selector]),
+    (0x0194, "AL Local Machine Browser", &[Selector]),
+    (0x0195, "AL LAN/WAN Browser", &[Selector]),
+    (0x0196, "AL Internet Browser", &[Selector]),
+    (0x0197, "AL Remote Networking/ISP Connect", &[Selector]),
+    (0x0198, "AL Network Conference", &[Selector]),
+    (0x0199, "AL Network Chat", &[Selector]),
+    (0x019A, "AL Telephony/Dialer", &[Selector]),
+    (0x019B, "AL Logon", &[Selector]),
+    (0x019C, "AL Logoff", &[Selector]),
+    (0x019D, "AL Logon/Logoff", &[Selector]),
+    (0x019E, "AL Terminal Lock/Screensaver", &[Selector]),
+    (0x019F, "AL Control Panel", &[Selector]),
+    (0x01A0, "AL Command Line Processor/Run", &[Selector]),
+    (0x01A1, "AL Process/Task Manager", &[Selector]),
+    (0x01A2, "AL Select Task/Application", &[Selector]),
+    (0x01A3, "AL Next Task/Application", &[Selector]),
+    (0x01A4, "AL Previous Task/Application", &[Selector]),
+    (0x01A5, "AL Preemptive Halt Task/Application", &[Selector]),
+    (0x01A6, "AL Integrated Help Center", &[Selector]),
+    (0x01A7, "AL Documents", &[Selector]),
+    (0x01A8, "AL Thesaurus", &[Selector]),
+    (0x01A9, "AL Dictionary", &[Selector]),
+    (0x01AA, "AL Desktop", &[Selector]),
+    (0x01AB, "AL Spell Check", &[Selector]),
+    (0x01AC, "AL Grammar Check", &[Selector]),
+    (0x01AD, "AL Wireless Status", &[Selector]),
+    (0x01AE, "AL Keyboard Layout", &[Selector]),
+    (0x01AF, "AL Virus Protection", &[Selector]),
+    (0x01B0, "AL Encryption", &[Selector]),
+    (0x01B1, "AL Screen Saver", &[Selector]),
+    (0x01B2, "AL Alarms", &[Selector]),
+    (0x01B3, "AL Clock", &[Selector]),
+    (0x01B4, "AL File Browser", &[Selector]),
+    (0x01B5, "AL Power Status", &[Selector]),
+    (0x01B6, "AL Image Browser", &[Selector]),
+    (0x01B7, "AL Audio Browser", &[Selector]),
+    (0x01B8, "AL Movie Browser", &[Selector]),
+    (0x01B9, "AL Digital Rights Manager", &[Selector]),
+    (0x01BA, "AL Digital Wallet", &[Selector]),
+    (0x01BC, "AL Instant Messaging", &[Selector]),
+    (0x01BD, "AL OEM Features/Tips/Tutorial Browser", &[Selector]),
+    (0x01BE, "AL OEM Help", &[Selector]),
+    (0x01BF, "AL Online Community", &[Selector]),
+    (0x01C0, "AL Entertainment Content Browser", &[Selector]),
+    (0x01C1, "AL Online Shopping Browser", &[Selector]),
+    (0x01C2, "AL SmartCard Information/Help", &[Selector]),
+    (0x01C3, "AL Market Monitor/Finance Browser", &[Selector]),
+    (0x01C4, "AL Customized Corporate News Browser", &[Selector]),
+    (0x01C5, "AL Online Activity Browser", &[Selector]),
+    (0x01C6, "AL Research/Search Browser", &[Selector]),
+    (0x01C7, "AL Audio Player", &[Selector]),
+    (0x01C8, "AL Message Status", &[Selector]),
+    (0x01C9, "AL Contact Sync", &[Selector]),
+    (0x01CA, "AL Navigation", &[Selector]),
+    (0x01CB, "AL Context-aware Desktop Assistant", &[Selector]),
+    (0x0200, "Generic GUI Application Controls", &[NamedArray]),
+    (0x0201, "AC New", &[Selector]),
+    (0x0202, "AC Open", &[Selector]),
+    (0x0203, "AC Close", &[Selector]),
+    (0x0204, "AC Exit", &[Selector]),
+    (0x0205, "AC Maximize", &[Selector]),
+    (0x0206, "AC Minimize", &[Selector]),
+    (0x0207, "AC Save", &[Selector]),
+    (0x0208, "AC Print", &[Selector]),
+    (0x0209, "AC Properties", &[Selector]),
+    (0x021A, "AC Undo", &[Selector]),
+    (0x021B, "AC Copy", &[Selector]),
+    (0x021C, "AC Cut", &[Selector]),
+    (0x021D, "AC Paste", &[Selector]),
+    (0x021E, "AC Select All", &[Selector]),
+    (0x021F, "AC Find", &[Selector]),
+    (0x0220, "AC Find and Replace", &[Selector]),
+    (0x0221, "AC Search", &[Selector]),
+    (0x0222, "AC Go To", &[Selector]),
+    (0x0223, "AC Home", &[Selector]),
+    (0x0224, "AC Back", &[Selector]),
+    (0x0225, "AC Forward", &[Selector]),
+    (0x0226, "AC Stop", &[Selector]),
+    (0x0227, "AC Refresh", &[Selector]),
+    (0x0228, "AC Previous Link", &[Selector]),
+    (0x0229, "AC Next Link", &[Selector]),
+    (0x022A, "AC Bookmarks", &[Selector]),
+    (0x022B, "AC History", &[Selector]),
+    (0x022C, "AC Subscriptions", &[Selector]),
+    (0x022D, "AC Zoom In", &[Selector]),
+    (0x022E, "AC Zoom Out", &[Selector]),
+    (0x022F, "AC Zoom", &[LinearControl]),
+    (0x0230, "AC Full Screen View", &[Selector]),
+    (0x0231, "AC Normal View", &[Selector]),
+    (0x0232, "AC View Toggle", &[Selector]),
+    (0x0233, "AC Scroll Up", &[Selector]),
+    (0x0234, "AC Scroll Down", &[Selector]),
+    (0x0235, "AC Scroll", &[LinearControl]),
+    (0x0236, "AC Pan Left", &[Selector]),
+    (0x0237, "AC Pan Right", &[Selector]),
+    (0x0238, "AC Pan", &[LinearControl]),
+    (0x0239, "AC New Window", &[Selector]),
+    (0x023A, "AC Tile Horizontally", &[Selector]),
+    (0x023B, "AC Tile Vertically", &[Selector]),
+    (0x023C, "AC Format", &[Selector]),
+    (0x023D, "AC Edit", &[Selector]),
+    (0x023E, "AC Bold", &[Selector]),
+    (0x023F, "AC Italics", &[Selector]),
+    (0x0240, "AC Underline", &[Selector]),
+    (0x0241, "AC Strikethrough", &[Selector]),
+    (0x0242, "AC Subscript", &[Selector]),
+    (0x0243, "AC Superscript", &[Selector]),
+    (0x0244, "AC All Caps", &[Selector]),
+    (0x0245, "AC Rotate", &[Selector]),
+    (0x0246, "AC Resize", &[Selector]),
+    (0x0247, "AC Flip Horizontal", &[Selector]),
+    (0x0248, "AC Flip Vertical", &[Selector]),
+    (0x0249, "AC Mirror Horizontal", &[Selector]),
+    (0x024A, "AC Mirror Vertical", &[Selector]),
+    (0x024B, "AC Font Select", &[Selector]),
+    (0x024C, "AC Font Color", &[Selector]),
+    (0x024D, "AC Font Size", &[Selector]),
+    (0x024E, "AC Justify Left", &[Selector]),
+    (0x024F, "AC Justify Center H", &[Selector]),
+    (0x0250, "AC Justify Right", &[Selector]),
+    (0x0251, "AC Justify Block H", &[Selector]),
+    (0x0252, "AC Justify Top", &[Selector]),
+    (0x0253, "AC Justify Center V", &[Selector]),
+    (0x0254, "AC Justify Bottom", &[Selector]),
+    (0x0255, "AC Justify Block V", &[Selector]),
+    (0x0256, "AC Indent Decrease", &[Selector]),
+    (0x0257, "AC Indent Increase", &[Selector]),
+    (0x0258, "AC Numbered List", &[Selector]),
+    (0x0259, "AC Restart Numbering", &[Selector]),
+    (0x025A, "AC Bulleted List", &[Selector]),
+    (0x025B, "AC Promote", &[Selector]),
+    (0x025C, "AC Demote", &[Selector]),
+    (0x025D, "AC Yes", &[Selector]),
+    (0x025E, "AC No", &[Selector]),
+    (0x025F, "AC Cancel", &[Selector]),
+    (0x0260, "AC Catalog", &[Selector]),
+    (0x0261, "AC Buy/Checkout", &[Selector]),
+    (0x0262, "AC Add to Cart", &[Selector]),
+    (0x0263, "AC Expand", &[Selector]),
+    (0x0264, "AC Expand All", &[Selector]),
+    (0x0265, "AC Collapse", &[Selector]),
+    (0x0266, "AC Collapse All", &[Selector]),
+    (0x0267, "AC Print Preview", &[Selector]),
+    (0x0268, "AC Paste Special", &[Selector]),
+    (0x0269, "AC Insert Mode", &[Selector]),
+    (0x026A, "AC Delete", &[Selector]),
+    (0x026B, "AC Lock", &[Selector]),
+    (0x026C, "AC Unlock", &[Selector]),
+    (0x026D, "AC Protect", &[Selector]),
+    (0x026E, "AC Unprotect", &[Selector]),
+    (0x026F, "AC Attach Comment", &[Selector]),
+    (0x0270, "AC Delete Comment", &[Selector]),
+    (0x0271, "AC View Comment", &[Selector]),
+    (0x0272, "AC Select Word", &[Selector]),
+    (0x0273, "AC Select Sentence", &[Selector]),
+    (0x0274, "AC Select Paragraph", &[Selector]),
+    (0x0275, "AC Select Column", &[Selector]),
+    (0x0276, "AC Select Row", &[Selector]),
+    (0x0277, "AC Select Table", &[Selector]),
+    (0x0278, "AC Select Object", &[Selector]),
+    (0x0279, "AC Redo/Repeat", &[Selector]),
+    (0x027A, "AC Sort", &[Selector]),
+    (0x027B, "AC Sort Ascending", &[Selector]),
+    (0x027C, "AC Sort Descending", &[Selector]),
+    (0x027D, "AC Filter", &[Selector]),
+    (0x027E, "AC Set Clock", &[Selector]),
+    (0x027F, "AC View Clock", &[Selector]),
+    (0x0280, "AC Select Time Zone", &[Selector]),
+    (0x0281, "AC Edit Time Zones", &[Selector]),
+    (0x0282, "AC Set Alarm", &[Selector]),
+    (0x0283, "AC Clear Alarm", &[Selector]),
+    (0x0284, "AC Snooze Alarm", &[Selector]),
+    (0x0285, "AC Reset Alarm", &[Selector]),
+    (0x0286, "AC Synchronize", &[Selector]),
+    (0x0287, "AC Send/Receive", &[Selector]),
+    (0x0288, "AC Send To", &[Selector]),
+    (0x0289, "AC Reply", &[Selector]),
+    (0x028A, "AC Reply All", &[Selector]),
+    (0x028B, "AC Forward Msg", &[Selector]),
+    (0x028C, "AC Send", &[Selector]),
+    (0x028D, "AC Attach File", &[Selector]),
+    (0x028E, "AC Upload", &[Selector]),
+    (0x028F, "AC Download (Save Target As)", &[Selector]),
+    (0x0290, "AC Set Borders", &[Selector]),
+    (0x0291, "AC Insert Row", &[Selector]),
+    (0x0292, "AC Insert Column", &[Selector]),
+    (0x0293, "AC Insert File", &[Selector]),
+    (0x0294, "AC Insert Picture", &[Selector]),
+    (0x0295, "AC Insert Object", &[Selector]),
+    (0x0296, "AC Insert Symbol", &[Selector]),
+    (0x0297, "AC Save and Close", &[Selector]),
+    (0x0298, "AC Rename", &[Selector]),
+    (0x0299, "AC Merge", &[Selector]),
+    (0x029A, "AC Split", &[Selector]),
+    (0x029B, "AC Distribute Horizontally", &[Selector]),
+    (0x029C, "AC Distribute Vertically", &[Selector]),
+    (0x029D, "AC Next Keyboard Layout Select", &[Selector]),
+    (0x029E, "AC Navigation Guidance", &[Selector]),
+    (0x029F, "AC Desktop Show All Windows", &[Selector]),
+    (0x02A0, "AC Soft Key Left", &[Selector]),
+    (0x02A1, "AC Soft Key Right", &[Selector]),
+    (0x02A2, "AC Desktop Show All Applications", &[Selector]),
+    (0x02B0, "AC Idle Keep Alive", &[Selector]),
+    (0x02C0, "Extended Keyboard Attributes Collection", &[LogicalCollection]),
+    (0x02C1, "Keyboard Form Factor", &[StaticValue]),
+    (0x02C2, "Keyboard Key Type", &[StaticValue]),
+    (0x02C3, "Keyboard Physical Layout", &[StaticValue]),
+    (0x02C4, "Vendor-Specific Keyboard Physical Layout", &[StaticValue]),
+    (0x02C5, "Keyboard IETF Language Tag Index", &[StaticValue]),
+    (0x02C6, "Implemented Keyboard Input Assist Controls", &[StaticValue]),
+    (0x02C7, "Keyboard Input Assist Previous", &[Selector]),
+    (0x02C8, "Keyboard Input Assist Next", &[Selector]),
+    (0x02C9, "Keyboard Input Assist Previous Group", &[Selector]),
+    (0x02CA, "Keyboard Input Assist Next Group", &[Selector]),
+    (0x02CB, "Keyboard Input Assist Accept", &[Selector]),
+    (0x02CC, "Keyboard Input Assist Cancel", &[Selector]),
+    (0x02D0, "Privacy Screen Toggle", &[OnOffControl]),
+    (0x02D1, "Privacy Screen Level Decrement", &[RetriggerControl]),
+    (0x02D2, "Privacy Screen Level Increment", &[RetriggerControl]),
+    (0x02D3, "Privacy Screen Level Minimum", &[OneShotControl]),
+    (0x02D4, "Privacy Screen Level Maximum", &[OneShotControl]),
+    (0x0500, "Contact Edited", &[OnOffControl]),
+    (0x0501, "Contact Added", &[OnOffControl]),
+    (0x0502, "Contact Record Active", &[OnOffControl]),
+    (0x0503, "Contact Index", &[DynamicValue]),
+    (0x0504, "Contact Nickname", &[DynamicValue]),
+    (0x0505, "Contact First Name", &[DynamicValue]),
+    (0x0506, "Contact Last Name", &[DynamicValue]),
+    (0x0507, "Contact Full Name", &[DynamicValue]),
+    (0x0508, "Contact Phone Number Personal", &[DynamicValue]),
+    (0x0509, "Contact Phone Number Business", &[DynamicValue]),
+    (0x050A, "Contact Phone Number Mobile", &[DynamicValue]),
+    (0x050B, "Contact Phone Number Pager", &[DynamicValue]),
+    (0x050C, "Contact Phone Number Fax", &[DynamicValue]),
+    (0x050D, "Contact Phone Number Other", &[DynamicValue]),
+    (0x050E, "Contact Email Personal", &[DynamicValue]),
+    (0x050F, "Contact Email Business", &[DynamicValue]),
+    (0x0510, "Contact Email Other", &[DynamicValue]),
+    (0x0511, "Contact Email Main", &[DynamicValue]),
+    (0x0512, "Contact Speed Dial Number", &[DynamicValue]),
+    (0x0513, "Contact Status Flag", &[DynamicValue]),
+    (0x0514, "Contact Misc.", &[DynamicValue]),
+];
+
+
+
+/// Digitizers page (0Dh).
+const DIGITIZERS: &[Entry] = &[
+    (0x0001, "Digitizer", &[ApplicationCollection]),
+    (0x0002, "Pen", &[ApplicationCollection]),
+    (0x0003, "Light Pen", &[ApplicationCollection]),
+    (0x0004, "Touch Screen", &[ApplicationCollection]),
+    (0x0005, "Touch Pad", &[ApplicationCollection]),
+    (0x0006, "Whiteboard", &[ApplicationCollection]),
+    (0x0007, "Coordinate Measuring Machine", &[ApplicationCollection]),
+    (0x0008, "3D Digitizer", &[ApplicationCollection]),
+    (0x0009, "Stereo Plotter", &[ApplicationCollection]),
+    (0x000A, "Articulated Arm", &[ApplicationCollection]),
+    (0x000B, "Armature", &[ApplicationCollection]),
+    (0x000C, "Multiple Point Digitizer", &[ApplicationCollection]),
+    (0x000D, "Free Space Wand", &[ApplicationCollection]),
+    (0x000E, "Device Configuration", &[ApplicationCollection]),
+    (0x000F, "Capacitive Heat Map Digitizer", &[ApplicationCollection]),
+    (0x0020, "Stylus", &[ApplicationCollection, LogicalCollection]),
+    (0x0021, "Puck", &[LogicalCollection]),
+    (0x0022, "Finger", &[LogicalCollection]),
+    (0x0023, "Device settings", &[LogicalCollection]),
+    (0x0024, "Character Gesture", &[LogicalCollection]),
+    (0x0030, "Tip Pressure", &[DynamicValue]),
+    (0x0031, "Barrel Pressure", &[DynamicValue]),
+    (0x0032, "In Range", &[MomentaryControl]),
+    (0x0033, "Touch", &[MomentaryControl]),
+    (0x0034, "Untouch", &[OneShotControl]),
+    (0x0035, "Tap", &[OneShotControl]),
+    (0x0036, "Quality", &[DynamicValue]),
+    (0x0037, "Data Valid", &[MomentaryControl]),
+    (0x0038, "Transducer Index", &[DynamicValue]),
+    (0x0039, "Tablet Function Keys", &[LogicalCollection]),
+    (0x003A, "Program Change Keys", &[LogicalCollection]),
+    (0x003B, "Battery Strength", &[DynamicValue]),
+    (0x003C, "Invert", &[MomentaryControl]),
+    (0x003D, "X Tilt", &[DynamicValue]),
+    (0x003E, "Y Tilt", &[DynamicValue]),
+    (0x003F, "Azimuth", &[DynamicValue]),
+    (0x0040, "Altitude", &[DynamicValue]),
+    (0x0041, "Twist", &[DynamicValue]),
+    (0x0042, "Tip Switch", &[MomentaryControl]),
+    (0x0043, "Secondary Tip Switch", &[MomentaryControl]),
+    (0x0044, "Barrel Switch", &[MomentaryControl]),
+    (0x0045, "Eraser", &[MomentaryControl]),
+    (0x0046, "Tablet Pick", &[MomentaryControl]),
+    (0x0047, "Touch Valid", &[MomentaryControl]),
+    (0x0048, "Width", &[DynamicValue]),
+    (0x0049, "Height", &[DynamicValue]),
+    (0x0051, "Contact Identifier", &[DynamicValue]),
+    (0x0052, "Device Mode", &[DynamicValue]),
+    (0x0053, "Device Identifier", &[DynamicValue, StaticValue]),
+    (0x0054, "Contact Count", &[DynamicValue]),
+    (0x0055, "Contact Count Maximum", &[StaticValue]),
+    (0x0056, "Scan Time", &[DynamicValue]),
+    (0x0057, "Surface Switch", &[DynamicFlag]),
+    (0x0058, "Button Switch", &[DynamicFlag]),
+    (0x0059, "Pad Type", &[StaticFlag]),
+    (0x005A, "Secondary Barrel Switch", &[MomentaryControl]),
+    (0x005B, "Transducer Serial Number", &[StaticValue]),
+    (0x005C, "Preferred Color", &[DynamicValue]),
+    (0x005D, "Preferred Color is Locked", &[MomentaryControl]),
+    (0x005E, "Preferred Line Width", &[DynamicValue]),
+    (0x005F, "Preferred Line Width is Locked", &[MomentaryControl]),
+    (0x0060, "Latency Mode", &[DynamicFlag]),
+    (0x0061, "Gesture Character Quality", &[DynamicValue]),
+    (0x0062, "Character Gesture Data Length", &[DynamicValue]),
+    (0x0063, "Character Gesture Data", &[DynamicValue]),
+    (0x0064, "Gesture Character Encoding", &[NamedArray]),
+    (0x0065, "UTF8 Character Gesture Encoding", &[Selector]),
+    (0x0066, "UTF16 Little Endian Character Gesture Encoding", &[Selector]),
+    (0x0067, "UTF16 Big Endian Character Gesture Encoding", &[Selector]),
+    (0x0068, "UTF32 Little Endian Character Gesture Encoding", &[Selector]),
+    (0x0069, "UTF32 Big Endian Character Gesture Encoding", &[Selector]),
+    (0x006A, "Capacitive Heat Map Protocol Vendor ID", &[StaticValue]),
+    (0x006B, "Capacitive Heat Map Protocol Version", &[StaticValue]),
+    (0x006C, "Capacitive Heat Map Frame Data", &[DynamicValue]),
+    (0x006D, "Gesture Character Enable", &[DynamicFlag]),
+    (0x006E, "Transducer Serial Number Part 2", &[StaticValue]),
+    (0x006F, "No Preferred Color", &[DynamicFlag]),
+    (0x0070, "Preferred Line Style", &[NamedArray]),
+    (0x0071, "Preferred Line Style is Locked", &[MomentaryControl]),
+    (0x0072, "Ink", &[Selector]),
+    (0x0073, "Pencil", &[Selector]),
+    (0x0074, "Highlighter", &[Selector]),
+    (0x0075, "Chisel Marker", &[Selector]),
+    (0x0076, "Brush", &[Selector]),
+    (0x0077, "No Preference", &[Selector]),
+    (0x0080, "Digitizer Diagnostic", &[LogicalCollection]),
+    (0x0081, "Digitizer Error", &[NamedArray]),
+    (0x0082, "Err Normal Status", &[Selector]),
+    (0x0083, "Err Transducers Exceeded", &[Selector]),
+    (0x0084, "Err Full Trans Features Unavailable", &[Selector]),
+    (0x0085, "Err Charge Low", &[Selector]),
+    (0x0090, "Transducer Software Info", &[LogicalCollection]),
+    (0x0091, "Transducer Vendor Id", &[StaticValue]),
+    (0x0092, "Transducer Product Id", &[StaticValue]),
+    (0x0093, "Device Supported Protocols", &[NamedArray, LogicalCollection]),
+    (0x0094, "Transducer Supported Protocols", &[NamedArray, LogicalCollection]),
+    (0x0095, "No Protocol", &[Selector]),
+    (0x0096, "Wacom AES Protocol", &[Selector]),
+    (0x0097, "USI Protocol", &[Selector]),
+    (0x0098, "Microsoft Pen Protocol", &[Selector]),
+    (0x00A0, "Supported Report Rates", &[StaticValue, LogicalCollection]),
+    (0x00A1, "Report Rate", &[DynamicValue]),
+    (0x00A2, "Transducer Connected", &[StaticFlag]),
+    (0x00A3, "Switch Disabled", &[Selector]),
+    (0x00A4, "Switch Unimplemented", &[Selector]),
+    (0x00A5, "Transducer Switches", &[Selector]),
+    (0x00A6, "Transducer Index Selector", &[DynamicValue]),
+    (0x00B0, "Button Press Threshold", &[DynamicValue]),
+];
+
+
+
+/// Haptics page (0Eh).
+const HAPTICS: &[Entry] = &[
+    (0x0001, "Simple Haptic Controller", &[ApplicationCollection, LogicalCollection]),
+    (0x0010, "Waveform List", &[NamedArray]),
+    (0x0011, "Duration List", &[NamedArray]),
+    (0x0020, "Auto Trigger", &[DynamicValue]),
+    (0x0021, "Manual Trigger", &[DynamicValue]),
+    (0x0022, "Auto Trigger Associated Control", &[StaticValue]),
+    (0x0023, "Intensity", &[DynamicValue]),
+    (0x0024, "Repeat Count", &[DynamicValue]),
+    (0x0025, "Retrigger Period", &[DynamicValue]),
+    (0x0026, "Waveform Vendor Page", &[StaticValue]),
+    (0x0027, "Waveform Vendor ID", &[StaticValue]),
+    (0x0028, "Waveform Cutoff Time", &[StaticValue]),
+    (0x1001, "Waveform None", &[StaticValue]),
+    (0x1002, "Waveform Stop", &[StaticValue]),
+    (0x1003, "Waveform Click", &[StaticValue]),
+    (0x1004, "Waveform Buzz Continuous", &[StaticValue]),
+    (0x1005, "Waveform Rumble Continuous", &[StaticValue]),
+    (0x1006, "Waveform Press", &[StaticValue]),
+    (0x1007, "Waveform Release", &[StaticValue]),
+    (0x1008, "Waveform Hover", &[StaticValue]),
+    (0x1009, "Waveform Success", &[StaticValue]),
+    (0x100A, "Waveform Error", &[StaticValue]),
+    (0x100B, "Waveform Ink Continuous", &[StaticValue]),
+    (0x100C, "Waveform Pencil Continuous", &[StaticValue]),
+    (0x100D, "Waveform Marker Continuous", &[StaticValue]),
+    (0x100E, "Waveform Chisel Marker Continuous", &[StaticValue]),
+    (0x100F, "Waveform Brush Continuous", &[StaticValue]),
+    (0x1010, "Waveform Eraser Continuous", &[StaticValue]),
+    (0x1011, "Waveform Sparkle Continuous", &[StaticValue]),
+];
+
+
+
+/// Sensors page (20h).
+const SENSORS: &[Entry] = &[
+    (0x0001, "Sensor", &[ApplicationCollection, PhysicalCollection]),
+    (0x0010, "Biometric", &[ApplicationCollection, PhysicalCollection]),
+    (0x0011, "Biometric: Human Presence", &[ApplicationCollection, PhysicalCollection]),
+    (0x0012, "Biometric: Human Proximity", &[ApplicationCollection, PhysicalCollection]),
+    (0x0013, "Biometric: Human Touch", &[ApplicationCollection, PhysicalCollection]),
+    (0x0014, "Biometric: Heart Rate", &[ApplicationCollection, PhysicalCollection]),
+    (0x0020, "Electrical", &[ApplicationCollection, PhysicalCollection]),
+    (0x0021, "Electrical: Capacitance", &[ApplicationCollection, PhysicalCollection]),
+    (0x0022, "Electrical: Current", &[ApplicationCollection, PhysicalCollection]),
+    (0x0023, "Electrical: Power", &[ApplicationCollection, PhysicalCollection]),
+    (0x0024, "Electrical: Inductance", &[ApplicationCollection, PhysicalCollection]),
+    (0x0025, "Electrical: Resistance", &[ApplicationCollection, PhysicalCollection]),
+    (0x0026, "Electrical: Voltage", &[ApplicationCollection, PhysicalCollection]),
+    (0x0027, "Electrical: Potentiometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x0028, "Electrical: Frequency", &[ApplicationCollection, PhysicalCollection]),
+    (0x0029, "Electrical: Period", &[ApplicationCollection, PhysicalCollection]),
+    (0x0030, "Environmental", &[ApplicationCollection, PhysicalCollection]),
+    (0x0031, "Environmental: Atmospheric Pressure", &[ApplicationCollection, PhysicalCollection]),
+    (0x0032, "Environmental: Humidity", &[ApplicationCollection, PhysicalCollection]),
+    (0x0033, "Environmental: Temperature", &[ApplicationCollection, PhysicalCollection]),
+    (0x0034, "Environmental: Wind Direction", &[ApplicationCollection, PhysicalCollection]),
+    (0x0035, "Environmental: Wind Speed", &[ApplicationCollection, PhysicalCollection]),
+    (0x0036, "Environmental: Air Quality", &[ApplicationCollection, PhysicalCollection]),
+    (0x0037, "Environmental: Heat Index", &[ApplicationCollection, PhysicalCollection]),
+    (0x0038, "Environmental: Surface Temperature", &[ApplicationCollection, PhysicalCollection]),
+    (0x0039, "Environmental: Volatile Organic Compounds", &[ApplicationCollection, PhysicalCollection]),
+    (0x003A, "Environmental: Object Presence", &[ApplicationCollection, PhysicalCollection]),
+    (0x003B, "Environmental: Object Proximity", &[ApplicationCollection, PhysicalCollection]),
+    (0x0040, "Light", &[ApplicationCollection, PhysicalCollection]),
+    (0x0041, "Light: Ambient Light", &[ApplicationCollection, PhysicalCollection]),
+    (0x0042, "Light: Consumer Infrared", &[ApplicationCollection, PhysicalCollection]),
+    (0x0043, "Light: Infrared Light", &[ApplicationCollection, PhysicalCollection]),
+    (0x0044, "Light: Visible Light", &[ApplicationCollection, PhysicalCollection]),
+    (0x0045, "Light: Ultraviolet Light", &[ApplicationCollection, PhysicalCollection]),
+    (0x0050, "Location", &[ApplicationCollection, PhysicalCollection]),
+    (0x0051, "Location: Broadcast", &[ApplicationCollection, PhysicalCollection]),
+    (0x0052, "Location: Dead Reckoning", &[ApplicationCollection, PhysicalCollection]),
+    (0x0053, "Location: GPS", &[ApplicationCollection, PhysicalCollection]),
+    (0x0054, "Location: Lookup", &[ApplicationCollection, PhysicalCollection]),
+    (0x0055, "Location: Other", &[ApplicationCollection, PhysicalCollection]),
+    (0x0056, "Location: Static", &[ApplicationCollection, PhysicalCollection]),
+    (0x0057, "Location: Triangulation", &[ApplicationCollection, PhysicalCollection]),
+    (0x0060, "Mechanical", &[ApplicationCollection, PhysicalCollection]),
+    (0x0061, "Mechanical: Boolean Switch", &[ApplicationCollection, PhysicalCollection]),
+    (0x0062, "Mechanical: Boolean Switch Array", &[ApplicationCollection, PhysicalCollection]),
+    (0x0063, "Mechanical: Multivalue Switch", &[ApplicationCollection, PhysicalCollection]),
+    (0x0064, "Mechanical: Force", &[ApplicationCollection, PhysicalCollection]),
+    (0x0065, "Mechanical: Pressure", &[ApplicationCollection, PhysicalCollection]),
+    (0x0066, "Mechanical: Strain", &[ApplicationCollection, PhysicalCollection]),
+    (0x0067, "Mechanical: Weight", &[ApplicationCollection, PhysicalCollection]),
+    (0x0068, "Mechanical: Haptic Vibrator", &[ApplicationCollection, PhysicalCollection]),
+    (0x0069, "Mechanical: Hall Effect Switch", &[ApplicationCollection, PhysicalCollection]),
+    (0x0070, "Motion", &[ApplicationCollection, PhysicalCollection]),
+    (0x0071, "Motion: Accelerometer 1D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0072, "Motion: Accelerometer 2D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0073, "Motion: Accelerometer 3D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0074, "Motion: Gyrometer 1D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0075, "Motion: Gyrometer 2D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0076, "Motion: Gyrometer 3D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0077, "Motion: Motion Detector", &[ApplicationCollection, PhysicalCollection]),
+    (0x0078, "Motion: Speedometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x0079, "Motion: Accelerometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x007A, "Motion: Gyrometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x007B, "Motion: Gravity Vector", &[ApplicationCollection, PhysicalCollection]),
+    (0x007C, "Motion: Linear Accelerometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x0080, "Orientation", &[ApplicationCollection, PhysicalCollection]),
+    (0x0081, "Orientation: Compass 1D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0082, "Orientation: Compass 2D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0083, "Orientation: Compass 3D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0084, "Orientation: Inclinometer 1D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0085, "Orientation: Inclinometer 2D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0086, "Orientation: Inclinometer 3D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0087, "Orientation: Distance 1D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0088, "Orientation: Distance 2D", &[ApplicationCollection, PhysicalCollection]),
+    (0x0089, "Orientation: Distance 3D", &[ApplicationCollection, PhysicalCollection]),
+    (0x008A, "Orientation: Device Orientation", &[ApplicationCollection, PhysicalCollection]),
+    (0x008B, "Orientation: Compass", &[ApplicationCollection, PhysicalCollection]),
+    (0x008C, "Orientation: Inclinometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x008D, "Orientation: Distance", &[ApplicationCollection, PhysicalCollection]),
+    (0x008E, "Orientation: Relative Orientation", &[ApplicationCollection, PhysicalCollection]),
+    (0x008F, "Orientation: Simple Orientation", &[ApplicationCollection, PhysicalCollection]),
+    (0x0090, "Scanner", &[ApplicationCollection, PhysicalCollection]),
+    (0x0091, "Scanner: Barcode", &[ApplicationCollection, PhysicalCollection]),
+    (0x0092, "Scanner: RFID", &[ApplicationCollection, PhysicalCollection]),
+    (0x0093, "Scanner: NFC", &[ApplicationCollection, PhysicalCollection]),
+    (0x00A0, "Time", &[ApplicationCollection, PhysicalCollection]),
+    (0x00A1, "Time: Alarm Timer", &[ApplicationCollection, PhysicalCollection]),
+    (0x00A2, "Time: Real Time Clock", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B0, "Personal Activity", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B1, "Personal Activity: Activity Detection", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B2, "Personal Activity: Device Position", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B3, "Personal Activity: Floor Tracker", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B4, "Personal Activity: Pedometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x00B5, "Personal Activity: Step Detection", &[ApplicationCollection, PhysicalCollection]),
+    (0x00C0, "Orientation Extended", &[ApplicationCollection, PhysicalCollection]),
+    (0x00C1, "Orientation Extended: Geomagnetic Orientation", &[ApplicationCollection, PhysicalCollection]),
+    (0x00C2, "Orientation Extended: Magnetometer", &[ApplicationCollection, PhysicalCollection]),
+    (0x00D0, "Gesture", &[ApplicationCollection, PhysicalCollection]),
+    (0x00D1, "Gesture: Chassis Flip Gesture", &[ApplicationCollection, PhysicalCollection]),
+    (0x00D2, "Gesture: Hinge Fold Gesture", &[ApplicationCollection, PhysicalCollection]),
+    (0x00E0, "Other", &[ApplicationCollection, PhysicalCollection]),
+    (0x00E1, "Other: Custom", &[ApplicationCollection, PhysicalCollection]),
+    (0x00E2, "Other: Generic", &[ApplicationCollection, PhysicalCollection]),
+    (0x00E3, "Other: Generic Enumerator", &[ApplicationCollection, PhysicalCollection]),
+    (0x00E4, "Other: Hinge Angle", &[ApplicationCollection, PhysicalCollection]),
+    (0x0200, "Event", &[DynamicValue]),
+    (0x0201, "Event: Sensor State", &[NamedArray]),
+    (0x0202, "Event: Sensor Event", &[NamedArray]),
+    (0x0300, "Property", &[DynamicValue]),
+    (0x0301, "Property: Friendly Name", &[StaticValue]),
+    (0x0302, "Property: Persistent Unique ID", &[DynamicValue]),
+    (0x0303, "Property: Sensor Status", &[DynamicValue]),
+    (0x0304, "Property: Minimum Report Interval", &[StaticValue]),
+    (0x0305, "Property: Sensor Manufacturer", &[StaticValue]),
+    (0x0306, "Property: Sensor Model", &[StaticValue]),
+    (0x0307, "Property: Sensor Serial Number", &[StaticValue]),
+    (0x0308, "Property: Sensor Description", &[StaticValue]),
+    (0x0309, "Property: Sensor Connection Type", &[NamedArray]),
+    (0x030A, "Property: Sensor Device Path", &[DynamicValue]),
+    (0x030B, "Property: Hardware Revision", &[StaticValue]),
+    (0x030C, "Property: Firmware Version", &[StaticValue]),
+    (0x030D, "Property: Release Date", &[StaticValue]),
+    (0x030E, "Property: Report Interval", &[DynamicValue]),
+    (0x030F, "Property: Change Sensitivity Absolute", &[DynamicValue]),
+    (0x0310, "Property: Change Sensitivity Percent of Range", &[DynamicValue]),
+    (0x0311, "Property: Change Sensitivity Percent Relative", &[DynamicValue]),
+    (0x0312, "Property: Accuracy", &[DynamicValue]),
+    (0x0313, "Property: Resolution", &[DynamicValue]),
+    (0x0314, "Property: Maximum", &[DynamicValue]),
+    (0x0315, "Property: Minimum", &[DynamicValue]),
+    (0x0316, "Property: Reporting State", &[NamedArray]),
+    (0x0317, "Property: Sampling Rate", &[DynamicValue]),
+    (0x0318, "Property: Response Curve", &[DynamicValue]),
+    (0x0319, "Property: Power State", &[NamedArray]),
+    (0x031A, "Property: Maximum FIFO Events", &[StaticValue]),
+    (0x031B, "Property: Report Latency", &[DynamicValue]),
+    (0x031C, "Property: Flush FIFO Events", &[DynamicFlag]),
+    (0x031D, "Property: Maximum Power Consumption", &[DynamicValue]),
+    (0x031E, "Property: Is Primary", &[DynamicFlag]),
+    (0x031F, "Property: Human Presence Detection Type", &[NamedArray]),
+    (0x0400, "Data Field: Location", &[DynamicValue]),
+    (0x0430, "Data Field: Environmental", &[DynamicValue]),
+    (0x0431, "Data Field: Atmospheric Pressure", &[StaticValue]),
+    (0x0433, "Data Field: Relative Humidity", &[StaticValue]),
+    (0x0434, "Data Field: Temperature", &[StaticValue]),
+    (0x0452, "Data Field: Acceleration", &[StaticValue]),
+    (0x0453, "Data Field: Acceleration Axis X", &[StaticValue]),
+    (0x0454, "Data Field: Acceleration Axis Y", &[StaticValue]),
+    (0x0455, "Data Field: Acceleration Axis Z", &[StaticValue]),
+    (0x0456, "Data Field: Angular Velocity", &[StaticValue]),
+    (0x0457, "Data Field: Angular Velocity about X Axis", &[StaticValue]),
+    (0x0458, "Data Field: Angular Velocity about Y Axis", &[StaticValue]),
+    (0x0459, "Data Field: Angular Velocity about Z Axis", &[StaticValue]),
+    (0x04B0, "Data Field: Presence", &[DynamicValue]),
+    (0x04B1, "Data Field: Human Presence", &[StaticFlag]),
+    (0x04B2, "Data Field: Human Proximity Range", &[StaticValue]),
+    (0x04B3, "Data Field: Human Proximity Out of Range", &[StaticFlag]),
+    (0x04B4, "Data Field: Human Touch State", &[StaticFlag]),
+    (0x04D1, "Data Field: Illuminance", &[StaticValue]),
+];
+
+
+
+/// Power page (84h).
+const POWER: &[Entry] = &[
+    (0x0001, "iName", &[StaticValue]),
+    (0x0002, "Present Status", &[LogicalCollection]),
+    (0x0003, "Changed Status", &[LogicalCollection]),
+    (0x0004, "UPS", &[ApplicationCollection]),
+    (0x0005, "Power Supply", &[ApplicationCollection]),
+    (0x0010, "Battery System", &[PhysicalCollection]),
+    (0x0011, "Battery System Id", &[StaticValue]),
+    (0x0012, "Battery", &[PhysicalCollection]),
+    (0x0013, "Battery Id", &[StaticValue]),
+    (0x0014, "Charger", &[PhysicalCollection]),
+    (0x0015, "Charger Id", &[StaticValue]),
+    (0x0016, "Power Converter", &[PhysicalCollection]),
+    (0x0017, "Power Converter Id", &[StaticValue]),
+    (0x0018, "Outlet System", &[PhysicalCollection]),
+    (0x0019, "Outlet System Id", &[StaticValue]),
+    (0x001A, "Input", &[PhysicalCollection]),
+    (0x001B, "Input Id", &[StaticValue]),
+    (0x001C, "Output", &[PhysicalCollection]),
+    (0x001D, "Output Id", &[StaticValue]),
+    (0x001E, "Flow", &[PhysicalCollection]),
+    (0x001F, "Flow Id", &[StaticValue]),
+    (0x0020, "Outlet", &[PhysicalCollection]),
+    (0x0021, "Outlet Id", &[StaticValue]),
+    (0x0022, "Gang", &[LogicalCollection, PhysicalCollection]),
+    (0x0023, "Gang Id", &[StaticValue]),
+    (0x0024, "Power Summary", &[LogicalCollection, PhysicalCollection]),
+    (0x0025, "Power Summary Id", &[StaticValue]),
+    (0x0030, "Voltage", &[DynamicValue]),
+    (0x0031, "Current", &[DynamicValue]),
+    (0x0032, "Frequency", &[DynamicValue]),
+    (0x0033, "Apparent Power", &[DynamicValue]),
+    (0x0034, "Active Power", &[DynamicValue]),
+    (0x0035, "Percent Load", &[DynamicValue]),
+    (0x0036, "Temperature", &[DynamicValue]),
+    (0x0037, "Humidity", &[DynamicValue]),
+    (0x0038, "Bad Count", &[DynamicValue]),
+    (0x0040, "Config Voltage", &[StaticValue, DynamicValue]),
+    (0x0041, "Config Current", &[StaticValue, DynamicValue]),
+    (0x0042, "Config Frequency", &[StaticValue, DynamicValue]),
+    (0x0043, "Config Apparent Power", &[StaticValue, DynamicValue]),
+    (0x0044, "Config Active Power", &[StaticValue, DynamicValue]),
+    (0x0045, "Config Percent Load", &[StaticValue, DynamicValue]),
+    (0x0046, "Config Temperature", &[StaticValue, DynamicValue]),
+    (0x0047, "Config Humidity", &[StaticValue, DynamicValue]),
+    (0x0050, "Switch On Control", &[DynamicValue]),
+    (0x0051, "Switch Off Control", &[DynamicValue]),
+    (0x0052, "Toggle Control", &[DynamicValue]),
+    (0x0053, "Low Voltage Transfer", &[DynamicValue]),
+    (0x0054, "High Voltage Transfer", &[DynamicValue]),
+    (0x0055, "Delay Before Reboot", &[DynamicValue]),
+    (0x0056, "Delay Before Startup", &[DynamicValue]),
+    (0x0057, "Delay Before Shutdown", &[DynamicValue]),
+    (0x0058, "Test", &[DynamicValue]),
+    (0x0059, "Module Reset", &[DynamicValue]),
+    (0x005A, "Audible Alarm Control", &[DynamicValue]),
+    (0x0060, "Present", &[DynamicFlag]),
+    (0x0061, "Good", &[DynamicFlag]),
+    (0x0062, "Internal Failure", &[DynamicFlag]),
+    (0x0063, "Voltage Out Of Range", &[DynamicFlag]),
+    (0x0064, "Frequency Out Of Range", &[DynamicFlag]),
+    (0x0065, "Overload", &[DynamicFlag]),
+    (0x0066, "Over Charged", &[DynamicFlag]),
+    (0x0067, "Over Temperature", &[DynamicFlag]),
+    (0x0068, "Shutdown Requested", &[DynamicFlag]),
+    (0x0069, "Shutdown Imminent", &[DynamicFlag]),
+    (0x006B, "Switch On/Off", &[DynamicFlag]),
+    (0x006C, "Switchable", &[DynamicFlag]),
+    (0x006D, "Used", &[DynamicFlag]),
+    (0x006E, "Boost", &[DynamicFlag]),
+    (0x006F, "Buck", &[DynamicFlag]),
+    (0x0070, "Initialized", &[DynamicFlag]),
+    (0x0071, "Tested", &[DynamicFlag]),
+    (0x0072, "Awaiting Power", &[DynamicFlag]),
+    (0x0073, "Communication Lost", &[DynamicFlag]),
+    (0x00FD, "iManufacturer", &[StaticValue]),
+    (0x00FE, "iProduct", &[StaticValue]),
+    (0x00FF, "iSerialNumber", &[StaticValue]),
+];
+
+
+
+/// Battery System page (85h).
+const BATTERY_SYSTEM: &[Entry] = &[
+    (0x0001, "Smart Battery Battery Mode", &[LogicalCollection]),
+    (0x0002, "Smart Battery Battery Status", &[NamedArray]),
+    (0x0003, "Smart Battery Alarm Warning", &[NamedArray]),
+    (0x0004, "Smart Battery Charger Mode", &[LogicalCollection]),
+    (0x0005, "Smart Battery Charger Status", &[LogicalCollection]),
+    (0x0006, "Smart Battery Charger Spec Info", &[LogicalCollection]),
+    (0x0007, "Smart Battery Selector State", &[LogicalCollection]),
+    (0x0008, "Smart Battery Selector Presets", &[LogicalCollection]),
+    (0x0009, "Smart Battery Selector Info", &[LogicalCollection]),
+    (0x0029, "Remaining Capacity Limit", &[DynamicValue]),
+    (0x002A, "Remaining Time Limit", &[DynamicValue]),
+    (0x002B, "At Rate", &[DynamicValue]),
+    (0x002C, "Capacity Mode", &[DynamicValue]),
+    (0x002D, "Broadcast To Charger", &[DynamicValue]),
+    (0x002E, "Primary Battery", &[DynamicValue]),
+    (0x002F, "Charge Controller", &[DynamicValue]),
+    (0x0040, "Terminate Charge", &[DynamicFlag]),
+    (0x0041, "Terminate Discharge", &[DynamicFlag]),
+    (0x0042, "Below Remaining Capacity Limit", &[DynamicFlag]),
+    (0x0043, "Remaining Time Limit Expired", &[DynamicFlag]),
+    (0x0044, "Charging", &[DynamicFlag]),
+    (0x0045, "Discharging", &[DynamicValue]),
+    (0x0046, "Fully Charged", &[DynamicFlag]),
+    (0x0047, "Fully Discharged", &[DynamicValue]),
+    (0x0048, "Conditioning Flag", &[DynamicValue]),
+    (0x0049, "At Rate OK", &[DynamicValue]),
+    (0x004A, "Smart Battery Error Code", &[DynamicFlag]),
+    (0x004B, "Need Replacement", &[DynamicFlag]),
+    (0x0060, "At Rate Time To Full", &[DynamicValue]),
+    (0x0061, "At Rate Time To Empty", &[DynamicValue]),
+    (0x0062, "Average Current", &[DynamicValue]),
+    (0x0063, "Max Error", &[DynamicValue]),
+    (0x0064, "Relative State Of Charge", &[DynamicValue]),
+    (0x0065, "Absolute State Of Charge", &[DynamicValue]),
+    (0x0066, "Remaining Capacity", &[DynamicValue]),
+    (0x0067, "Full Charge Capacity", &[DynamicValue]),
+    (0x0068, "Run Time To Empty", &[DynamicValue]),
+    (0x0069, "Average Time To Empty", &[DynamicValue]),
+    (0x006A, "Average Time To Full", &[DynamicValue]),
+    (0x006B, "Cycle Count", &[DynamicValue]),
+    (0x0080, "Battery Pack Model Level", &[StaticValue]),
+    (0x0081, "Internal Charge Controller", &[StaticFlag]),
+    (0x0082, "Primary Battery Support", &[StaticFlag]),
+    (0x0083, "Design Capacity", &[StaticValue]),
+    (0x0084, "Specification Info", &[StaticValue]),
+    (0x0085, "Manufacture Date", &[StaticValue]),
+    (0x0086, "Serial Number", &[StaticValue]),
+    (0x0087, "iManufacturer Name", &[StaticValue]),
+    (0x0088, "iDevice Name", &[StaticValue]),
+    (0x0089, "iDevice Chemistry", &[StaticValue]),
+    (0x008A, "Manufacturer Data", &[StaticValue]),
+    (0x008B, "Rechargable", &[StaticValue]),
+    (0x008C, "Warning Capacity Limit", &[StaticValue]),
+    (0x008D, "Capacity Granularity 1", &[StaticValue]),
+    (0x008E, "Capacity Granularity 2", &[StaticValue]),
+    (0x008F, "iOEM Information", &[StaticValue]),
+    (0x00C0, "Inhibit Charge", &[DynamicFlag]),
+    (0x00C1, "Enable Polling", &[DynamicFlag]),
+    (0x00C2, "Reset To Zero", &[DynamicFlag]),
+    (0x00D0, "AC Present", &[DynamicValue]),
+    (0x00D1, "Battery Present", &[DynamicValue]),
+    (0x00D2, "Power Fail", &[DynamicValue]),
+    (0x00D3, "Alarm Inhibited", &[DynamicValue]),
+    (0x00D4, "Thermistor Under Range", &[DynamicValue]),
+    (0x00D5, "Thermistor Hot", &[DynamicValue]),
+    (0x00D6, "Thermistor Cold", &[DynamicValue]),
+    (0x00D7, "Thermistor Over Range", &[DynamicValue]),
+    (0x00D8, "Voltage Out Of Range", &[DynamicValue]),
+    (0x00D9, "Current Out Of Range", &[DynamicValue]),
+    (0x00DA, "Current Not Regulated", &[DynamicValue]),
+    (0x00DB, "Voltage Not Regulated", &[DynamicValue]),
+    (0x00DC, "Master Mode", &[DynamicValue]),
+    (0x00F0, "Charger Selector Support", &[StaticFlag]),
+    (0x00F1, "Charger Spec", &[StaticValue]),
+    (0x00F2, "Level 2", &[StaticFlag]),
+    (0x00F3, "Level 3", &[StaticFlag]),
+];
+
+
+
+/// FIDO Alliance page (F1D0h).
+const FIDO_ALLIANCE: &[Entry] = &[
+    (0x0001, "U2F Authenticator Device", &[ApplicationCollection]),
+    (0x0020, "Input Report Data", &[DynamicValue]),
+    (0x0021, "Output Report Data", &[DynamicValue]),
+];
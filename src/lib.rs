@@ -5,8 +5,13 @@
 
 #![no_std]
 
+// Conversions into raw codes are written as `Into<u8>` throughout the crate.
+#![allow(clippy::from_over_into)]
 
 
-//pub mod class;
+
+pub mod cdc;
+pub mod class;
 pub mod descriptor;
 pub mod error;
+pub mod hid;
@@ -0,0 +1,92 @@
+//! Tests for the Abstract Control Model requests and notifications.
+
+
+
+use usbdescriptor::cdc::*;
+use usbdescriptor::descriptor::ToBytes;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Line coding of 115200 bauds, 7 data bits, even parity and 2 stop bits.
+const CODING: LineCoding = LineCoding::new(115200, StopBits::Two, Parity::Even, DataBits::Seven);
+
+/// Setup packets encode the fields of their requests and parse back.
+#[test]
+fn setup_round_trip() {
+    let requests = [
+        (ACMRequest::SetLineCoding { interface: 2, coding: CODING }, [0x21, 0x20, 0x00, 0x00, 0x02, 0x00, 0x07, 0x00]),
+        (ACMRequest::GetLineCoding { interface: 2 }, [0xA1, 0x21, 0x00, 0x00, 0x02, 0x00, 0x07, 0x00]),
+        (ACMRequest::SetControlLineState { interface: 0, state: ControlLineState::DTR.with(ControlLineState::RTS) }, [0x21, 0x22, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (ACMRequest::SendBreak { interface: 1, duration: 0xFFFF }, [0x21, 0x23, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00]),
+    ];
+
+    let data = CODING.to_array();
+    assert_eq!(data, [0x00, 0xC2, 0x01, 0x00, 0x02, 0x02, 0x07]);
+
+    for (request, setup) in requests {
+        assert_eq!(request.setup(), setup);
+        assert_eq!(ACMRequest::parse(&setup, &data), Ok( request ));
+    }
+}
+
+/// Setup packets with an unknown request, a wrong direction or a wrong
+/// data stage length are rejected.
+#[test]
+fn setup_errors() {
+    let data = CODING.to_array();
+
+    assert_eq!(ACMRequest::parse(&[0x21, 0x24, 0, 0, 0, 0, 0, 0], &[]), Err( USBParseError::UnknownRequest(0x24) ));
+    assert_eq!(ACMRequest::parse(&[0xA1, 0x20, 0, 0, 0, 0, 7, 0], &data), Err( USBParseError::InvalidRequestType(0xA1) ));
+    assert_eq!(ACMRequest::parse(&[0x21, 0x22, 0, 0, 0, 0, 2, 0], &[]), Err( USBParseError::InvalidRequestLength(2) ));
+    assert_eq!(ACMRequest::parse(&[0x21, 0x20, 0, 0, 0, 0, 7, 0], &data[..6]), Err( USBParseError::BufferTooShort(6) ));
+    assert_eq!(ACMRequest::parse(&[0x21, 0x22, 0, 0], &[]), Err( USBParseError::BufferTooShort(4) ));
+}
+
+/// Line codings with an unknown stop bit, parity or data bit code are
+/// rejected.
+#[test]
+fn line_coding_codes() {
+    assert_eq!(LineCoding::parse(&[0x80, 0x25, 0x00, 0x00, 0x00, 0x00, 0x08]), Ok( LineCoding::default() ));
+
+    assert_eq!(LineCoding::parse(&[0x80, 0x25, 0x00, 0x00, 0x03, 0x00, 0x08]), Err( USBParseError::UnknownStopBits(3) ));
+    assert_eq!(LineCoding::parse(&[0x80, 0x25, 0x00, 0x00, 0x00, 0x05, 0x08]), Err( USBParseError::UnknownParity(5) ));
+    assert_eq!(LineCoding::parse(&[0x80, 0x25, 0x00, 0x00, 0x00, 0x00, 0x09]), Err( USBParseError::UnknownDataBits(9) ));
+}
+
+/// `SERIAL_STATE` carries the UART signals in the low bits of its data.
+#[test]
+fn serial_state_layout() {
+    const NOTIFICATION: [u8; 10] = ACMNotification::SerialState {
+        interface: 0,
+        state: SerialState::DCD.with(SerialState::DSR).with(SerialState::OVERRUN),
+    }.to_array();
+
+    assert_eq!(NOTIFICATION, [0xA1, 0x20, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x43, 0x00]);
+
+    match ACMNotification::parse(&NOTIFICATION) {
+        Ok( ACMNotification::SerialState { interface: 0, state } ) => {
+            assert!(state.contains(SerialState::DCD.with(SerialState::DSR)));
+            assert!(!state.contains(SerialState::BREAK));
+            assert!(state.contains(SerialState::OVERRUN));
+        },
+
+        notification => panic!("unexpected notification {:?}", notification),
+    }
+
+    assert_eq!(ACMNotification::parse(&NOTIFICATION[..9]), Err( USBParseError::BufferTooShort(9) ));
+}
+
+/// `NETWORK_CONNECTION` carries the connection in its value and has no
+/// data.
+#[test]
+fn network_connection_round_trip() {
+    let notification = ACMNotification::NetworkConnection { interface: 3, connected: true };
+    let mut buffer = [0xFFu8; 10];
+
+    assert_eq!(notification.to_bytes(&mut buffer), Ok(8));
+    assert_eq!(buffer, [0xA1, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0xFF, 0xFF]);
+
+    assert_eq!(ACMNotification::parse(&buffer[..8]), Ok( notification ));
+    assert_eq!(ACMNotification::parse(&[0xA1, 0x2A, 0, 0, 0, 0, 0, 0]), Err( USBParseError::UnknownNotification(0x2A) ));
+}
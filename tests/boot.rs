@@ -0,0 +1,154 @@
+//! Tests for the boot protocol reports.
+
+
+
+use usbdescriptor::class::class::Class;
+use usbdescriptor::class::protocol::HIDProtocol;
+use usbdescriptor::class::subclass::HIDSubClass;
+use usbdescriptor::descriptor::ToBytes;
+use usbdescriptor::error::{ USBParseError, USBSerializeError };
+use usbdescriptor::hid::*;
+
+
+
+/// Keyboard reports decode their modifiers and keys, and encode back to the
+/// same bytes.
+#[test]
+fn keyboard_round_trip() {
+    const BYTES: [u8; 8] = [0x22, 0x00, 0x04, 0x05, 0x00, 0x00, 0x00, 0x00];
+
+    let report = BootKeyboardReport::parse(&BYTES).unwrap();
+
+    assert_eq!(report.modifiers(), KeyModifiers::LEFT_SHIFT.with(KeyModifiers::RIGHT_SHIFT));
+    assert!(report.modifiers().shift() && !report.modifiers().control());
+    assert!(report.keys().eq([0x04, 0x05]));
+    assert!(report.pressed(0x05) && !report.pressed(0x06));
+    assert_eq!(report.to_array(), BYTES);
+
+    assert_eq!(BootKeyboardReport::new(KeyModifiers(0x22), &[0x04, 0x05]), report);
+    assert_eq!(BootKeyboardReport::new(KeyModifiers(0), &[1, 2, 3, 4, 5, 6, 7]).slots(), [1, 2, 3, 4, 5, 6]);
+
+    let mut buffer = [0xFFu8; 9];
+    assert_eq!(report.to_bytes(&mut buffer), Ok(8));
+    assert_eq!(buffer[..8], BYTES);
+    assert_eq!(report.to_bytes(&mut buffer[..7]), Err( USBSerializeError::BufferTooShort(8) ));
+
+    assert_eq!(BootKeyboardReport::parse(&[0x00, 0x00, 0x04, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01]).map(|r| r.slots()), Ok([4, 5, 0, 0, 0, 0]));
+    assert_eq!(BootKeyboardReport::parse(&BYTES[..7]), Err( USBParseError::BufferTooShort(7) ));
+}
+
+/// A rollover error hides the keys but not the modifiers.
+#[test]
+fn keyboard_rollover() {
+    let report = BootKeyboardReport::rollover(KeyModifiers::LEFT_CONTROL);
+
+    assert!(report.is_rollover());
+    assert_eq!(report.to_array(), [0x01, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01]);
+    assert_eq!(report.keys().count(), 0);
+    assert!(!report.pressed(0x01));
+    assert!(report.modifiers().control());
+
+    let partial = BootKeyboardReport::new(KeyModifiers(0), &[0x01, 0x01, 0x01, 0x01, 0x01, 0x04]);
+
+    assert!(!partial.is_rollover());
+    assert!(partial.keys().eq([0x01, 0x01, 0x01, 0x01, 0x01, 0x04]));
+}
+
+/// LED reports ignore and never send the padding bits.
+#[test]
+fn keyboard_leds() {
+    let leds = BootKeyboardLeds::parse(&[0xE3]).unwrap();
+
+    assert_eq!(leds, BootKeyboardLeds::NUM_LOCK.with(BootKeyboardLeds::CAPS_LOCK));
+    assert!(!leds.contains(BootKeyboardLeds::SCROLL_LOCK));
+    assert_eq!(BootKeyboardLeds(0xFF).to_array(), [0x1F]);
+
+    let mut buffer = [0u8; 1];
+    assert_eq!(BootKeyboardLeds(0xF0).to_bytes(&mut buffer), Ok(1));
+    assert_eq!(buffer, [0x10]);
+
+    assert_eq!(BootKeyboardLeds::parse(&[]), Err( USBParseError::BufferTooShort(0) ));
+}
+
+/// Mouse reports decode their buttons and signed displacements.
+#[test]
+fn mouse_round_trip() {
+    const BYTES: [u8; 3] = [0x05, 0xFB, 0x7F];
+
+    let report = BootMouseReport::parse(&BYTES).unwrap();
+
+    assert!(report.left() && !report.right() && report.middle());
+    assert!(!report.button(0) && !report.button(9));
+    assert_eq!((report.x(), report.y()), (-5, 127));
+    assert_eq!(report.to_array(), BYTES);
+    assert_eq!(BootMouseReport::new(0x05, -5, 127), report);
+
+    assert_eq!(BootMouseReport::parse(&[0x01, 0x02, 0x03, 0xFF]), Ok( BootMouseReport::new(0x01, 2, 3) ));
+    assert_eq!(BootMouseReport::parse(&BYTES[..2]), Err( USBParseError::BufferTooShort(2) ));
+
+    let mut buffer = [0u8; 3];
+    assert_eq!(report.to_bytes(&mut buffer), Ok(3));
+    assert_eq!(buffer, BYTES);
+}
+
+/// Only interfaces of the boot subclass implement a boot protocol.
+#[test]
+fn boot_device_of() {
+    let keyboard = Class::HumanInterfaceDevice(HIDSubClass::Boot, HIDProtocol::Keyboard);
+    let mouse = Class::HumanInterfaceDevice(HIDSubClass::Boot, HIDProtocol::Mouse);
+
+    assert_eq!(BootDevice::of(keyboard), Some(BootDevice::Keyboard));
+    assert_eq!(BootDevice::of(mouse), Some(BootDevice::Mouse));
+    assert_eq!(BootDevice::of( Class::HumanInterfaceDevice(HIDSubClass::None, HIDProtocol::Keyboard) ), None);
+    assert_eq!(BootDevice::of( Class::HumanInterfaceDevice(HIDSubClass::Boot, HIDProtocol::None) ), None);
+    assert_eq!(BootDevice::of( Class::decode((0x08, 0x06, 0x50)) ), None);
+
+    assert_eq!(BootDevice::Keyboard.report_descriptor(), &BootKeyboardReport::DESCRIPTOR[..]);
+    assert_eq!(BootDevice::Mouse.report_descriptor(), &BootMouseReport::DESCRIPTOR[..]);
+}
+
+/// The keyboard reports agree with the fields of their canonical report
+/// descriptor.
+#[test]
+fn keyboard_matches_descriptor() {
+    let descriptor = ReportDescriptor::parse(&BootKeyboardReport::DESCRIPTOR).unwrap();
+
+    let input = descriptor.report(ReportKind::Input, None);
+    let report = BootKeyboardReport::new(KeyModifiers::LEFT_ALT.with(KeyModifiers::RIGHT_GUI), &[0x04, 0x29, 0x65]);
+    let bytes = report.to_array();
+
+    assert_eq!(input.size(), BootKeyboardReport::LENGTH);
+
+    let mut fields = input.fields();
+    let modifiers = fields.next().unwrap();
+    let keys = fields.nth(1).unwrap();
+
+    assert!((0..8).all(|bit| modifiers.read(&bytes, bit) == Ok( ((report.modifiers().0 >> bit) & 1) as i64 )));
+    assert!((0..6).all(|slot| keys.read(&bytes, slot) == Ok( report.slots()[slot as usize] as i64 )));
+
+    let output = descriptor.report(ReportKind::Output, None);
+    let leds = output.fields().next().unwrap();
+    let byte = BootKeyboardLeds::NUM_LOCK.with(BootKeyboardLeds::KANA).to_array();
+
+    assert_eq!(output.size(), BootKeyboardLeds::LENGTH);
+    assert!(leds.values(&byte).unwrap().map(|element| element.value).eq([1, 0, 0, 0, 1]));
+}
+
+/// The mouse report agrees with the fields of its canonical report
+/// descriptor.
+#[test]
+fn mouse_matches_descriptor() {
+    let descriptor = ReportDescriptor::parse(&BootMouseReport::DESCRIPTOR).unwrap();
+
+    let input = descriptor.report(ReportKind::Input, None);
+    let bytes = BootMouseReport::new(0x06, 12, -100).to_array();
+
+    assert_eq!(input.size(), BootMouseReport::LENGTH);
+
+    let mut fields = input.fields();
+    let buttons = fields.next().unwrap();
+    let axes = fields.nth(1).unwrap();
+
+    assert!(buttons.values(&bytes).unwrap().map(|element| element.value).eq([0, 1, 1]));
+    assert_eq!((axes.read(&bytes, 0), axes.read(&bytes, 1)), (Ok(12), Ok(-100)));
+}
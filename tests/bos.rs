@@ -0,0 +1,217 @@
+//! Tests for the Binary Object Store and its device capabilities.
+
+
+
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Binary Object Store of a USB 3.0 flash drive: USB 2.0 Extension with LPM,
+/// and SuperSpeed USB.
+const STORE: [u8; 22] = [
+    0x05, 0x0F, 0x16, 0x00, 0x02,
+    0x07, 0x10, 0x02, 0x02, 0x00, 0x00, 0x00,
+    0x0A, 0x10, 0x03, 0x00, 0x0E, 0x00, 0x01, 0x0A, 0xFF, 0x07,
+];
+
+/// SuperSpeedPlus capability with two sublink speed attributes.
+const SUPERSPEED_PLUS: [u8; 20] = [
+    0x14, 0x10, 0x0A, 0x00,
+    0x01, 0x00, 0x00, 0x00,
+    0x00, 0x11, 0x00, 0x00,
+    0x30, 0x40, 0x0A, 0x00,
+    0x31, 0x00, 0x05, 0x00,
+];
+
+/// Billboard capability with one DisplayPort Alternate Mode.
+const BILLBOARD: [u8; 48] = [
+    0x30, 0x10, 0x0D, 0x01, 0x01, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x01, 0x00, 0x00,
+    0x01, 0xFF, 0x01, 0x02,
+];
+
+/// Configuration Summary of a CDC ACM function in configurations 1 and 2.
+const SUMMARY: [u8; 11] = [0x0B, 0x10, 0x10, 0x00, 0x01, 0x02, 0x02, 0x01, 0x02, 0x01, 0x02];
+
+/// Parses a single capability descriptor.
+fn capability(bytes: &[u8]) -> Result<DeviceCapability<'_>, USBParseError> {
+    DeviceCapability::parse( RawDescriptor::split(bytes)?.0 )
+}
+
+/// The capabilities of a captured store are decoded.
+#[test]
+fn captured_store() {
+    let store = BosDescriptor::parse(&STORE).unwrap();
+
+    assert_eq!((store.total_length(), store.num_capabilities()), (22, 2));
+
+    let mut capabilities = store.capabilities();
+
+    match capabilities.next() {
+        Some(DeviceCapability::USB20Extension(extension)) => {
+            assert!(extension.lpm() && !extension.besl());
+            assert_eq!(extension.baseline_besl(), None);
+        },
+
+        capability => panic!("unexpected capability {:?}", capability),
+    }
+
+    match capabilities.next() {
+        Some(DeviceCapability::SuperSpeed(superspeed)) => {
+            assert!(superspeed.supports(Speed::Full) && superspeed.supports(Speed::Super));
+            assert!(!superspeed.supports(Speed::Low));
+            assert_eq!(superspeed.functionality_support(), Some(Speed::Full));
+            assert_eq!((superspeed.u1_exit_latency(), superspeed.u2_exit_latency()), (0x0A, 0x07FF));
+        },
+
+        capability => panic!("unexpected capability {:?}", capability),
+    }
+
+    assert!(capabilities.next().is_none());
+}
+
+/// A capability count that disagrees with the store is reported as is, and
+/// recomputed when the store is serialized.
+#[test]
+fn capability_count_mismatch() {
+    for count in [0, 1, 3] {
+        let mut bytes = STORE;
+        bytes[4] = count;
+
+        let store = BosDescriptor::parse(&bytes).unwrap();
+
+        assert_eq!(store.num_capabilities(), count);
+        assert_eq!(store.capabilities().count(), 2);
+
+        let mut buffer = [0u8; 32];
+
+        assert_eq!(store.to_bytes(&mut buffer), Ok(22));
+        assert_eq!(buffer[..22], STORE);
+    }
+}
+
+/// Total lengths that cannot hold the header or exceed the buffer, and
+/// truncated capabilities, are rejected.
+#[test]
+fn store_errors() {
+    let mut bytes = STORE;
+
+    bytes[2] = 0x04;
+    assert_eq!(BosDescriptor::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidTotalLength(4) ));
+
+    bytes[2] = 0x17;
+    assert_eq!(BosDescriptor::parse(&bytes).map(|_| ()), Err( USBParseError::BufferTooShort(22) ));
+
+    bytes[2] = 0x15;
+    assert_eq!(BosDescriptor::parse(&bytes).map(|_| ()), Err( USBParseError::BufferTooShort(9) ));
+
+    let mut bytes = STORE;
+    bytes[5] = 0x06;
+    assert_eq!(BosDescriptor::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidLength(0x06) ));
+}
+
+/// The length of a SuperSpeedPlus capability follows its sublink speed
+/// attribute count.
+#[test]
+fn superspeed_plus_sublinks() {
+    let superspeed = match capability(&SUPERSPEED_PLUS) {
+        Ok( DeviceCapability::SuperSpeedPlus(superspeed) ) => superspeed,
+        capability => panic!("unexpected capability {:?}", capability),
+    };
+
+    assert_eq!((superspeed.sublink_speed_attribute_count(), superspeed.sublink_speed_id_count()), (2, 1));
+    assert_eq!((superspeed.min_speed_id(), superspeed.min_rx_lanes(), superspeed.min_tx_lanes()), (0, 1, 1));
+
+    let mut sublinks = superspeed.sublink_speeds();
+
+    let first = sublinks.next().unwrap();
+    assert_eq!((first.id(), first.protocol(), first.bits_per_second()), (0, 1, 10_000_000_000));
+
+    let second = sublinks.next().unwrap();
+    assert_eq!((second.id(), second.protocol(), second.bits_per_second()), (1, 0, 5_000_000_000));
+
+    assert!(sublinks.next().is_none());
+
+    let mut bytes = SUPERSPEED_PLUS;
+    bytes[0] = 0x10;
+    assert_eq!(capability(&bytes[..16]), Err( USBParseError::InvalidLength(0x10) ));
+
+    bytes[0] = 0x14;
+    bytes[4] = 0x02;
+    assert_eq!(capability(&bytes), Err( USBParseError::InvalidLength(0x14) ));
+}
+
+/// Billboard capabilities list their Alternate Modes, and must hold all of
+/// them.
+#[test]
+fn billboard() {
+    let billboard = match capability(&BILLBOARD) {
+        Ok( DeviceCapability::Billboard(billboard) ) => billboard,
+        capability => panic!("unexpected capability {:?}", capability),
+    };
+
+    assert_eq!((billboard.additional_info_url_index(), billboard.num_alternate_modes()), (1, 1));
+    assert_eq!(billboard.state(0), Some(AlternateModeState::Successful));
+    assert_eq!(billboard.state(1), None);
+    assert_eq!(billboard.version(), BCDVersion::new(1, 1, 0));
+    assert!(billboard.alternate_modes().eq([AlternateMode { svid: 0xFF01, mode: 1, string_index: 2 }]));
+
+    let mut bytes = BILLBOARD;
+    bytes[0] = 0x2C;
+    assert_eq!(capability(&bytes[..44]), Err( USBParseError::InvalidLength(0x2C) ));
+
+    bytes[0] = 0x04;
+    assert_eq!(capability(&bytes[..4]), Err( USBParseError::InvalidLength(0x04) ));
+}
+
+/// Configuration Summary capabilities must hold all their configurations.
+#[test]
+fn configuration_summary() {
+    let summary = match capability(&SUMMARY) {
+        Ok( DeviceCapability::ConfigurationSummary(summary) ) => summary,
+        capability => panic!("unexpected capability {:?}", capability),
+    };
+
+    assert_eq!(summary.version(), BCDVersion::new(1, 0, 0));
+    assert_eq!(summary.class_code(), (0x02, 0x02, 0x01));
+    assert_eq!(summary.configurations(), &[1, 2]);
+
+    let mut bytes = SUMMARY;
+    bytes[0] = 0x0A;
+    assert_eq!(capability(&bytes[..10]), Err( USBParseError::InvalidLength(0x0A) ));
+
+    bytes[0] = 0x08;
+    assert_eq!(capability(&bytes[..8]), Err( USBParseError::InvalidLength(0x08) ));
+}
+
+/// The writer fills in the total length and the capability count, and the
+/// store parses back.
+#[test]
+fn writer_round_trip() {
+    let mut buffer = [0u8; 128];
+    let mut writer = BosWriter::new(&mut buffer).unwrap();
+
+    writer
+        .push( &DeviceCapability::USB20Extension( USB20Extension::new(0x02) ) ).unwrap()
+        .push( &DeviceCapability::SuperSpeed( SuperSpeedCapability::new(0x00, 0x000E, 0x01, 0x0A, 0x07FF) ) ).unwrap()
+        .push( &DeviceCapability::ContainerID( ContainerID([0xA5; 16]) ) ).unwrap()
+        .push( &SUPERSPEED_PLUS[..] ).unwrap()
+        .push( &SUMMARY[..] ).unwrap();
+
+    let total = writer.finish().unwrap();
+
+    assert_eq!(total, 22 + 20 + 20 + 11);
+    assert_eq!(buffer[..5], [0x05, 0x0F, total as u8, 0x00, 0x05]);
+    assert_eq!(buffer[5..22], STORE[5..]);
+
+    let store = BosDescriptor::parse(&buffer[..total]).unwrap();
+
+    assert_eq!((store.total_length() as usize, store.num_capabilities()), (total, 5));
+    assert_eq!(store.capabilities().nth(2), Some( DeviceCapability::ContainerID( ContainerID([0xA5; 16]) ) ));
+    assert_eq!(store.capabilities().count(), 5);
+}
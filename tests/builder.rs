@@ -0,0 +1,72 @@
+//! Tests for the compile-time device and configuration builders.
+
+
+
+use usbdescriptor::cdc::*;
+use usbdescriptor::class::class::Class;
+use usbdescriptor::class::protocol::*;
+use usbdescriptor::class::subclass::*;
+use usbdescriptor::descriptor::*;
+
+
+
+/// Device descriptor of a composite CDC ACM device.
+static DEVICE: [u8; 18] = DeviceDescriptorBuilder::new()
+    .class( Class::Miscellaneous( MiscellaneousSubClass::InterfaceAssociation ) )
+    .vendor(0x1209)
+    .product(0x0001)
+    .device_version( BCDVersion::from_raw(0x0100) )
+    .manufacturer_index(1)
+    .product_index(2)
+    .serial_number_index(3)
+    .build();
+
+/// Configuration of a CDC ACM function with a bulk pair and an interrupt
+/// notification endpoint.
+static CONFIGURATION: [u8; 75] = ConfigurationBuilder::<75>::new( ConfigurationDescriptor::new(1, 0, 0x00, 50) )
+    .association( InterfaceAssociationDescriptor::new(0, 2, Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250), 0) )
+    .interface( InterfaceDescriptor::new(0, 0, 1, Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250), 0) )
+    .descriptor( &HeaderDescriptor::new(HeaderDescriptor::CDC11).to_array() )
+    .descriptor( &CallManagementDescriptor::new(CallManagementCapabilities(0), 1).to_array() )
+    .descriptor( &AbstractControlManagementDescriptor::new(ACMCapabilities::LINE_CODING).to_array() )
+    .descriptor( &UnionDescriptor::to_array::<5>(0, &[1]) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(1, Direction::In), 0x03, 8, 16) )
+    .interface( InterfaceDescriptor::new(1, 0, 2, Class::CDCData(CDCDataProtocol::USB), 0) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(2, Direction::Out), 0x02, 64, 0) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(2, Direction::In), 0x02, 64, 0) )
+    .build();
+
+/// The device builder fills every field of the descriptor.
+#[test]
+fn static_device() {
+    assert_eq!(DEVICE, [
+        0x12, 0x01, 0x00, 0x02,
+        0xEF, 0x02, 0x01, 0x40,
+        0x09, 0x12, 0x01, 0x00,
+        0x00, 0x01, 0x01, 0x02,
+        0x03, 0x01,
+    ]);
+}
+
+/// The configuration builder computes `wTotalLength` and `bNumInterfaces`.
+#[test]
+fn static_configuration() {
+    assert_eq!(CONFIGURATION, [
+        0x09, 0x02, 0x4B, 0x00, 0x02, 0x01, 0x00, 0x80, 0x32,
+        0x08, 0x0B, 0x00, 0x02, 0x02, 0x02, 0x01, 0x00,
+        0x09, 0x04, 0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00,
+        0x05, 0x24, 0x00, 0x10, 0x01,
+        0x05, 0x24, 0x01, 0x00, 0x01,
+        0x04, 0x24, 0x02, 0x02,
+        0x05, 0x24, 0x06, 0x00, 0x01,
+        0x07, 0x05, 0x81, 0x03, 0x08, 0x00, 0x10,
+        0x09, 0x04, 0x01, 0x00, 0x02, 0x0A, 0x00, 0x00, 0x00,
+        0x07, 0x05, 0x02, 0x02, 0x40, 0x00, 0x00,
+        0x07, 0x05, 0x82, 0x02, 0x40, 0x00, 0x00,
+    ]);
+
+    let configuration = Configuration::parse(&CONFIGURATION).unwrap();
+
+    assert_eq!(configuration.descriptor().total_length(), 75);
+    assert_eq!(configuration.descriptor().num_interfaces(), 2);
+}
@@ -0,0 +1,58 @@
+//! Tests for the CDC functional descriptors.
+
+
+
+use usbdescriptor::cdc::*;
+use usbdescriptor::class::class::Class;
+use usbdescriptor::class::protocol::*;
+use usbdescriptor::class::subclass::*;
+use usbdescriptor::descriptor::*;
+
+
+
+/// Configuration whose data interface has no endpoints in its default
+/// alternate setting.
+static CONFIGURATION: [u8; 67] = ConfigurationBuilder::<67>::new( ConfigurationDescriptor::new(1, 0, 0x00, 50) )
+    .interface( InterfaceDescriptor::new(0, 0, 1, Class::CDCControl(CDCControlSubClass::NetworkControl, CDCControlProtocol::USB), 0) )
+    .descriptor( &HeaderDescriptor::new(HeaderDescriptor::CDC12).to_array() )
+    .descriptor( &UnionDescriptor::to_array::<5>(0, &[1]) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(1, Direction::In), 0x03, 16, 9) )
+    .interface( InterfaceDescriptor::new(1, 0, 0, Class::CDCData(CDCDataProtocol::NTB), 0) )
+    .interface( InterfaceDescriptor::new(1, 1, 2, Class::CDCData(CDCDataProtocol::NTB), 0) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(2, Direction::Out), 0x02, 512, 0) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(2, Direction::In), 0x02, 512, 0) )
+    .build();
+
+/// The data interface of a union is its first alternate setting with
+/// endpoints.
+#[test]
+fn union_data_interface() {
+    let configuration = Configuration::parse(&CONFIGURATION).unwrap();
+    let control = configuration.interfaces().next().unwrap();
+    let union = control.cdc_union().unwrap();
+
+    assert_eq!(union.control_interface(), 0);
+    assert_eq!(union.subordinate_interfaces(), &[1]);
+
+    let data = union.data_interface(&configuration).unwrap();
+
+    assert_eq!((data.number(), data.alternate_setting()), (1, 1));
+    assert_eq!(data.endpoints().count(), 2);
+}
+
+/// Union and country selection descriptors encoded at compile time parse
+/// back to their fields.
+#[test]
+fn functional_round_trip() {
+    const UNION: [u8; 6] = UnionDescriptor::to_array::<6>(2, &[3, 4]);
+    const COUNTRIES: [u8; 8] = CountrySelectionDescriptor::to_array::<8>(5, &[0x5355, 0x5246]);
+
+    assert_eq!(UNION, [0x06, 0x24, 0x06, 0x02, 0x03, 0x04]);
+
+    let union = UnionDescriptor::parse(&UNION).unwrap();
+    assert_eq!((union.control_interface(), union.subordinate_interfaces()), (2, &[3, 4][..]));
+
+    let countries = CountrySelectionDescriptor::parse(&COUNTRIES).unwrap();
+    assert_eq!(countries.release_date_index(), 5);
+    assert!(countries.countries().eq([0x5355, 0x5246]));
+}
@@ -0,0 +1,84 @@
+//! Round-trip tests for the class codes.
+
+
+
+use usbdescriptor::class::class::Class;
+
+
+
+/// Every triple accepted by the strict decoder converts back to itself.
+#[test]
+fn strict_round_trip() {
+    let mut defined = 0;
+
+    for class in 0..=0xFFu8 {
+        for subclass in 0..=0xFFu8 {
+            for protocol in 0..=0xFFu8 {
+                let code = (class, subclass, protocol);
+
+                if let Ok(decoded) = Class::try_from(code) {
+                    assert_eq!(<(u8, u8, u8)>::from(decoded), code, "{:?}", decoded);
+                    defined += 1;
+                }
+            }
+        }
+    }
+
+    assert!(defined > 0);
+}
+
+/// Every triple converts back to itself when decoded leniently.
+#[test]
+fn lenient_round_trip() {
+    for class in 0..=0xFFu8 {
+        for subclass in 0..=0xFFu8 {
+            for protocol in 0..=0xFFu8 {
+                let code = (class, subclass, protocol);
+
+                assert_eq!(Class::decode(code).code(), code);
+            }
+        }
+    }
+}
+
+/// Strict decoding agrees with lenient decoding on defined triples.
+#[test]
+fn strict_matches_lenient() {
+    for class in 0..=0xFFu8 {
+        for subclass in 0..=0xFFu8 {
+            for protocol in 0..=0xFFu8 {
+                let code = (class, subclass, protocol);
+
+                if let Ok(decoded) = Class::try_from(code) {
+                    assert_eq!(Class::decode(code), decoded);
+                }
+            }
+        }
+    }
+}
+
+/// The class code can be computed at compile time.
+#[test]
+fn const_code() {
+    use usbdescriptor::class::subclass::MiscellaneousSubClass;
+
+    const CODE: (u8, u8, u8) = Class::Miscellaneous( MiscellaneousSubClass::InterfaceAssociation ).code();
+
+    assert_eq!(CODE, (0xEF, 0x02, 0x01));
+}
+
+/// Unknown sub class and protocol pairs are kept by their class.
+#[test]
+fn unknown_pairs_kept() {
+    use usbdescriptor::class::protocol::WirelessBaseProtocol;
+    use usbdescriptor::class::subclass::*;
+
+    assert_eq!(Class::decode((0xDC, 0x01, 0x07)), Class::Diagnostic( DiagnosticSubClass::Other(0x01, 0x07) ));
+    assert_eq!(Class::decode((0xEF, 0x09, 0x00)), Class::Miscellaneous( MiscellaneousSubClass::Other(0x09, 0x00) ));
+    assert_eq!(Class::decode((0xE0, 0x05, 0x01)), Class::Wireless( WirelessSubClass::Other(0x05, 0x01) ));
+    assert_eq!(Class::decode((0xFE, 0x07, 0x02)), Class::ApplicationSpecific( ApplicationSpecificSubClass::Other(0x07, 0x02) ));
+
+    assert_eq!(Class::decode((0xE0, 0x01, 0x09)), Class::Wireless( WirelessSubClass::RadioFrequency( WirelessBaseProtocol::Other(0x09) ) ));
+
+    assert!(Class::try_from((0xDC, 0x01, 0x07)).is_err());
+}
@@ -0,0 +1,93 @@
+//! Tests for the endpoint companion descriptors.
+
+
+
+use usbdescriptor::class::class::Class;
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Interface with a bulk and three isochronous endpoints: SuperSpeed,
+/// SuperSpeedPlus, and USB 2.0 high bandwidth.
+static CONFIGURATION: [u8; 72] = ConfigurationBuilder::<72>::new( ConfigurationDescriptor::new(1, 0, 0x00, 50) )
+    .interface( InterfaceDescriptor::new(0, 0, 4, Class::VendorSpecific(0x00, 0x00), 0) )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(1, Direction::In), 0x02, 1024, 0) )
+    .descriptor( &SuperSpeedEndpointCompanion::new(15, 0x04, 0).to_array() )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(2, Direction::In), 0x05, 1024, 1) )
+    .descriptor( &SuperSpeedEndpointCompanion::new(15, 0x02, 49152).to_array() )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(3, Direction::In), 0x05, 1024, 1) )
+    .descriptor( &SuperSpeedEndpointCompanion::new(15, 0x80, 0).to_array() )
+    .descriptor( &SuperSpeedPlusIsochronousCompanion::new(98304).to_array() )
+    .endpoint( EndpointDescriptor::new(EndpointAddress::new(4, Direction::In), 0x05, 0x1400, 1) )
+    .build();
+
+/// Companion descriptors parse back to their fields.
+#[test]
+fn parse_round_trip() {
+    const COMPANION: [u8; 6] = [0x06, 0x30, 0x0F, 0x04, 0x00, 0x00];
+    const ISOCHRONOUS: [u8; 8] = [0x08, 0x31, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00];
+
+    let companion = SuperSpeedEndpointCompanion::parse(&COMPANION).unwrap();
+
+    assert_eq!((companion.max_burst(), companion.burst_size()), (15, 16));
+    assert_eq!((companion.max_streams(), companion.streams()), (4, 16));
+    assert_eq!(companion.to_array(), COMPANION);
+
+    let isochronous = SuperSpeedPlusIsochronousCompanion::parse(&ISOCHRONOUS).unwrap();
+
+    assert_eq!(isochronous.bytes_per_interval(), 98304);
+    assert_eq!(isochronous.to_array(), ISOCHRONOUS);
+
+    assert_eq!(SuperSpeedEndpointCompanion::parse(&COMPANION[..5]), Err( USBParseError::BufferTooShort(5) ));
+    assert_eq!(SuperSpeedEndpointCompanion::parse(&[0x07, 0x30, 0, 0, 0, 0, 0]), Err( USBParseError::InvalidLength(7) ));
+    assert_eq!(SuperSpeedEndpointCompanion::parse(&ISOCHRONOUS[..6]), Err( USBParseError::InvalidLength(8) ));
+    assert_eq!(SuperSpeedPlusIsochronousCompanion::parse(&[0x08, 0x30, 0, 0, 0, 0, 0, 0]), Err( USBParseError::InvalidDescriptorType(0x30) ));
+}
+
+/// Burst sizes, streams and multipliers are decoded from the raw fields,
+/// including out of range values.
+#[test]
+fn attributes() {
+    let bulk = SuperSpeedEndpointCompanion::new(0, 0x00, 0);
+    assert_eq!((bulk.burst_size(), bulk.streams()), (1, 0));
+
+    let isochronous = SuperSpeedEndpointCompanion::new(3, 0x02, 12288);
+    assert_eq!((isochronous.mult(), isochronous.ssp_iso_companion()), (2, false));
+    assert_eq!(isochronous.bytes_per_interval(), 12288);
+
+    let superspeed_plus = SuperSpeedEndpointCompanion::new(15, 0x80, 0);
+    assert!(superspeed_plus.ssp_iso_companion());
+
+    let corrupt = SuperSpeedEndpointCompanion::new(0xFF, 0x1F, 0);
+    assert_eq!((corrupt.burst_size(), corrupt.streams()), (256, 1 << 31));
+}
+
+/// The bytes moved per interval come from the companions of USB 3.x
+/// endpoints, and from the additional transactions of USB 2.0 endpoints.
+#[test]
+fn max_bytes_per_interval() {
+    let configuration = Configuration::parse(&CONFIGURATION).unwrap();
+    let endpoints = configuration.interfaces().next().unwrap().endpoints();
+
+    assert!(endpoints.map(|endpoint| endpoint.max_bytes_per_interval()).eq([16384, 49152, 98304, 3072]));
+
+    let mut endpoints = configuration.interfaces().next().unwrap().endpoints();
+
+    assert_eq!(endpoints.next().unwrap().companion().map(|c| c.streams()), Some(16));
+    assert!(endpoints.next().unwrap().isochronous_companion().is_none());
+    assert_eq!(endpoints.next().unwrap().isochronous_companion().map(|c| c.bytes_per_interval()), Some(98304));
+    assert!(endpoints.next().unwrap().companion().is_none());
+}
+
+/// A companion with an out of range burst does not overflow.
+#[test]
+fn corrupt_burst() {
+    let mut bytes = CONFIGURATION;
+    bytes[27] = 0xFF;
+
+    let configuration = Configuration::parse(&bytes).unwrap();
+    let endpoint = configuration.interfaces().next().unwrap().endpoints().next().unwrap();
+
+    assert_eq!(endpoint.max_bytes_per_interval(), 1024 * 256);
+}
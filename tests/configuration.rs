@@ -0,0 +1,259 @@
+//! Tests for the configuration tree parser.
+
+
+
+use usbdescriptor::class::class::Class;
+use usbdescriptor::class::protocol::*;
+use usbdescriptor::class::subclass::*;
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::{ USBParseError, USBSerializeError };
+
+
+
+/// Configuration of the virtual COM port of an STM32 development board, as
+/// captured from the device.
+const VCP: [u8; 67] = [
+    0x09, 0x02, 0x43, 0x00, 0x02, 0x01, 0x00, 0xC0, 0x32,
+    0x09, 0x04, 0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00,
+    0x05, 0x24, 0x00, 0x10, 0x01,
+    0x05, 0x24, 0x01, 0x00, 0x01,
+    0x04, 0x24, 0x02, 0x02,
+    0x05, 0x24, 0x06, 0x00, 0x01,
+    0x07, 0x05, 0x82, 0x03, 0x08, 0x00, 0x10,
+    0x09, 0x04, 0x01, 0x00, 0x02, 0x0A, 0x00, 0x00, 0x00,
+    0x07, 0x05, 0x01, 0x02, 0x40, 0x00, 0x00,
+    0x07, 0x05, 0x81, 0x02, 0x40, 0x00, 0x00,
+];
+
+/// A captured configuration parses into its interfaces and endpoints.
+#[test]
+fn captured_configuration() {
+    let configuration = Configuration::parse(&VCP).unwrap();
+    let descriptor = configuration.descriptor();
+
+    assert_eq!((descriptor.total_length(), descriptor.num_interfaces()), (67, 2));
+    assert_eq!((descriptor.configuration_value(), descriptor.max_power()), (1, 0x32));
+    assert!(descriptor.self_powered() && !descriptor.remote_wakeup());
+
+    assert_eq!(configuration.descriptors().count(), 9);
+    assert_eq!(configuration.class_specific().count(), 0);
+    assert_eq!(configuration.associations().count(), 0);
+
+    let mut interfaces = configuration.interfaces();
+
+    let control = interfaces.next().unwrap();
+    assert_eq!((control.number(), control.alternate_setting()), (0, 0));
+    assert_eq!(control.class(), Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250));
+    assert!(control.endpoints().map(|endpoint| endpoint.address()).eq([0x82]));
+
+    let data = interfaces.next().unwrap();
+    assert_eq!(data.class(), Class::CDCData(CDCDataProtocol::USB));
+    assert_eq!(data.class_specific().count(), 0);
+    assert!(data.endpoints().map(|endpoint| endpoint.address()).eq([0x01, 0x81]));
+
+    let endpoint = data.endpoints().next().unwrap().descriptor();
+    assert_eq!((endpoint.transfer_type(), endpoint.max_packet_size()), (TransferType::Bulk, 64));
+
+    assert!(interfaces.next().is_none());
+}
+
+/// Class specific descriptors attach to the configuration, interface or
+/// endpoint they follow.
+#[test]
+fn class_specific_attachment() {
+    const BYTES: [u8; 45] = [
+        0x09, 0x02, 0x2D, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32,
+        0x04, 0x40, 0xAA, 0xBB,
+        0x09, 0x04, 0x00, 0x00, 0x01, 0xFF, 0x00, 0x00, 0x00,
+        0x03, 0x41, 0x01,
+        0x03, 0x42, 0x02,
+        0x07, 0x05, 0x81, 0x01, 0x40, 0x00, 0x01,
+        0x03, 0x43, 0x03,
+        0x07, 0x25, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let configuration = Configuration::parse(&BYTES).unwrap();
+
+    assert!(configuration.class_specific().map(|raw| raw.descriptor_type()).eq([0x40]));
+
+    let interface = configuration.interfaces().next().unwrap();
+    assert!(interface.class_specific().map(|raw| raw.descriptor_type()).eq([0x41, 0x42]));
+
+    let endpoint = interface.endpoints().next().unwrap();
+    assert!(endpoint.class_specific().map(|raw| raw.descriptor_type()).eq([0x43, 0x25]));
+    assert!(endpoint.companion().is_none());
+}
+
+/// Total lengths that cannot hold the header, or that exceed the buffer,
+/// are rejected.
+#[test]
+fn invalid_total_length() {
+    let mut bytes = VCP;
+
+    bytes[2] = 0x08;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidTotalLength(8) ));
+
+    bytes[2] = 0x44;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::BufferTooShort(67) ));
+
+    bytes[2] = 0x43;
+    assert_eq!(Configuration::parse(&bytes[..66]).map(|_| ()), Err( USBParseError::BufferTooShort(66) ));
+
+    bytes[2] = 0x2C;
+    assert_eq!(Configuration::parse(&bytes).map(|c| c.interfaces().count()), Ok(1));
+}
+
+/// Descriptors that overrun the total length, or that are too short for
+/// their type, are rejected.
+#[test]
+fn malformed_descriptors() {
+    let mut bytes = VCP;
+
+    bytes[2] = 0x42;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::BufferTooShort(6) ));
+
+    bytes[2] = 0x43;
+    bytes[18] = 0x01;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidLength(0x01) ));
+
+    let mut bytes = VCP;
+    bytes[0] = 0x0A;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidLength(0x0A) ));
+
+    let mut bytes = VCP;
+    bytes[1] = 0x07;
+    assert_eq!(Configuration::parse(&bytes).map(|_| ()), Err( USBParseError::InvalidDescriptorType(0x07) ));
+
+    const SHORT: [u8; 23] = [
+        0x09, 0x02, 0x17, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32,
+        0x09, 0x04, 0x00, 0x00, 0x01, 0xFF, 0x00, 0x00, 0x00,
+        0x05, 0x05, 0x81, 0x02, 0x40,
+    ];
+
+    assert_eq!(Configuration::parse(&SHORT).map(|_| ()), Err( USBParseError::InvalidLength(0x05) ));
+}
+
+/// Serializing a parsed configuration gives back its bytes.
+#[test]
+fn serialize_round_trip() {
+    let configuration = Configuration::parse(&VCP).unwrap();
+    let mut buffer = [0u8; 80];
+
+    assert_eq!(configuration.size(), 67);
+    assert_eq!(configuration.to_bytes(&mut buffer), Ok(67));
+    assert_eq!(buffer[..67], VCP);
+
+    assert_eq!(configuration.to_bytes(&mut buffer[..60]), Err( USBSerializeError::BufferTooShort(67) ));
+}
+
+/// Consecutive alternate settings of an interface are grouped, and an
+/// interface interrupted by another one starts a new group.
+#[test]
+fn grouped_alternate_settings() {
+    const BYTES: [u8; 52] = [
+        0x09, 0x02, 0x34, 0x00, 0x02, 0x01, 0x00, 0x80, 0x32,
+        0x09, 0x04, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00,
+        0x09, 0x04, 0x01, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00,
+        0x09, 0x04, 0x01, 0x01, 0x01, 0x01, 0x02, 0x00, 0x00,
+        0x07, 0x05, 0x01, 0x05, 0xC0, 0x00, 0x01,
+        0x09, 0x04, 0x00, 0x01, 0x00, 0x01, 0x01, 0x00, 0x00,
+    ];
+
+    let configuration = Configuration::parse(&BYTES).unwrap();
+    let groups = configuration.interfaces().grouped();
+
+    assert!(groups.map(|group| (group.number(), group.len())).eq([(0, 1), (1, 2), (0, 1)]));
+
+    let streaming = groups.clone().nth(1).unwrap();
+
+    assert!(streaming.alternates().map(|interface| interface.alternate_setting()).eq([0, 1]));
+    assert_eq!(streaming.alternate(1).map(|interface| interface.endpoints().count()), Some(1));
+    assert!(streaming.alternate(2).is_none());
+
+    let last = groups.last().unwrap();
+    assert!(last.alternates().map(|interface| interface.alternate_setting()).eq([1]));
+}
+
+/// Interface descriptors with a class that is only valid at device level
+/// are rejected.
+#[test]
+fn interface_class_level() {
+    let mut bytes = [0x09, 0x04, 0x00, 0x00, 0x01, 0x09, 0x00, 0x00, 0x00];
+    assert_eq!(InterfaceDescriptor::parse(&bytes), Err( USBParseError::InvalidClassLevel(0x09) ));
+
+    bytes[5] = 0xFF;
+    let interface = InterfaceDescriptor::parse(&bytes).unwrap();
+
+    assert_eq!((interface.number(), interface.alternate_setting(), interface.num_endpoints()), (0, 0, 1));
+    assert_eq!(interface.to_array(), bytes);
+}
+
+/// Composite configuration: a CDC function grouped by an association, and
+/// two interfaces outside of any association.
+static COMPOSITE: [u8; 62] = ConfigurationBuilder::<62>::new( ConfigurationDescriptor::new(1, 0, 0x00, 50) )
+    .association( InterfaceAssociationDescriptor::new(0, 2, Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250), 5) )
+    .interface( InterfaceDescriptor::new(0, 0, 0, Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250), 0) )
+    .interface( InterfaceDescriptor::new(1, 0, 0, Class::CDCData(CDCDataProtocol::USB), 0) )
+    .interface( InterfaceDescriptor::new(2, 0, 0, Class::VendorSpecific(0x01, 0x02), 6) )
+    .interface( InterfaceDescriptor::new(2, 1, 0, Class::VendorSpecific(0x01, 0x02), 7) )
+    .interface( InterfaceDescriptor::new(3, 0, 0, Class::VendorSpecific(0x03, 0x04), 8) )
+    .build();
+
+/// Interfaces are grouped by their association, and the others form single
+/// interface functions with all their alternate settings.
+#[test]
+fn functions() {
+    let configuration = Configuration::parse(&COMPOSITE).unwrap();
+
+    assert_eq!(configuration.validate_associations(), Ok(()));
+
+    let functions = configuration.functions();
+
+    assert!(functions.map(|f| (f.first_interface(), f.interface_count(), f.string_index())).eq([(0, 2, 5), (2, 1, 6), (3, 1, 8)]));
+
+    let mut functions = configuration.functions();
+
+    let cdc = functions.next().unwrap();
+    assert!(matches!(cdc, Function::Association(_)));
+    assert_eq!(cdc.class(), Class::CDCControl(CDCControlSubClass::Abstract, CDCControlProtocol::V250));
+    assert!(cdc.interfaces().map(|interface| interface.number()).eq([0, 1]));
+    assert!(cdc.contains(1) && !cdc.contains(2));
+
+    let vendor = functions.next().unwrap();
+    assert!(matches!(vendor, Function::Interface(_)));
+    assert_eq!(vendor.class_code(), (0xFF, 0x01, 0x02));
+    assert!(vendor.interfaces().map(|interface| (interface.number(), interface.alternate_setting())).eq([(2, 0), (2, 1)]));
+
+    assert!(configuration.unassociated_interfaces().map(|interface| interface.number()).eq([2, 2, 3]));
+}
+
+/// Associations past the number of interfaces, and interfaces claimed by
+/// two associations, are reported.
+#[test]
+fn invalid_associations() {
+    let mut bytes = COMPOSITE;
+
+    bytes[12] = 5;
+    assert_eq!(Configuration::parse(&bytes).unwrap().validate_associations(), Err( USBParseError::AssociationOutOfRange(0) ));
+
+    bytes[12] = 4;
+    assert_eq!(Configuration::parse(&bytes).unwrap().validate_associations(), Ok(()));
+
+    bytes[4] = 3;
+    assert_eq!(Configuration::parse(&bytes).unwrap().validate_associations(), Err( USBParseError::AssociationOutOfRange(0) ));
+
+    const OVERLAP: [u8; 52] = [
+        0x09, 0x02, 0x34, 0x00, 0x03, 0x01, 0x00, 0x80, 0x32,
+        0x08, 0x0B, 0x00, 0x02, 0xFF, 0x00, 0x00, 0x00,
+        0x08, 0x0B, 0x01, 0x02, 0xFF, 0x00, 0x00, 0x00,
+        0x09, 0x04, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00,
+        0x09, 0x04, 0x01, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00,
+        0x09, 0x04, 0x02, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00,
+    ];
+
+    let configuration = Configuration::parse(&OVERLAP).unwrap();
+
+    assert_eq!(configuration.validate_associations(), Err( USBParseError::InterfaceClaimedTwice(1) ));
+    assert_eq!(configuration.associations().count(), 2);
+    assert_eq!(configuration.unassociated_interfaces().count(), 0);
+}
@@ -0,0 +1,88 @@
+//! Tests for the device descriptor.
+
+
+
+use usbdescriptor::class::class::Class;
+use usbdescriptor::class::subclass::*;
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Device descriptor of a USB 2.0 composite device.
+const DEVICE: [u8; 18] = [
+    0x12, 0x01,
+    0x00, 0x02,
+    0xEF, 0x02, 0x01,
+    0x40,
+    0x83, 0x04,
+    0x40, 0x57,
+    0x00, 0x01,
+    0x01, 0x02, 0x03,
+    0x01,
+];
+
+/// The fields of the descriptor are decoded and typed.
+#[test]
+fn parse_fields() {
+    let device = DeviceDescriptor::parse(&DEVICE).unwrap();
+
+    assert_eq!(device.usb_version(), BCDVersion::USB20);
+    assert_eq!(device.class(), Class::Miscellaneous( MiscellaneousSubClass::InterfaceAssociation ));
+    assert_eq!(device.class_code(), (0xEF, 0x02, 0x01));
+    assert_eq!(device.max_packet_size0(), MaxPacketSize0::Bytes64);
+    assert_eq!((device.vendor_id(), device.product_id()), (0x0483, 0x5740));
+    assert_eq!(device.device_version(), BCDVersion::new(1, 0, 0));
+    assert_eq!((device.manufacturer_index(), device.product_index(), device.serial_number_index()), (1, 2, 3));
+    assert_eq!(device.num_configurations(), 1);
+
+    assert_eq!(device.to_array(), DEVICE);
+}
+
+/// Unknown class codes are kept by the typed accessor.
+#[test]
+fn unknown_class_kept() {
+    let mut bytes = DEVICE;
+    bytes[4..7].copy_from_slice(&[0x42, 0x01, 0x02]);
+
+    let device = DeviceDescriptor::parse(&bytes).unwrap();
+
+    assert_eq!(device.class().code(), (0x42, 0x01, 0x02));
+    assert!(Class::try_from( device.class_code() ).is_err());
+}
+
+/// The control endpoint size depends on the USB version: 8 to 64 bytes up to
+/// USB 2.x, and 512 bytes from USB 3.0.
+#[test]
+fn max_packet_size0_rules() {
+    let mut bytes = DEVICE;
+
+    for (size, expected) in [(8, MaxPacketSize0::Bytes8), (16, MaxPacketSize0::Bytes16), (32, MaxPacketSize0::Bytes32)] {
+        bytes[7] = size;
+        assert_eq!(DeviceDescriptor::parse(&bytes).map(|d| d.max_packet_size0()), Ok( expected ));
+    }
+
+    bytes[7] = 0x09;
+    assert_eq!(DeviceDescriptor::parse(&bytes), Err( USBParseError::InvalidMaxPacketSize(0x09) ));
+
+    bytes[2..4].copy_from_slice(&[0x20, 0x03]);
+    assert_eq!(DeviceDescriptor::parse(&bytes).map(|d| d.max_packet_size0().size()), Ok(512));
+
+    bytes[7] = 0x40;
+    assert_eq!(DeviceDescriptor::parse(&bytes), Err( USBParseError::InvalidMaxPacketSize(0x40) ));
+}
+
+/// Descriptors with a wrong length or type, or truncated, are rejected.
+#[test]
+fn parse_errors() {
+    let mut bytes = DEVICE;
+
+    assert_eq!(DeviceDescriptor::parse(&bytes[..17]), Err( USBParseError::BufferTooShort(17) ));
+
+    bytes[0] = 0x11;
+    assert_eq!(DeviceDescriptor::parse(&bytes), Err( USBParseError::InvalidLength(0x11) ));
+
+    bytes[0] = 0x12;
+    bytes[1] = 0x02;
+    assert_eq!(DeviceDescriptor::parse(&bytes), Err( USBParseError::InvalidDescriptorType(0x02) ));
+}
@@ -0,0 +1,106 @@
+//! Tests for the endpoint descriptor.
+
+
+
+use usbdescriptor::descriptor::{ Configuration, EndpointAddress, EndpointDescriptor, Speed, ToBytes };
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Builds an endpoint of the given transfer type and interval.
+fn endpoint(attributes: u8, interval: u8) -> EndpointDescriptor {
+    EndpointDescriptor::new(EndpointAddress::from_raw(0x81), attributes, 64, interval)
+}
+
+/// Full and low speed interrupt endpoints are polled every `bInterval`
+/// frames.
+#[test]
+fn interrupt_interval_frames() {
+    assert_eq!(endpoint(0x03, 1).interval_duration(Speed::Full), Some(1_000));
+    assert_eq!(endpoint(0x03, 10).interval_duration(Speed::Full), Some(10_000));
+    assert_eq!(endpoint(0x03, 255).interval_duration(Speed::Low), Some(255_000));
+}
+
+/// Full speed isochronous endpoints are polled every `2^(bInterval-1)`
+/// frames.
+#[test]
+fn isochronous_interval_frames() {
+    assert_eq!(endpoint(0x01, 1).interval_duration(Speed::Full), Some(1_000));
+    assert_eq!(endpoint(0x01, 4).interval_duration(Speed::Full), Some(8_000));
+    assert_eq!(endpoint(0x01, 16).interval_duration(Speed::Full), Some(32_768_000));
+}
+
+/// High speed and SuperSpeed periodic endpoints are polled every
+/// `2^(bInterval-1)` microframes.
+#[test]
+fn interval_microframes() {
+    assert_eq!(endpoint(0x03, 1).interval_duration(Speed::High), Some(125));
+    assert_eq!(endpoint(0x03, 4).interval_duration(Speed::High), Some(1_000));
+    assert_eq!(endpoint(0x01, 4).interval_duration(Speed::Super), Some(1_000));
+    assert_eq!(endpoint(0x01, 16).interval_duration(Speed::SuperPlus), Some(4_096_000));
+}
+
+/// Out of range intervals and non periodic endpoints have no interval.
+#[test]
+fn interval_out_of_range() {
+    assert_eq!(endpoint(0x03, 0).interval_duration(Speed::Full), None);
+    assert_eq!(endpoint(0x01, 0).interval_duration(Speed::High), None);
+    assert_eq!(endpoint(0x03, 17).interval_duration(Speed::High), None);
+    assert_eq!(endpoint(0x01, 17).interval_duration(Speed::Full), None);
+    assert_eq!(endpoint(0x01, 1).interval_duration(Speed::Low), None);
+    assert_eq!(endpoint(0x02, 1).interval_duration(Speed::High), None);
+}
+
+/// Standard and Audio 1.0 endpoints serialize back to the parsed bytes.
+#[test]
+fn parse_round_trip() {
+    const STANDARD: [u8; 7] = [0x07, 0x05, 0x02, 0x02, 0x00, 0x02, 0x00];
+    const AUDIO: [u8; 9] = [0x09, 0x05, 0x81, 0x01, 0xC0, 0x00, 0x01, 0x05, 0x82];
+
+    let mut buffer = [0u8; 16];
+
+    let standard = EndpointDescriptor::parse(&STANDARD).unwrap();
+    let size = standard.to_bytes(&mut buffer).unwrap();
+    assert_eq!(&buffer[..size], &STANDARD[..]);
+
+    let audio = EndpointDescriptor::parse(&AUDIO).unwrap();
+    assert!(audio.is_audio());
+    assert_eq!((audio.refresh(), audio.synch_address()), (0x05, 0x82));
+
+    let size = audio.to_bytes(&mut buffer).unwrap();
+    assert_eq!(&buffer[..size], &AUDIO[..]);
+
+    assert_eq!(EndpointDescriptor::parse(&[0x08, 0x05, 0x81, 0x01, 0xC0, 0x00, 0x01, 0x05]), Err( USBParseError::InvalidLength(8) ));
+    assert_eq!(EndpointDescriptor::parse(&AUDIO[..8]), Err( USBParseError::BufferTooShort(8) ));
+}
+
+/// Endpoints of a configuration keep all their bytes in their typed form,
+/// and endpoint descriptors of any other length are rejected.
+#[test]
+fn configuration_endpoint_lengths() {
+    let mut bytes = [
+        0x09, 0x02, 0x00, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32,
+        0x09, 0x04, 0x01, 0x01, 0x01, 0x01, 0x02, 0x00, 0x00,
+        0x00, 0x05, 0x01, 0x09, 0xC0, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    for length in 7..=10 {
+        bytes[2] = 18 + length;
+        bytes[18] = length;
+
+        let parsed = Configuration::parse(&bytes);
+
+        match length {
+            7 | 9 => {
+                let configuration = parsed.unwrap();
+                let endpoint = configuration.interfaces().next().unwrap().endpoints().next().unwrap();
+                let descriptor = endpoint.descriptor();
+
+                assert_eq!((descriptor.length(), descriptor.is_audio()), (length, length == 9));
+                assert_eq!(&descriptor.to_array()[..length as usize], endpoint.raw().bytes());
+            },
+
+            _ => assert_eq!(parsed.map(|_| ()), Err( USBParseError::InvalidLength(length) )),
+        }
+    }
+}
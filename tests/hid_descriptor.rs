@@ -0,0 +1,116 @@
+//! Tests for the HID class descriptor.
+
+
+
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::{ USBParseError, USBSerializeError };
+use usbdescriptor::hid::{ ClassDescriptor, CountryCode, HIDDescriptor };
+
+
+
+/// HID descriptor of a German keyboard listing a report and a physical
+/// descriptor.
+const KEYBOARD: [u8; 12] = [
+    0x0C, 0x21, 0x11, 0x01, 0x09, 0x02,
+    0x22, 0x3F, 0x00,
+    0x23, 0x12, 0x00,
+];
+
+/// Configuration of a boot keyboard with the HID descriptor above, followed
+/// by a vendor interface.
+const CONFIGURATION: [u8; 46] = [
+    0x09, 0x02, 0x2E, 0x00, 0x02, 0x01, 0x00, 0xA0, 0x32,
+    0x09, 0x04, 0x00, 0x00, 0x01, 0x03, 0x01, 0x01, 0x00,
+    0x0C, 0x21, 0x11, 0x01, 0x09, 0x02, 0x22, 0x3F, 0x00, 0x23, 0x12, 0x00,
+    0x07, 0x05, 0x81, 0x03, 0x08, 0x00, 0x0A,
+    0x09, 0x04, 0x01, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00,
+];
+
+/// The class descriptors are listed in order, and the descriptor serializes
+/// back to its bytes.
+#[test]
+fn parse_round_trip() {
+    let hid = HIDDescriptor::parse(&KEYBOARD).unwrap();
+
+    assert_eq!(hid.version(), BCDVersion::new(1, 1, 1));
+    assert_eq!((hid.country_code(), hid.country()), (0x09, CountryCode::German));
+    assert_eq!(hid.num_descriptors(), 2);
+    assert_eq!(hid.report_length(), Some(0x3F));
+
+    assert!(hid.descriptors().eq([
+        ClassDescriptor { descriptor_type: DescriptorType::Report, length: 0x3F },
+        ClassDescriptor { descriptor_type: DescriptorType::Physical, length: 0x12 },
+    ]));
+
+    let mut buffer = [0u8; 16];
+
+    assert_eq!(hid.size(), 12);
+    assert_eq!(hid.to_bytes(&mut buffer), Ok(12));
+    assert_eq!(buffer[..12], KEYBOARD);
+    assert_eq!(hid.to_bytes(&mut buffer[..11]), Err( USBSerializeError::BufferTooShort(12) ));
+}
+
+/// A class descriptor count that does not fit the length is rejected, and
+/// extra bytes after the listed descriptors are kept.
+#[test]
+fn descriptor_count() {
+    let mut bytes = KEYBOARD;
+
+    bytes[5] = 3;
+    assert_eq!(HIDDescriptor::parse(&bytes), Err( USBParseError::InvalidLength(0x0C) ));
+
+    bytes[5] = 0;
+    assert_eq!(HIDDescriptor::parse(&bytes), Err( USBParseError::InvalidLength(0x0C) ));
+
+    bytes[5] = 1;
+    let hid = HIDDescriptor::parse(&bytes).unwrap();
+    assert_eq!(hid.descriptors().count(), 1);
+    assert_eq!(hid.size(), 12);
+
+    let mut bytes = KEYBOARD;
+    bytes[0] = 0x09;
+    bytes[5] = 1;
+    let hid = HIDDescriptor::parse(&bytes).unwrap();
+    assert_eq!(hid.descriptors().count(), 1);
+
+    bytes[5] = 2;
+    assert_eq!(HIDDescriptor::parse(&bytes), Err( USBParseError::InvalidLength(0x09) ));
+}
+
+/// Truncated buffers and other descriptor types are rejected.
+#[test]
+fn parse_errors() {
+    assert_eq!(HIDDescriptor::parse(&KEYBOARD[..8]), Err( USBParseError::BufferTooShort(8) ));
+    assert_eq!(HIDDescriptor::parse(&KEYBOARD[..11]), Err( USBParseError::BufferTooShort(11) ));
+
+    let mut bytes = KEYBOARD;
+    bytes[1] = 0x22;
+    assert_eq!(HIDDescriptor::parse(&bytes), Err( USBParseError::InvalidDescriptorType(0x22) ));
+}
+
+/// The HID descriptor is found in HID interfaces only, and only when it is
+/// well formed.
+#[test]
+fn interface_attachment() {
+    let configuration = Configuration::parse(&CONFIGURATION).unwrap();
+    let mut interfaces = configuration.interfaces();
+
+    let keyboard = interfaces.next().unwrap();
+    assert_eq!(keyboard.hid(), Some( HIDDescriptor::parse(&KEYBOARD).unwrap() ));
+    assert_eq!(keyboard.hid().and_then(|hid| hid.report_length()), Some(0x3F));
+
+    let vendor = interfaces.next().unwrap();
+    assert_eq!(vendor.hid(), None);
+
+    let mut bytes = CONFIGURATION;
+    bytes[23] = 3;
+
+    let configuration = Configuration::parse(&bytes).unwrap();
+    assert_eq!(configuration.interfaces().next().unwrap().hid(), None);
+
+    let mut bytes = CONFIGURATION;
+    bytes[14] = 0xFF;
+
+    let configuration = Configuration::parse(&bytes).unwrap();
+    assert_eq!(configuration.interfaces().next().unwrap().hid(), None);
+}
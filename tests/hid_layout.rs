@@ -0,0 +1,126 @@
+//! Tests for the bit layout of HID reports.
+
+
+
+use usbdescriptor::error::{ USBParseError, USBSerializeError };
+use usbdescriptor::hid::{ BootKeyboardReport, BootMouseReport, ReportDescriptor, ReportKind };
+
+
+
+/// The boot keyboard input report holds the modifiers, a reserved byte and
+/// the key codes.
+#[test]
+fn boot_keyboard_offsets() {
+    let descriptor = ReportDescriptor::parse(&BootKeyboardReport::DESCRIPTOR).unwrap();
+    let report = descriptor.report(ReportKind::Input, None);
+
+    assert!(report.fields().map(|field| field.bit_offset()).eq([0, 8, 16]));
+    assert_eq!(report.size(), 8);
+}
+
+/// Mouse displacements are sign extended.
+#[test]
+fn boot_mouse_signed() {
+    let descriptor = ReportDescriptor::parse(&BootMouseReport::DESCRIPTOR).unwrap();
+    let axes = descriptor.report(ReportKind::Input, None).fields().nth(2).unwrap();
+
+    assert_eq!(axes.bit_offset(), 8);
+    assert_eq!(axes.read(&[0x01, 0xFB, 0x05], 0), Ok(-5));
+    assert_eq!(axes.read(&[0x01, 0xFB, 0x05], 1), Ok(5));
+}
+
+/// Reports with an ID start after the ID byte, and raw reports are matched
+/// by their ID.
+#[test]
+fn report_ids() {
+    const BYTES: [u8; 18] = [
+        0x85, 0x01,
+        0x75, 0x08,
+        0x95, 0x02,
+        0x81, 0x02,
+        0x85, 0x02,
+        0x75, 0x10,
+        0x95, 0x01,
+        0x81, 0x02,
+        0x91, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+
+    assert!(descriptor.uses_report_ids());
+    assert!(descriptor.reports().map(|report| (report.kind(), report.id())).eq([
+        (ReportKind::Input, Some(1)),
+        (ReportKind::Input, Some(2)),
+        (ReportKind::Output, Some(2)),
+    ]));
+
+    let report = descriptor.report_of(ReportKind::Input, &[0x02, 0x34, 0x12]).unwrap();
+    let field = report.fields().next().unwrap();
+
+    assert_eq!((report.id(), report.size()), (Some(2), 3));
+    assert_eq!(field.bit_offset(), 8);
+    assert_eq!(field.read(&[0x02, 0x34, 0x12], 0), Ok(0x1234));
+
+    let report = descriptor.report_of(ReportKind::Input, &[0x01, 0x00, 0x00]).unwrap();
+    assert!(report.fields().map(|field| field.element_offset(1)).eq([16]));
+
+    assert!(descriptor.report_of(ReportKind::Input, &[0x03]).is_none());
+}
+
+/// Fields of 12 bits straddle byte boundaries without touching their
+/// neighbours.
+#[test]
+fn straddling_fields() {
+    const BYTES: [u8; 17] = [
+        0x75, 0x04,
+        0x95, 0x01,
+        0x81, 0x01,
+        0x15, 0x00,
+        0x26, 0xFF, 0x0F,
+        0x75, 0x0C,
+        0x95, 0x02,
+        0x81, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let field = descriptor.report(ReportKind::Input, None).fields().nth(1).unwrap();
+
+    let mut report = [0x0Fu8, 0x00, 0x00, 0xF0];
+
+    field.write(&mut report, 0, 0xABC).unwrap();
+    field.write(&mut report, 1, 0x123).unwrap();
+
+    assert_eq!(report, [0xCF, 0xAB, 0x23, 0xF1]);
+    assert_eq!(field.read(&report, 0), Ok(0xABC));
+    assert_eq!(field.read(&report, 1), Ok(0x123));
+}
+
+/// Values must fit in their field, and fields must fit in 32 bits.
+#[test]
+fn field_errors() {
+    const BYTES: [u8; 14] = [
+        0x75, 0x04,
+        0x95, 0x01,
+        0x81, 0x02,
+        0x75, 0x21,
+        0x95, 0x01,
+        0x81, 0x02,
+        0x75, 0x08,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let mut fields = descriptor.report(ReportKind::Input, None).fields();
+
+    let narrow = fields.next().unwrap();
+    let wide = fields.next().unwrap();
+
+    let mut report = [0u8; 8];
+
+    assert_eq!(narrow.write(&mut report, 0, 16), Err( USBSerializeError::ValueOutOfRange(16) ));
+    assert_eq!(narrow.write(&mut report, 0, -1), Err( USBSerializeError::ValueOutOfRange(-1) ));
+    assert_eq!(narrow.read(&report, 1), Err( USBParseError::FieldIndexOutOfRange(1) ));
+    assert_eq!(narrow.write(&mut report, 1, 0), Err( USBSerializeError::InvalidField( USBParseError::FieldIndexOutOfRange(1) ) ));
+
+    assert_eq!(wide.read(&report, 0), Err( USBParseError::FieldTooWide(33) ));
+    assert_eq!(wide.write(&mut report, 0, 0), Err( USBSerializeError::InvalidField( USBParseError::FieldTooWide(33) ) ));
+}
@@ -0,0 +1,155 @@
+//! Tests for the report descriptor tokenizer and parser.
+
+
+
+use usbdescriptor::error::USBParseError;
+use usbdescriptor::hid::{ Item, ReportDescriptor, Usage };
+
+
+
+/// Push and Pop items must balance within the stack depth.
+#[test]
+fn stack_depth() {
+    let mut pushes = [0xA4u8; 9];
+
+    assert_eq!(ReportDescriptor::parse(&pushes[..8]).map(|_| ()), Ok(()));
+    assert_eq!(ReportDescriptor::parse(&pushes).map(|_| ()), Err( USBParseError::ReportStackMismatch(8) ));
+
+    pushes[0] = 0xB4;
+    assert_eq!(ReportDescriptor::parse(&pushes[..1]).map(|_| ()), Err( USBParseError::ReportStackMismatch(0) ));
+}
+
+/// Collections must be closed, and closed only once.
+#[test]
+fn unbalanced_collections() {
+    assert_eq!(ReportDescriptor::parse(&[0xA1, 0x01, 0xC0, 0xC0]).map(|_| ()), Err( USBParseError::CollectionMismatch(3) ));
+    assert_eq!(ReportDescriptor::parse(&[0xA1, 0x01, 0xA1, 0x00, 0xC0]).map(|_| ()), Err( USBParseError::CollectionMismatch(5) ));
+    assert_eq!(ReportDescriptor::parse(&[0x05, 0x01, 0x09]).map(|_| ()), Err( USBParseError::ReportItemTruncated(2) ));
+}
+
+/// A range of extended usages continues on the next page.
+#[test]
+fn extended_usage_range() {
+    const BYTES: [u8; 18] = [
+        0x05, 0x09,
+        0x1B, 0xFE, 0xFF, 0x01, 0x00,
+        0x2B, 0x01, 0x00, 0x02, 0x00,
+        0x75, 0x01,
+        0x95, 0x04,
+        0x81, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let field = descriptor.fields().next().unwrap();
+
+    assert!(field.usages().eq([
+        Usage::new(0x0001, 0xFFFE),
+        Usage::new(0x0001, 0xFFFF),
+        Usage::new(0x0002, 0x0000),
+        Usage::new(0x0002, 0x0001),
+    ]));
+}
+
+/// Only the first usage of a delimited set is kept.
+#[test]
+fn delimiter_sets() {
+    const BYTES: [u8; 16] = [
+        0x05, 0x01,
+        0xA9, 0x01,
+        0x09, 0x30,
+        0x09, 0x31,
+        0xA9, 0x00,
+        0x09, 0x32,
+        0x75, 0x08,
+        0x81, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let field = descriptor.fields().next().unwrap();
+
+    assert!(field.usages().eq([Usage::new(0x01, 0x30), Usage::new(0x01, 0x32)]));
+}
+
+/// Logical maximums above the signed range of their item are unsigned when
+/// the minimum is not negative.
+#[test]
+fn logical_maximum_sign() {
+    const BYTES: [u8; 16] = [
+        0x15, 0x00,
+        0x25, 0xFF,
+        0x75, 0x08,
+        0x81, 0x02,
+        0x15, 0x80,
+        0x25, 0xFF,
+        0x75, 0x08,
+        0x81, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let mut fields = descriptor.fields();
+
+    let unsigned = fields.next().unwrap();
+    assert_eq!((unsigned.logical_minimum(), unsigned.logical_maximum()), (0, 255));
+
+    let signed = fields.next().unwrap();
+    assert_eq!((signed.logical_minimum(), signed.logical_maximum()), (-128, -1));
+}
+
+/// Long items are tokenized and skipped by the parser.
+#[test]
+fn long_items() {
+    const BYTES: [u8; 11] = [
+        0xFE, 0x02, 0xF0, 0xAB, 0xCD,
+        0x75, 0x08,
+        0x95, 0x01,
+        0x81, 0x02,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+
+    match descriptor.items().next() {
+        Some(Item::Long(item)) => assert_eq!((item.tag(), item.data()), (0xF0, &[0xAB, 0xCD][..])),
+        item => panic!("unexpected item {:?}", item),
+    }
+
+    assert_eq!(descriptor.items().next().map(|item| item.size()), Some(5));
+    assert_eq!(descriptor.fields().count(), 1);
+
+    assert_eq!(ReportDescriptor::parse(&BYTES[..4]).map(|_| ()), Err( USBParseError::ReportItemTruncated(0) ));
+}
+
+/// Collections resume parsing with the global state of their Collection
+/// item, including states saved before it.
+#[test]
+fn collection_global_state() {
+    const BYTES: [u8; 15] = [
+        0x75, 0x08,
+        0xA4,
+        0x75, 0x04,
+        0xA1, 0x01,
+        0x95, 0x01,
+        0x81, 0x02,
+        0xB4,
+        0x81, 0x02,
+        0xC0,
+    ];
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+    let collection = descriptor.collections().next().unwrap();
+
+    assert!(collection.fields().map(|field| field.report_size()).eq([4, 8]));
+    assert!(collection.fields().eq(descriptor.fields()));
+}
+
+/// Report IDs must be 1 to 255, and Usage Pages must fit 16 bits.
+#[test]
+fn global_item_ranges() {
+    assert_eq!(ReportDescriptor::parse(&[0x85, 0x01, 0x85, 0xFF]).map(|_| ()), Ok(()));
+    assert_eq!(ReportDescriptor::parse(&[0x05, 0x01, 0x85, 0x00]).map(|_| ()), Err( USBParseError::ReportItemOutOfRange(2) ));
+    assert_eq!(ReportDescriptor::parse(&[0x86, 0x01, 0x01]).map(|_| ()), Err( USBParseError::ReportItemOutOfRange(0) ));
+    assert_eq!(ReportDescriptor::parse(&[0x84]).map(|_| ()), Err( USBParseError::ReportItemOutOfRange(0) ));
+
+    assert_eq!(ReportDescriptor::parse(&[0x06, 0x00, 0xFF]).map(|_| ()), Ok(()));
+    assert_eq!(ReportDescriptor::parse(&[0x07, 0x01, 0x00, 0x00, 0x00]).map(|_| ()), Ok(()));
+    assert_eq!(ReportDescriptor::parse(&[0x07, 0x01, 0x00, 0x01, 0x00]).map(|_| ()), Err( USBParseError::ReportItemOutOfRange(0) ));
+}
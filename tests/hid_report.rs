@@ -0,0 +1,121 @@
+//! Round-trip tests for the report descriptor builder.
+
+
+
+use usbdescriptor::hid::{ BootKeyboardReport, BootMouseReport, Item, ReportDescriptor, ReportNode };
+use usbdescriptor::hid_report;
+
+
+
+/// The boot mouse descriptor, declared with the macro.
+const MOUSE: [u8; 50] = hid_report! {
+    usage_page(GenericDesktop),
+    usage(Mouse),
+    collection(Application) {
+        usage(Pointer),
+        collection(Physical) {
+            usage_page(Button),
+            usage_minimum(1),
+            usage_maximum(3),
+            logical_minimum(0),
+            logical_maximum(1),
+            report_count(3),
+            report_size(1),
+            input(Data, Variable, Absolute),
+            report_count(1),
+            report_size(5),
+            input(Constant),
+            usage_page(GenericDesktop),
+            usage(X),
+            usage(Y),
+            logical_minimum(-127),
+            logical_maximum(127),
+            report_size(8),
+            report_count(2),
+            input(Data, Variable, Relative),
+        },
+    },
+};
+
+/// Builds the same descriptors as the boot protocol.
+#[test]
+fn boot_descriptors() {
+    let keyboard = hid_report! {
+        usage_page(GenericDesktop),
+        usage(Keyboard),
+        collection(Application) {
+            usage_page(Keyboard),
+            usage_minimum(0xE0),
+            usage_maximum(0xE7),
+            logical_minimum(0),
+            logical_maximum(1),
+            report_size(1),
+            report_count(8),
+            input(Data, Variable, Absolute),
+            report_count(1),
+            report_size(8),
+            input(Constant),
+            report_count(5),
+            report_size(1),
+            usage_page(LED),
+            usage_minimum(1),
+            usage_maximum(5),
+            output(Data, Variable, Absolute),
+            report_count(1),
+            report_size(3),
+            output(Constant),
+            report_count(6),
+            report_size(8),
+            logical_minimum(0),
+            logical_maximum(101),
+            usage_page(Keyboard),
+            usage_minimum(0),
+            usage_maximum(101),
+            input(Data, Array),
+        },
+    };
+
+    assert_eq!(MOUSE, BootMouseReport::DESCRIPTOR);
+    assert_eq!(keyboard, BootKeyboardReport::DESCRIPTOR);
+}
+
+/// Parsing a built descriptor gives back the declared items.
+#[test]
+fn parse_round_trip() {
+    const NODES: &[ReportNode<'static>] = &hid_report!(@nodes
+        usage_page(0xFF00),
+        usage(0x0001_0002),
+        collection(0x80) {
+            report_id(2),
+            push(),
+            unit(0x1001),
+            unit_exponent(-3),
+            logical_minimum(-40000),
+            logical_maximum(40000),
+            physical_maximum(255),
+            report_size(32),
+            report_count(1),
+            delimiter(1),
+            usage(0x30),
+            usage(0x31),
+            delimiter(0),
+            feature(Data, Variable, Absolute, Volatile, BufferedBytes),
+            pop(),
+            string_index(4),
+            report_size(8),
+            report_count(3),
+            output(Constant),
+        },
+    );
+
+    const BYTES: [u8; 56] = usbdescriptor::hid::ReportBuilder::<56>::new().nodes(NODES).build();
+
+    let descriptor = ReportDescriptor::parse(&BYTES).unwrap();
+
+    let items = descriptor.items().map(|item| match item {
+        Item::Short(item) => item.typed(),
+        Item::Long(_) => None,
+    });
+
+    assert!(items.eq(ReportNode::items(NODES).map(Some)));
+}
@@ -0,0 +1,188 @@
+//! Tests for the CDC networking functional descriptors and MAC addresses.
+
+
+
+use usbdescriptor::cdc::*;
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// MAC address string "02A1b2C3d4E5", in mixed case.
+const MAC: [u8; 26] = [
+    0x1A, 0x03,
+    0x30, 0x00, 0x32, 0x00, 0x41, 0x00, 0x31, 0x00, 0x62, 0x00, 0x32, 0x00,
+    0x43, 0x00, 0x33, 0x00, 0x64, 0x00, 0x34, 0x00, 0x45, 0x00, 0x35, 0x00,
+];
+
+/// Parses a MAC address string descriptor.
+fn mac(bytes: &[u8]) -> Result<MACAddress, USBParseError> {
+    MACAddress::parse( &StringDescriptor::parse(bytes)? )
+}
+
+/// Parses a single functional descriptor.
+fn functional(bytes: &[u8]) -> Result<FunctionalDescriptor<'_>, USBParseError> {
+    FunctionalDescriptor::parse( RawDescriptor::split(bytes)?.0 )
+}
+
+/// MAC addresses decode in either case, and encode in upper case.
+#[test]
+fn mac_address_round_trip() {
+    let address = mac(&MAC).unwrap();
+
+    assert_eq!(address, MACAddress([0x02, 0xA1, 0xB2, 0xC3, 0xD4, 0xE5]));
+    assert_eq!(format!("{}", address), "02:A1:B2:C3:D4:E5");
+
+    let upper = address.to_array();
+
+    assert!(StringDescriptor::parse(&upper).unwrap().matches("02A1B2C3D4E5"));
+    assert_eq!(mac(&upper), Ok(address));
+
+    let mut buffer = [0u8; 26];
+    assert_eq!(address.to_bytes(&mut buffer), Ok(26));
+    assert_eq!(buffer, upper);
+}
+
+/// MAC address strings must hold exactly 12 hexadecimal digits.
+#[test]
+fn mac_address_errors() {
+    let mut bytes = MAC;
+
+    bytes[4] = b'G';
+    assert_eq!(mac(&bytes), Err( USBParseError::InvalidMACAddress(0x47) ));
+
+    bytes[4] = b':';
+    assert_eq!(mac(&bytes), Err( USBParseError::InvalidMACAddress(0x3A) ));
+
+    let mut bytes = MAC;
+    bytes[2..4].copy_from_slice(&[0x30, 0xFF]);
+    assert_eq!(mac(&bytes), Err( USBParseError::InvalidMACAddress(0xFF30) ));
+
+    let mut short = [0u8; 24];
+    short.copy_from_slice(&MAC[..24]);
+    short[0] = 0x18;
+    assert_eq!(mac(&short), Err( USBParseError::InvalidLength(0x18) ));
+}
+
+/// The MTU is the maximum segment size without the Ethernet header, and the
+/// filters are encoded with their flags.
+#[test]
+fn ethernet_networking() {
+    let statistics = EthernetStatistics::default()
+        .with(EthernetStatistic::TransmitOk)
+        .with(EthernetStatistic::ReceiveCRCError);
+
+    const DESCRIPTOR: EthernetNetworkingDescriptor = EthernetNetworkingDescriptor::new(4, EthernetStatistics(0x0002_0001), 1514)
+        .multicast_filters(16, false)
+        .power_filters(2);
+
+    assert_eq!(DESCRIPTOR.statistics(), statistics);
+    assert_eq!(DESCRIPTOR.to_array(), [0x0D, 0x24, 0x0F, 0x04, 0x01, 0x00, 0x02, 0x00, 0xEA, 0x05, 0x10, 0x80, 0x02]);
+
+    let parsed = EthernetNetworkingDescriptor::parse(&DESCRIPTOR.to_array()).unwrap();
+
+    assert_eq!(parsed, DESCRIPTOR);
+    assert_eq!((parsed.mac_address_index(), parsed.max_segment_size(), parsed.mtu()), (4, 1514, 1500));
+    assert_eq!((parsed.num_multicast_filters(), parsed.perfect_multicast_filtering()), (16, false));
+    assert_eq!(parsed.num_power_filters(), 2);
+
+    assert_eq!(EthernetNetworkingDescriptor::new(0, statistics, 10).mtu(), 0);
+    assert!(EthernetNetworkingDescriptor::new(0, statistics, 1514).multicast_filters(1, true).perfect_multicast_filtering());
+
+    let mut bytes = DESCRIPTOR.to_array();
+    bytes[0] = 0x0C;
+    assert_eq!(EthernetNetworkingDescriptor::parse(&bytes), Err( USBParseError::InvalidLength(0x0C) ));
+}
+
+/// Statistics are numbered from bit 0, and selected from 1.
+#[test]
+fn statistics_bit_order() {
+    for (bit, statistic) in EthernetStatistic::ALL.into_iter().enumerate() {
+        assert_eq!(statistic.bit() as usize, bit);
+        assert_eq!(statistic.selector() as usize, bit + 1);
+        assert!(EthernetStatistics(1 << bit).supports(statistic));
+        assert!(EthernetStatistics(1 << bit).iter().eq([statistic]));
+    }
+
+    assert_eq!(EthernetStatistic::TransmitOk.selector(), 0x01);
+    assert_eq!(EthernetStatistic::ReceiveCRCError.selector(), 0x12);
+    assert_eq!(EthernetStatistic::TransmitLateCollisions.selector(), 0x1D);
+
+    let all = EthernetStatistics(0x1FFF_FFFF);
+    assert!(all.iter().eq(EthernetStatistic::ALL));
+    assert_eq!(EthernetStatistics(0xE000_0000).iter().count(), 0);
+}
+
+/// NCM descriptors parse back to their version and capabilities.
+#[test]
+fn ncm_round_trip() {
+    const NCM: NCMDescriptor = NCMDescriptor::new(NCMDescriptor::NCM10, NetworkCapabilities::PACKET_FILTER.with(NetworkCapabilities::MAX_DATAGRAM_SIZE));
+
+    assert_eq!(NCM.to_array(), [0x06, 0x24, 0x1A, 0x00, 0x01, 0x09]);
+
+    let parsed = NCMDescriptor::parse(&NCM.to_array()).unwrap();
+
+    assert_eq!(parsed, NCM);
+    assert_eq!(parsed.version(), BCDVersion::new(1, 0, 0));
+    assert!(parsed.capabilities().contains(NetworkCapabilities::MAX_DATAGRAM_SIZE));
+    assert!(!parsed.capabilities().contains(NetworkCapabilities::CRC_MODE));
+
+    assert_eq!(NCMDescriptor::parse(&[0x06, 0x24, 0x1B, 0x00, 0x01, 0x00]), Err( USBParseError::InvalidDescriptorSubtype(0x1B) ));
+    assert_eq!(NCMDescriptor::parse(&[0x07, 0x24, 0x1A, 0x00, 0x01, 0x00, 0x00]), Err( USBParseError::InvalidLength(0x07) ));
+    assert_eq!(NCMDescriptor::parse(&[0x06, 0x24, 0x1A, 0x00, 0x01]), Err( USBParseError::BufferTooShort(5) ));
+}
+
+/// MBIM and MBIM extended descriptors parse back to their fields.
+#[test]
+fn mbim_round_trip() {
+    const MBIM: MBIMDescriptor = MBIMDescriptor::new(MBIMDescriptor::MBIM10, 4096, 1500, NetworkCapabilities::NTB_INPUT_SIZE_8)
+        .filters(16, 192);
+
+    const EXTENDED: MBIMExtendedDescriptor = MBIMExtendedDescriptor::new(BCDVersion::new(1, 0, 0), 64, 1428);
+
+    assert_eq!(MBIM.to_array(), [0x0C, 0x24, 0x1B, 0x00, 0x01, 0x00, 0x10, 0x10, 0xC0, 0xDC, 0x05, 0x20]);
+    assert_eq!(EXTENDED.to_array(), [0x08, 0x24, 0x1C, 0x00, 0x01, 0x40, 0x94, 0x05]);
+
+    let mbim = MBIMDescriptor::parse(&MBIM.to_array()).unwrap();
+
+    assert_eq!(mbim, MBIM);
+    assert_eq!((mbim.max_control_message(), mbim.max_segment_size()), (4096, 1500));
+    assert_eq!((mbim.num_filters(), mbim.max_filter_size()), (16, 192));
+    assert_eq!(mbim.capabilities(), NetworkCapabilities::NTB_INPUT_SIZE_8);
+
+    let extended = MBIMExtendedDescriptor::parse(&EXTENDED.to_array()).unwrap();
+
+    assert_eq!(extended, EXTENDED);
+    assert_eq!((extended.max_outstanding_commands(), extended.mtu()), (64, 1428));
+
+    assert_eq!(MBIMDescriptor::parse(&EXTENDED.to_array()), Err( USBParseError::BufferTooShort(8) ));
+    assert_eq!(MBIMExtendedDescriptor::parse(&MBIM.to_array()), Err( USBParseError::InvalidDescriptorSubtype(0x1B) ));
+}
+
+/// Functional descriptors are dispatched on their subtype, and unknown
+/// subtypes are kept as is.
+#[test]
+fn functional_dispatch() {
+    let ncm = NCMDescriptor::new(NCMDescriptor::NCM10, NetworkCapabilities(0)).to_array();
+    let mbim = MBIMDescriptor::new(MBIMDescriptor::MBIM10, 512, 1500, NetworkCapabilities(0)).to_array();
+    let extended = MBIMExtendedDescriptor::new(BCDVersion::new(1, 0, 0), 1, 1500).to_array();
+    let ethernet = EthernetNetworkingDescriptor::new(3, EthernetStatistics(0), 1514).to_array();
+    let terminal = [0x07, 0x24, 0x0A, 0x01, 0x02, 0x00, 0x01];
+
+    assert!(matches!(functional(&ncm), Ok( FunctionalDescriptor::NCM(_) )));
+    assert!(matches!(functional(&mbim), Ok( FunctionalDescriptor::MBIM(_) )));
+    assert!(matches!(functional(&extended), Ok( FunctionalDescriptor::MBIMExtended(_) )));
+    assert!(matches!(functional(&ethernet), Ok( FunctionalDescriptor::EthernetNetworking(_) )));
+
+    let other = functional(&terminal).unwrap();
+
+    assert!(matches!(other, FunctionalDescriptor::Other(_)));
+    assert_eq!(other.subtype(), FunctionalSubtype::NetworkChannelTerminal);
+    assert_eq!(other.size(), 7);
+
+    let mut broken = ncm;
+    broken[0] = 0x05;
+    assert_eq!(functional(&broken[..5]), Err( USBParseError::BufferTooShort(5) ));
+
+    assert_eq!(functional(&[0x03, 0x25, 0x1A]), Err( USBParseError::InvalidDescriptorType(0x25) ));
+}
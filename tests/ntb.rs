@@ -0,0 +1,140 @@
+//! Tests for the packing and parsing of NCM transfer blocks.
+
+
+
+use usbdescriptor::cdc::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Alignment with datagrams at 2 modulo 4.
+const ALIGNMENT: NtbAlignment = NtbAlignment { divisor: 4, remainder: 2, ndp_alignment: 4 };
+
+/// Datagrams packed by the tests.
+const DATAGRAMS: [&[u8]; 3] = [&[0x11; 5], &[0x22; 3], &[0x33; 7]];
+
+/// Packs the test datagrams into a block of the given format.
+fn build(format: NtbFormat) -> ([u8; 128], usize) {
+    let mut buffer = [0xFFu8; 128];
+    let mut builder = NtbBuilder::<'_, 4>::new(&mut buffer, format, ALIGNMENT, 7);
+
+    for datagram in DATAGRAMS {
+        builder.push(datagram).unwrap();
+    }
+
+    let size = builder.finish().unwrap();
+
+    (buffer, size)
+}
+
+/// Checks that a packed block parses back to its datagrams at aligned
+/// offsets.
+fn round_trip(format: NtbFormat, expected: usize) {
+    let (buffer, size) = build(format);
+    assert_eq!(size, expected);
+
+    let ntb = Ntb::parse(&buffer[..size]).unwrap();
+
+    assert_eq!((ntb.format(), ntb.sequence(), ntb.block_length()), (format, 7, size));
+    assert!(ntb.datagrams().eq(DATAGRAMS));
+
+    let mut ndps = ntb.ndps();
+    let ndp = ndps.next().unwrap();
+
+    assert!(ndps.next().is_none());
+    assert_eq!(ndp.signature(), format.ndp_signature(false));
+
+    for datagram in ndp.datagrams() {
+        let offset = datagram.as_ptr() as usize - buffer.as_ptr() as usize;
+        assert_eq!(offset % 4, 2);
+    }
+
+    let last = ndp.datagrams().last().unwrap();
+    let end = last.as_ptr() as usize - buffer.as_ptr() as usize + last.len();
+    assert_eq!(size - ndp.len(), ALIGNMENT.ndp_offset(end));
+}
+
+/// NTB16 blocks parse back to their datagrams.
+#[test]
+fn ntb16_round_trip() {
+    round_trip(NtbFormat::Ntb16, 60);
+}
+
+/// NTB32 blocks parse back to their datagrams.
+#[test]
+fn ntb32_round_trip() {
+    round_trip(NtbFormat::Ntb32, 88);
+}
+
+/// NTB parameters serialize and parse back.
+#[test]
+fn parameters_round_trip() {
+    let parameters = NtbParameters::new(
+        NtbFormats::NTB16.with(NtbFormats::NTB32),
+        2048, NtbAlignment { divisor: 4, remainder: 0, ndp_alignment: 4 },
+        2048, NtbAlignment { divisor: 4, remainder: 2, ndp_alignment: 4 },
+        16,
+    );
+
+    let bytes = parameters.to_array();
+
+    assert_eq!(bytes, [
+        0x1C, 0x00, 0x03, 0x00,
+        0x00, 0x08, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00,
+        0x04, 0x00, 0x02, 0x00, 0x04, 0x00, 0x10, 0x00,
+    ]);
+
+    assert_eq!(NtbParameters::parse(&bytes), Ok( parameters ));
+}
+
+/// NDPs chained to themselves are rejected.
+#[test]
+fn ndp_loop() {
+    let (mut buffer, size) = build(NtbFormat::Ntb16);
+    let ndp = u16::from_le_bytes([buffer[10], buffer[11]]);
+
+    buffer[ndp as usize + 6..ndp as usize + 8].copy_from_slice(&ndp.to_le_bytes());
+
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(ndp as u32) ));
+}
+
+/// NDPs must be aligned on 4 bytes and lie after the header.
+#[test]
+fn misaligned_ndp() {
+    let (mut buffer, size) = build(NtbFormat::Ntb16);
+
+    buffer[10..12].copy_from_slice(&18u16.to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(18) ));
+
+    buffer[10..12].copy_from_slice(&8u16.to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(8) ));
+}
+
+/// NDPs and datagrams must lie within the block.
+#[test]
+fn pointers_out_of_range() {
+    let (mut buffer, size) = build(NtbFormat::Ntb16);
+    let ndp = u16::from_le_bytes([buffer[10], buffer[11]]) as usize;
+
+    buffer[ndp + 10..ndp + 12].copy_from_slice(&0x1000u16.to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(14) ));
+
+    buffer[10..12].copy_from_slice(&(size as u16).to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(size as u32) ));
+}
+
+/// Offsets near the end of the address space are rejected without
+/// overflowing.
+#[test]
+fn ntb32_huge_offsets() {
+    let (mut buffer, size) = build(NtbFormat::Ntb32);
+    let ndp = u32::from_le_bytes([buffer[12], buffer[13], buffer[14], buffer[15]]) as usize;
+
+    buffer[ndp + 16..ndp + 20].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(0xFFFF_FFF0) ));
+
+    buffer[12..16].copy_from_slice(&0xFFFF_FFFCu32.to_le_bytes());
+    assert_eq!(Ntb::parse(&buffer[..size]), Err( USBParseError::NtbIndexOutOfRange(0xFFFF_FFFC) ));
+}
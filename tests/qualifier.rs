@@ -0,0 +1,61 @@
+//! Tests for the device qualifier certification checks.
+
+
+
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Device descriptor of a vendor specific device with the given endpoint 0.
+fn device(max_packet_size0: MaxPacketSize0) -> DeviceDescriptor {
+    DeviceDescriptorBuilder::new()
+        .class( usbdescriptor::class::class::Class::VendorSpecific(0, 0) )
+        .max_packet_size0(max_packet_size0)
+        .descriptor()
+}
+
+/// A qualifier copied from the device passes at both speeds.
+#[test]
+fn validate_matching() {
+    let device = device(MaxPacketSize0::Bytes64);
+    let qualifier = DeviceQualifierDescriptor::new(&device, MaxPacketSize0::Bytes64);
+
+    assert_eq!(qualifier.validate(&device, Speed::High), Ok(()));
+    assert_eq!(qualifier.validate(&device, Speed::Full), Ok(()));
+}
+
+/// The qualifier must report the class codes of the device.
+#[test]
+fn validate_class_mismatch() {
+    let device = device(MaxPacketSize0::Bytes64);
+    let qualifier = DeviceQualifierDescriptor::parse(&[0x0A, 0x06, 0x00, 0x02, 0xEF, 0x02, 0x01, 0x40, 0x01, 0x00]).unwrap();
+
+    assert_eq!(qualifier.validate(&device, Speed::High), Err( USBParseError::ClassMismatch(0xEF) ));
+}
+
+/// The qualifier must report the number of configurations of the device.
+#[test]
+fn validate_configuration_count_mismatch() {
+    let device = device(MaxPacketSize0::Bytes64);
+    let qualifier = DeviceQualifierDescriptor::parse(&[0x0A, 0x06, 0x00, 0x02, 0xFF, 0x00, 0x00, 0x40, 0x02, 0x00]).unwrap();
+
+    assert_eq!(qualifier.validate(&device, Speed::Full), Err( USBParseError::ConfigurationCountMismatch(2) ));
+}
+
+/// Endpoint 0 uses 64 byte packets at high speed, which is the device
+/// descriptor at high speed and the qualifier at full speed.
+#[test]
+fn validate_high_speed_packet_size() {
+    let full = device(MaxPacketSize0::Bytes8);
+    let qualifier = DeviceQualifierDescriptor::new(&full, MaxPacketSize0::Bytes64);
+
+    assert_eq!(qualifier.validate(&full, Speed::Full), Ok(()));
+    assert_eq!(qualifier.validate(&full, Speed::High), Err( USBParseError::InvalidMaxPacketSize(8) ));
+
+    let high = device(MaxPacketSize0::Bytes64);
+    let qualifier = DeviceQualifierDescriptor::new(&high, MaxPacketSize0::Bytes16);
+
+    assert_eq!(qualifier.validate(&high, Speed::High), Ok(()));
+    assert_eq!(qualifier.validate(&high, Speed::Full), Err( USBParseError::InvalidMaxPacketSize(16) ));
+}
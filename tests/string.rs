@@ -0,0 +1,125 @@
+//! Tests for the string descriptors and the language identifiers.
+
+
+
+use usbdescriptor::descriptor::*;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// "Key 𝄞" in UTF-16LE, with a surrogate pair for the clef.
+const KEY: [u8; 14] = [
+    0x0E, 0x03,
+    0x4B, 0x00, 0x65, 0x00, 0x79, 0x00, 0x20, 0x00,
+    0x34, 0xD8, 0x1E, 0xDD,
+];
+
+/// Surrogate pairs decode to a single character.
+#[test]
+fn surrogate_pairs() {
+    let string = StringDescriptor::parse(&KEY).unwrap();
+
+    assert_eq!(string.len_utf16(), 6);
+    assert!(string.units().eq([0x004B, 0x0065, 0x0079, 0x0020, 0xD834, 0xDD1E]));
+    assert!(string.chars().eq("Key 𝄞".chars().map(Ok)));
+
+    assert_eq!(TextDescriptor("Key 𝄞").to_array::<14>(), KEY);
+}
+
+/// Unpaired surrogates are reported and decoding goes on after them.
+#[test]
+fn lone_surrogates() {
+    const BYTES: [u8; 8] = [0x08, 0x03, 0x00, 0xD8, 0x41, 0x00, 0x1E, 0xDD];
+
+    let string = StringDescriptor::parse(&BYTES).unwrap();
+
+    assert!(string.chars().eq([
+        Err( USBParseError::InvalidUTF16(0xD800) ),
+        Ok('A'),
+        Err( USBParseError::InvalidUTF16(0xDD1E) ),
+    ]));
+
+    assert!(!string.matches("A"));
+}
+
+/// Strings only match the exact same text.
+#[test]
+fn matches() {
+    let string = StringDescriptor::parse(&KEY).unwrap();
+
+    assert!(string.matches("Key 𝄞"));
+    assert!(!string.matches("Key "));
+    assert!(!string.matches("Key 𝄞 "));
+    assert!(!string.matches("key 𝄞"));
+
+    let empty = StringDescriptor::parse(&[0x02, 0x03]).unwrap();
+    assert!(empty.is_empty() && empty.matches(""));
+}
+
+/// Odd or too short lengths, wrong types and truncated buffers are rejected.
+#[test]
+fn parse_errors() {
+    assert_eq!(StringDescriptor::parse(&[0x03, 0x03, 0x41]), Err( USBParseError::InvalidLength(0x03) ));
+    assert_eq!(StringDescriptor::parse(&[0x00, 0x03]), Err( USBParseError::InvalidLength(0x00) ));
+    assert_eq!(StringDescriptor::parse(&[0x04, 0x02, 0x41, 0x00]), Err( USBParseError::InvalidDescriptorType(0x02) ));
+    assert_eq!(StringDescriptor::parse(&KEY[..12]), Err( USBParseError::BufferTooShort(12) ));
+    assert_eq!(LanguageTable::parse(&[0x05, 0x03, 0x09, 0x04, 0x00]), Err( USBParseError::InvalidLength(0x05) ));
+}
+
+/// Text descriptors serialize to their UTF-16LE encoding.
+#[test]
+fn text_serialize() {
+    let mut buffer = [0u8; 16];
+
+    assert_eq!(TextDescriptor("Key 𝄞").size(), 14);
+    assert_eq!(TextDescriptor("Key 𝄞").to_bytes(&mut buffer), Ok(14));
+    assert_eq!(buffer[..14], KEY);
+
+    let string = StringDescriptor::parse(&KEY).unwrap();
+    assert_eq!(string.to_bytes(&mut buffer), Ok(14));
+    assert_eq!(buffer[..14], KEY);
+}
+
+/// Language tables list known and unknown languages.
+#[test]
+fn language_table() {
+    const TABLE: [u8; 6] = LanguageList(&[LanguageId::EnglishUnitedStates, LanguageId::Other(0x7C04)]).to_array::<6>();
+
+    assert_eq!(TABLE, [0x06, 0x03, 0x09, 0x04, 0x04, 0x7C]);
+
+    let table = LanguageTable::parse(&TABLE).unwrap();
+
+    assert_eq!(table.len(), 2);
+    assert!(table.languages().eq([LanguageId::EnglishUnitedStates, LanguageId::Other(0x7C04)]));
+    assert!(table.supports(LanguageId::EnglishUnitedStates));
+    assert!(!table.supports(LanguageId::FrenchStandard));
+}
+
+/// Every LANGID converts back to itself, and only listed ones are accepted
+/// by the strict decoder.
+#[test]
+fn langid_round_trip() {
+    let mut defined = 0;
+
+    for code in 0..=0xFFFFu16 {
+        let language = LanguageId::decode(code);
+
+        assert_eq!(language.code(), code);
+
+        match LanguageId::try_from(code) {
+            Ok(strict) => {
+                assert_eq!(strict, language);
+                assert_ne!(strict.name(), "Unknown");
+                assert_eq!(Into::<u16>::into(strict), code);
+                defined += 1;
+            },
+
+            Err(error) => {
+                assert_eq!(error, USBParseError::UnknownLanguage(code));
+                assert_eq!(language, LanguageId::Other(code));
+            },
+        }
+    }
+
+    assert!(defined > 100);
+}
@@ -0,0 +1,74 @@
+//! Tests for the descriptor type codes.
+
+
+
+use usbdescriptor::class::class::Class;
+use usbdescriptor::descriptor::DescriptorType;
+use usbdescriptor::error::USBParseError;
+
+
+
+/// Every code decodes to a type that converts back to the same code, and the
+/// strict decoder agrees with the lenient one.
+#[test]
+fn code_round_trip() {
+    for code in 0..=0xFFu8 {
+        let decoded = DescriptorType::decode(code);
+
+        assert_eq!(decoded.code(), code);
+        assert_eq!(Into::<u8>::into(decoded), code);
+
+        match DescriptorType::try_from(code) {
+            Ok(strict) => assert_eq!(strict, decoded),
+
+            Err(error) => {
+                assert_eq!(error, USBParseError::UnknownDescriptorType(code));
+                assert_eq!(decoded, DescriptorType::Other(code));
+            },
+        }
+    }
+}
+
+/// Codes 40h to 5Fh are vendor specific, and the reserved class specific
+/// codes are unknown.
+#[test]
+fn vendor_and_reserved_codes() {
+    for code in 0x40..=0x5F {
+        assert_eq!(DescriptorType::try_from(code), Ok( DescriptorType::VendorSpecific(code) ));
+    }
+
+    for code in [0x00, 0x12, 0x20, 0x26, 0x27, 0x28, 0x2B, 0x32, 0x3F, 0x60, 0xFF] {
+        assert_eq!(DescriptorType::decode(code), DescriptorType::Other(code));
+    }
+}
+
+/// Class specific codes 21h to 23h are resolved with the interface class.
+#[test]
+fn class_aware_decoding() {
+    let hid = Class::decode((0x03, 0x01, 0x01));
+    let dfu = Class::decode((0xFE, 0x01, 0x02));
+    let smart_card = Class::decode((0x0B, 0x00, 0x00));
+    let audio = Class::decode((0x01, 0x01, 0x00));
+
+    assert_eq!(DescriptorType::decode_in(0x21, hid), DescriptorType::HID);
+    assert_eq!(DescriptorType::decode_in(0x22, hid), DescriptorType::Report);
+    assert_eq!(DescriptorType::decode_in(0x23, hid), DescriptorType::Physical);
+
+    assert_eq!(DescriptorType::decode_in(0x21, dfu), DescriptorType::DFUFunctional);
+    assert_eq!(DescriptorType::decode_in(0x21, smart_card), DescriptorType::SmartCardFunctional);
+
+    assert_eq!(DescriptorType::decode_in(0x21, audio), DescriptorType::ClassDevice);
+    assert_eq!(DescriptorType::decode_in(0x22, dfu), DescriptorType::ClassConfiguration);
+    assert_eq!(DescriptorType::decode_in(0x23, smart_card), DescriptorType::ClassString);
+
+    for class in [hid, dfu, smart_card, audio] {
+        assert_eq!(DescriptorType::decode_in(0x24, class), DescriptorType::ClassInterface);
+        assert_eq!(DescriptorType::decode_in(0x05, class), DescriptorType::Endpoint);
+        assert_eq!(DescriptorType::decode_in(0x26, class), DescriptorType::Other(0x26));
+        assert_eq!(DescriptorType::decode_in(0x41, class), DescriptorType::VendorSpecific(0x41));
+
+        for code in 0..=0xFF {
+            assert_eq!(DescriptorType::decode_in(code, class).code(), code);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Tests for the HID usage tables.
+
+#![cfg(feature = "hut")]
+
+
+
+use usbdescriptor::hid::Usage;
+use usbdescriptor::hid::usages::{ UsageInfo, UsageKind, page_name, usage_info };
+
+
+
+/// Pages are named from their table, vendor pages share a name, and
+/// reserved pages have none.
+#[test]
+fn page_names() {
+    assert_eq!(page_name(0x0001), Some("Generic Desktop"));
+    assert_eq!(page_name(0x0092), Some("Gaming Device"));
+    assert_eq!(page_name(0xF1D0), Some("FIDO Alliance"));
+    assert_eq!(page_name(0xFF00), Some("Vendor Defined"));
+    assert_eq!(page_name(0xFFFF), Some("Vendor Defined"));
+
+    for page in [0x0000, 0x0013, 0x0083, 0x0093, 0xF1CF, 0xFEFF] {
+        assert_eq!(page_name(page), None);
+    }
+}
+
+/// Usages are found at both ends and in the middle of their table, and
+/// reserved usages are not found.
+#[test]
+fn usage_lookups() {
+    assert_eq!(usage_info(0x0001, 0x0001), Some( UsageInfo { name: "Pointer", kinds: &[UsageKind::PhysicalCollection] } ));
+    assert_eq!(usage_info(0x0001, 0x0002).map(|info| info.name), Some("Mouse"));
+    assert_eq!(usage_info(0x0001, 0x00E2).map(|info| info.name), Some("Call Mute LED"));
+    assert_eq!(usage_info(0x0085, 0x00F3).map(|info| info.name), Some("Level 3"));
+    assert_eq!(usage_info(0x0007, 0x0004).map(|info| info.name), Some("Keyboard a and A"));
+    assert_eq!(usage_info(0x0007, 0x00E7).map(|info| info.kinds), Some( &[UsageKind::DynamicValue][..] ));
+    assert_eq!(usage_info(0xF1D0, 0x0021).map(|info| info.name), Some("Output Report Data"));
+
+    for (page, id) in [(0x0001, 0x0000), (0x0001, 0x0003), (0x0001, 0xFFFF), (0x0007, 0x00E8), (0x0013, 0x0001), (0xFF00, 0x0001)] {
+        assert_eq!(usage_info(page, id), None);
+    }
+}
+
+/// Buttons, ordinals and Unicode characters are numbered.
+#[test]
+fn numbered_usages() {
+    assert_eq!(usage_info(0x0009, 0x0000).map(|info| info.name), Some("No Button Pressed"));
+    assert_eq!(usage_info(0x0009, 0x0003).map(|info| info.name), Some("Button"));
+    assert_eq!(usage_info(0x000A, 0x0000), None);
+    assert_eq!(usage_info(0x000A, 0x0002).map(|info| info.name), Some("Instance"));
+    assert_eq!(usage_info(0x0010, 0x00E9).map(|info| info.name), Some("Unicode Character"));
+
+    assert!(Usage { page: 0x0009, id: 3 }.numbered());
+    assert!(!Usage { page: 0x0009, id: 0 }.numbered());
+    assert!(!Usage { page: 0x0001, id: 2 }.numbered());
+}
+
+/// Usages print with their names when known.
+#[test]
+fn display() {
+    let names = [
+        (Usage { page: 0x0001, id: 0x0002 }, "Generic Desktop: Mouse"),
+        (Usage { page: 0x0009, id: 0x0003 }, "Button: Button 3"),
+        (Usage { page: 0x0010, id: 0x00E9 }, "Unicode: U+00E9"),
+        (Usage { page: 0x0001, id: 0x0003 }, "Generic Desktop: 0003h"),
+        (Usage { page: 0x0013, id: 0x0001 }, "0013h: 0001h"),
+    ];
+
+    for (usage, name) in names {
+        assert_eq!(format!("{}", usage), name);
+    }
+}